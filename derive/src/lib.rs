@@ -32,7 +32,9 @@ mod models;
 use darling::FromDeriveInput;
 use proc_macro::TokenStream;
 use quote::quote;
-use surreal_derive_helpers::utilities::{PickedMeta, TableDeriveAttributesPickable};
+use surreal_derive_helpers::utilities::{
+    AggregateDeriveAttributes, PickedMeta, TableDeriveAttributesPickable,
+};
 use syn::parse_macro_input;
 
 #[proc_macro_derive(Node, attributes(orm))]
@@ -70,6 +72,17 @@ pub fn surreal_pickable_resources_derive(input: TokenStream) -> TokenStream {
     quote!(#output).into()
 }
 
+#[proc_macro_derive(Aggregate, attributes(agg))]
+pub fn surreal_aggregate_derive(input: TokenStream) -> TokenStream {
+    #[allow(clippy::all)]
+    let input = parse_macro_input!(input);
+    let output = match AggregateDeriveAttributes::from_derive_input(&input) {
+        Ok(out) => out,
+        Err(err) => return proc_macro::TokenStream::from(err.write_errors()),
+    };
+    quote!(#output).into()
+}
+
 /// ```rust
 /// #[derive(Pickable, Debug, Serialize)]
 /// struct Person<'a, T: 'a, U: 'a> {
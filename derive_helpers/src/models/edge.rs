@@ -93,8 +93,13 @@ impl ToTokens for EdgeToken {
             record_link_fields_methods,
             schema_struct_fields_names_kv_empty,
             field_definitions,
+            index_definitions,
+            event_definitions,
+            schema_struct_fields_idents,
             serialized_ident_struct_partial_init_fields,
             serialized_fmt_db_field_names_instance: serializable_fields,
+            serialized_db_field_names,
+            version_fields,
             linked_fields,
             link_one_fields,
             link_self_fields,
@@ -126,6 +131,31 @@ impl ToTokens for EdgeToken {
                 .write_errors(),
             );
         }
+
+        if self.account.is_some() {
+            tokens.extend(
+                ExtractorError::Darling(darling::Error::custom(
+                    "The account attribute can only be used on a Node",
+                ))
+                .write_errors(),
+            );
+        }
+
+        if self.soft_delete.is_some() {
+            tokens.extend(
+                ExtractorError::Darling(darling::Error::custom(
+                    "The soft_delete attribute can only be used on a Node",
+                ))
+                .write_errors(),
+            );
+        }
+        let version_field = version_fields.first().map(|version| {
+            quote!(
+                fn version_field() -> ::std::option::Option<#crate_name::Field> {
+                    ::std::option::Option::Some(#crate_name::Field::new(#version))
+                }
+            )
+        });
         let imports_referenced_node_schema = Vec::from_iter(imports_referenced_node_schema);
         let CommonIdents {
             module_name_internal,
@@ -137,6 +167,14 @@ impl ToTokens for EdgeToken {
         } = code_gen.common_idents();
 
         let serializable_fields_count = serializable_fields.len();
+        // Brings the schema fields in scope of the event attributes expressions
+        let event_schema_fields_binding = (!event_definitions.is_empty()).then(|| {
+            quote!(
+                #[allow(unused_variables, non_snake_case)]
+                let #module_name_rexported::Schema { #( #schema_struct_fields_idents, ) * .. } =
+                    <Self as #crate_name::SchemaGetter>::schema();
+            )
+        });
         let struct_partial_ident = struct_name_ident.partial_ident();
         let struct_partial_builder_ident = struct_name_ident.partial_builder_ident();
 
@@ -227,6 +265,8 @@ impl ToTokens for EdgeToken {
                 impl #impl_generics #crate_name::Model for #struct_name_ident #ty_generics #where_clause {
                     type Id = #table_id_type;
                     type StructRenamedCreator = #struct_with_renamed_serialized_fields;
+                    const TABLE_NAME: &'static str = #table_str;
+                    const SERIALIZABLE_FIELD_NAMES: &'static [&'static str] = &[#( #serialized_db_field_names), *];
 
                     fn table() -> #crate_name::Table {
                         #table_str.into()
@@ -274,9 +314,25 @@ impl ToTokens for EdgeToken {
                         ]
                     }
 
+                    fn define_indexes() -> ::std::vec::Vec<#crate_name::Raw> {
+                        ::std::vec![
+                           #( #index_definitions), *
+                        ]
+                    }
+
+                    fn define_events() -> ::std::vec::Vec<#crate_name::Raw> {
+                        #event_schema_fields_binding
+
+                        ::std::vec![
+                           #( #event_definitions), *
+                        ]
+                    }
+
                     fn get_field_meta() -> ::std::vec::Vec<#crate_name::FieldMetadata> {
                         return ::std::vec![#( #field_metadata), *]
                     }
+
+                    #version_field
                 }
 
                 #[allow(non_snake_case)]
@@ -0,0 +1,26 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use darling::FromMeta;
+
+/// Index on a single field e.g
+/// #[orm(index)] or #[orm(index(unique, name = "email_index"))]
+#[derive(Debug, Clone, Default, FromMeta)]
+pub struct FieldIndexAttr {
+    /// Name of the index. Defaults to `<field>_index`
+    #[darling(default)]
+    pub(crate) name: Option<String>,
+    #[darling(default)]
+    pub(crate) unique: bool,
+}
+
+impl FieldIndexAttr {
+    pub fn index_name(&self, db_field_name: &str) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{db_field_name}_index"))
+    }
+}
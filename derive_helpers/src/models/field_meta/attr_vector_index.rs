@@ -0,0 +1,164 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::str::FromStr;
+
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use proc_macros_helpers::get_crate_name;
+use quote::{quote, ToTokens};
+use surreal_query_builder::statements::{VectorDistance, VectorType};
+use syn::spanned::Spanned;
+
+/// M-Tree vector index field attribute e.g
+/// #[orm(mtree(dimension = 4, dist = cosine, ty = f32, capacity = 40))]
+#[derive(Debug, Clone, FromMeta)]
+pub struct MTreeIndexAttr {
+    /// Name of the index. Defaults to `<field>_vector_index`
+    #[darling(default)]
+    pub(crate) name: Option<String>,
+    pub(crate) dimension: u32,
+    #[darling(default)]
+    pub(crate) dist: Option<VectorDistanceAttr>,
+    #[darling(default, rename = "ty")]
+    pub(crate) vector_type: Option<VectorTypeAttr>,
+    #[darling(default)]
+    pub(crate) capacity: Option<u32>,
+}
+
+impl MTreeIndexAttr {
+    pub fn index_name(&self, db_field_name: &str) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{db_field_name}_vector_index"))
+    }
+
+    pub fn mtree_token(&self) -> TokenStream {
+        let crate_name = get_crate_name(false);
+        let dimension = self.dimension;
+        let dist = self.dist.as_ref().map(|dist| quote!(.dist(#dist)));
+        let vector_type = self
+            .vector_type
+            .as_ref()
+            .map(|vector_type| quote!(.vector_type(#vector_type)));
+        let capacity = self.capacity.map(|capacity| quote!(.capacity(#capacity)));
+
+        quote!(.mtree(#crate_name::statements::mtree(#dimension) #dist #vector_type #capacity))
+    }
+}
+
+/// HNSW vector index field attribute e.g
+/// #[orm(hnsw(dimension = 4, dist = cosine, ty = f32, efc = 150, m = 12))]
+#[derive(Debug, Clone, FromMeta)]
+pub struct HnswIndexAttr {
+    /// Name of the index. Defaults to `<field>_vector_index`
+    #[darling(default)]
+    pub(crate) name: Option<String>,
+    pub(crate) dimension: u32,
+    #[darling(default)]
+    pub(crate) dist: Option<VectorDistanceAttr>,
+    #[darling(default, rename = "ty")]
+    pub(crate) vector_type: Option<VectorTypeAttr>,
+    #[darling(default)]
+    pub(crate) efc: Option<u32>,
+    #[darling(default)]
+    pub(crate) m: Option<u32>,
+}
+
+impl HnswIndexAttr {
+    pub fn index_name(&self, db_field_name: &str) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{db_field_name}_vector_index"))
+    }
+
+    pub fn hnsw_token(&self) -> TokenStream {
+        let crate_name = get_crate_name(false);
+        let dimension = self.dimension;
+        let dist = self.dist.as_ref().map(|dist| quote!(.dist(#dist)));
+        let vector_type = self
+            .vector_type
+            .as_ref()
+            .map(|vector_type| quote!(.vector_type(#vector_type)));
+        let efc = self.efc.map(|efc| quote!(.efc(#efc)));
+        let m = self.m.map(|m| quote!(.m(#m)));
+
+        quote!(.hnsw(#crate_name::statements::hnsw(#dimension) #dist #vector_type #efc #m))
+    }
+}
+
+fn ident_or_str_from_expr(expr: &syn::Expr, attr_name: &str) -> darling::Result<String> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) => Ok(lit_str.value()),
+        syn::Expr::Path(expr_path) => expr_path
+            .path
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .ok_or_else(|| {
+                darling::Error::custom(format!("Expected an identifier for {attr_name}"))
+                    .with_span(&expr.span())
+            }),
+        _ => Err(
+            darling::Error::custom(format!("Expected a string literal for {attr_name}"))
+                .with_span(&expr.span()),
+        ),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorDistanceAttr(VectorDistance);
+
+impl FromMeta for VectorDistanceAttr {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let distance = ident_or_str_from_expr(expr, "dist")?;
+        VectorDistance::from_str(&distance)
+            .map(Self)
+            .map_err(|e| darling::Error::custom(e).with_span(&expr.span()))
+    }
+}
+
+impl ToTokens for VectorDistanceAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_name = get_crate_name(false);
+        let variant = match self.0 {
+            VectorDistance::Chebyshev => quote!(Chebyshev),
+            VectorDistance::Cosine => quote!(Cosine),
+            VectorDistance::Euclidean => quote!(Euclidean),
+            VectorDistance::Hamming => quote!(Hamming),
+            VectorDistance::Manhattan => quote!(Manhattan),
+        };
+        tokens.extend(quote!(#crate_name::statements::VectorDistance::#variant));
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VectorTypeAttr(VectorType);
+
+impl FromMeta for VectorTypeAttr {
+    fn from_expr(expr: &syn::Expr) -> darling::Result<Self> {
+        let vector_type = ident_or_str_from_expr(expr, "ty")?;
+        VectorType::from_str(&vector_type)
+            .map(Self)
+            .map_err(|e| darling::Error::custom(e).with_span(&expr.span()))
+    }
+}
+
+impl ToTokens for VectorTypeAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_name = get_crate_name(false);
+        let variant = match self.0 {
+            VectorType::F64 => quote!(F64),
+            VectorType::F32 => quote!(F32),
+            VectorType::I64 => quote!(I64),
+            VectorType::I32 => quote!(I32),
+            VectorType::I16 => quote!(I16),
+        };
+        tokens.extend(quote!(#crate_name::statements::VectorType::#variant));
+    }
+}
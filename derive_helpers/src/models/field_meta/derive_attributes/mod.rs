@@ -9,7 +9,7 @@ mod db_field_types;
 mod generics;
 mod ident;
 
-use darling::FromField;
+use darling::{util::Override, FromField};
 use proc_macro2::Ident;
 use syn::Type;
 
@@ -81,12 +81,36 @@ pub struct MyFieldReceiver {
     #[darling(default)]
     pub(crate) value: Option<AttributeValue>,
 
+    /// Set to the time the record is created and kept as is afterwards
+    #[darling(default)]
+    pub(crate) created_at: bool,
+
+    /// Set to the time of every write of the record
+    #[darling(default)]
+    pub(crate) updated_at: bool,
+
+    /// Incremented on every write of the record to detect stale writes
+    #[darling(default)]
+    pub(crate) version: bool,
+
     #[darling(default)]
     pub(crate) permissions: Option<Permissions>,
 
     #[darling(default)]
     pub(crate) item_assert: Option<AttributeItemAssert>,
 
+    /// M-Tree vector index on the field e.g mtree(dimension = 4, dist = cosine)
+    #[darling(default)]
+    pub(crate) mtree: Option<MTreeIndexAttr>,
+
+    /// HNSW vector index on the field e.g hnsw(dimension = 4, dist = cosine, efc = 150, m = 12)
+    #[darling(default)]
+    pub(crate) hnsw: Option<HnswIndexAttr>,
+
+    /// Index on the field e.g index or index(unique)
+    #[darling(default)]
+    pub(crate) index: Option<Override<FieldIndexAttr>>,
+
     // Serde attributes
     #[darling(default)]
     pub(crate) skip_serializing: bool,
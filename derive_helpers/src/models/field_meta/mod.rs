@@ -4,9 +4,11 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
+mod attr_index;
 mod attr_permissions;
 mod attr_relate;
 mod attr_type_db;
+mod attr_vector_index;
 mod attrs_expr_or_path;
 mod custom_type;
 mod custom_type_inference;
@@ -23,9 +25,11 @@ mod token_stream_hashable;
 mod token_wrappers;
 mod type_stripper;
 
+pub use attr_index::*;
 pub use attr_permissions::*;
 pub use attr_relate::*;
 pub use attr_type_db::*;
+pub use attr_vector_index::*;
 pub use attrs_expr_or_path::*;
 pub use custom_type::*;
 pub use custom_type_inference::*;
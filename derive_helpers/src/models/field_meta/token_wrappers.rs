@@ -65,3 +65,5 @@ create_tokenstream_wrapper!(=> DbFieldNamesToken);
 create_tokenstream_wrapper!(=> TableDefinitions);
 
 create_tokenstream_wrapper!(=> DefineFieldStatementToken);
+create_tokenstream_wrapper!(=> DefineIndexStatementToken);
+create_tokenstream_wrapper!(=> DefineEventStatementToken);
@@ -81,12 +81,17 @@ impl ToTokens for NodeToken {
             schema_struct_fields_names_kv_empty,
             serialized_ident_struct_partial_init_fields,
             serialized_fmt_db_field_names_instance: serializable_fields,
+            serialized_db_field_names,
+            version_fields,
             linked_fields,
             link_one_fields,
             link_self_fields,
             link_one_and_self_fields,
             link_many_fields,
             field_definitions,
+            index_definitions,
+            event_definitions,
+            schema_struct_fields_idents,
             fields_relations_aliased,
             struct_partial_fields,
             struct_partial_associated_functions,
@@ -112,6 +117,47 @@ impl ToTokens for NodeToken {
         let struct_partial_builder_ident = struct_name_ident.partial_builder_ident();
 
         let serializable_fields_count = serializable_fields.len();
+        // Brings the schema fields in scope of the event attributes expressions
+        let event_schema_fields_binding = (!event_definitions.is_empty()).then(|| {
+            quote!(
+                #[allow(unused_variables, non_snake_case)]
+                let #module_name_rexported::Schema { #( #schema_struct_fields_idents, ) * .. } =
+                    <Self as #crate_name::SchemaGetter>::schema();
+            )
+        });
+        let account_impl = table_derive_attributes.account.as_ref().map(|scope| {
+            quote!(
+                impl #struct_impl_generics #crate_name::Account for #struct_name_ident #struct_ty_generics #struct_where_clause {
+                    fn scope() -> #crate_name::Scope {
+                        #crate_name::Scope::new(#scope)
+                    }
+                }
+            )
+        });
+        let soft_delete_field = match &table_derive_attributes.soft_delete {
+            Some(soft_delete) if !serialized_db_field_names.contains(soft_delete) => {
+                return tokens.extend(
+                    ExtractorError::Darling(darling::Error::custom(format!(
+                        "The soft_delete field `{soft_delete}` must be a serialized field of the struct \
+                        e.g `deleted_at: Option<chrono::DateTime<chrono::Utc>>`"
+                    )))
+                    .write_errors(),
+                );
+            }
+            Some(soft_delete) => Some(quote!(
+                fn soft_delete_field() -> ::std::option::Option<#crate_name::Field> {
+                    ::std::option::Option::Some(#crate_name::Field::new(#soft_delete))
+                }
+            )),
+            None => None,
+        };
+        let version_field = version_fields.first().map(|version| {
+            quote!(
+                fn version_field() -> ::std::option::Option<#crate_name::Field> {
+                    ::std::option::Option::Some(#crate_name::Field::new(#version))
+                }
+            )
+        });
         let table_definitions = match self.get_table_definition_token() {
             Ok(table_definitions) => table_definitions,
             Err(err) => return tokens.extend(err.write_errors()),
@@ -241,6 +287,8 @@ impl ToTokens for NodeToken {
             impl #struct_impl_generics #crate_name::Model for #struct_name_ident #struct_ty_generics #struct_where_clause {
                 type Id = #table_id_type;
                 type StructRenamedCreator = #struct_with_renamed_serialized_fields;
+                const TABLE_NAME: &'static str = #table_str;
+                const SERIALIZABLE_FIELD_NAMES: &'static [&'static str] = &[#( #serialized_db_field_names), *];
 
                 fn table() -> #crate_name::Table {
                     #table_str.into()
@@ -291,8 +339,28 @@ impl ToTokens for NodeToken {
                        #( #field_definitions), *
                     ]
                 }
+
+                fn define_indexes() -> ::std::vec::Vec<#crate_name::Raw> {
+                    ::std::vec![
+                       #( #index_definitions), *
+                    ]
+                }
+
+                fn define_events() -> ::std::vec::Vec<#crate_name::Raw> {
+                    #event_schema_fields_binding
+
+                    ::std::vec![
+                       #( #event_definitions), *
+                    ]
+                }
+
+                #soft_delete_field
+
+                #version_field
             }
 
+            #account_impl
+
             #[allow(non_snake_case)]
             pub mod #module_name_rexported {
                 pub use super::#module_name_internal::#_____schema_def::__Schema__ as Schema;
@@ -0,0 +1,37 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::str::FromStr;
+
+use darling::FromMeta;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use surreal_query_builder::sql;
+
+/// Change feed duration table attribute e.g
+/// #[orm(table = reading, changefeed = "1d")]
+#[derive(Debug, Clone)]
+pub struct AttributeChangefeed(std::time::Duration);
+
+impl FromMeta for AttributeChangefeed {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        sql::Duration::from_str(value)
+            .map(|duration| Self(duration.0))
+            .map_err(|_| {
+                darling::Error::custom(format!(
+                    "Invalid changefeed duration `{value}`. Expected a duration e.g `1h`, `7d` or `1w`"
+                ))
+            })
+    }
+}
+
+impl ToTokens for AttributeChangefeed {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let secs = self.0.as_secs();
+        let nanos = self.0.subsec_nanos();
+        quote!(::std::time::Duration::new(#secs, #nanos)).to_tokens(tokens);
+    }
+}
@@ -63,6 +63,9 @@ pub struct TableDeriveAttributes {
     #[darling(default)]
     pub(crate) flexible: Option<bool>,
 
+    #[darling(default)]
+    pub(crate) changefeed: Option<AttributeChangefeed>,
+
     // #[darling(default, rename = "as_")]
     pub(crate) as_: Option<AttributeAs>,
 
@@ -70,6 +73,20 @@ pub struct TableDeriveAttributes {
 
     #[darling(default)]
     pub(crate) define: Option<AttributeDefine>,
+
+    #[darling(multiple, rename = "index")]
+    pub(crate) indexes: Vec<TableIndexAttr>,
+
+    #[darling(multiple, rename = "event")]
+    pub(crate) events: Vec<TableEventAttr>,
+
+    /// Name of the scope the node signs up and signs in with
+    #[darling(default)]
+    pub(crate) account: Option<String>,
+
+    /// Database name of the field set to the time a record is deleted instead of deleting it
+    #[darling(default)]
+    pub(crate) soft_delete: Option<String>,
 }
 
 impl TableDeriveAttributes {
@@ -95,6 +112,7 @@ impl TableDeriveAttributes {
             ref drop,
             ref flexible,
             ref schemafull,
+            ref changefeed,
             ref as_,
             ref permissions,
             ref define,
@@ -108,6 +126,7 @@ impl TableDeriveAttributes {
                 || as_.is_some()
                 || schemafull.is_some()
                 || flexible.is_some()
+                || changefeed.is_some()
                 || permissions.is_some())
         {
             return Err(
@@ -118,6 +137,7 @@ impl TableDeriveAttributes {
                             flexible,
                             as,
                             schemafull,
+                            changefeed,
                             permissions",
                 )
                 .into(),
@@ -147,6 +167,10 @@ impl TableDeriveAttributes {
             define_table_methods.push(quote!(.schemafull()))
         }
 
+        if let Some(changefeed) = changefeed {
+            define_table_methods.push(quote!(.changefeed(#changefeed)))
+        }
+
         if let Some(permissions) = permissions {
             define_table_methods.push(permissions.to_token_stream());
         }
@@ -0,0 +1,19 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use darling::FromMeta;
+
+use crate::models::*;
+
+/// Event on the table e.g
+/// #[orm(table = user, event(name = "audit", when = cond(age.gt(18)), then = select(All).from(User::table())))]
+/// The fields of the table schema are in scope of `when` and `then`.
+#[derive(Debug, Clone, FromMeta)]
+pub struct TableEventAttr {
+    pub(crate) name: String,
+    pub(crate) when: ExprOrPath,
+    pub(crate) then: ExprOrPath,
+}
@@ -0,0 +1,28 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use darling::{util::PathList, FromMeta};
+
+/// Index on one or more fields of the table e.g
+/// #[orm(table = user, index(unique, fields(email, age), name = "email_age_index"))]
+#[derive(Debug, Clone, FromMeta)]
+pub struct TableIndexAttr {
+    /// Name of the index. Defaults to the fields names joined with `_` and suffixed with `_index`
+    #[darling(default)]
+    pub(crate) name: Option<String>,
+    /// The struct fields the index is defined on
+    pub(crate) fields: PathList,
+    #[darling(default)]
+    pub(crate) unique: bool,
+}
+
+impl TableIndexAttr {
+    pub fn index_name(&self, db_field_names: &[String]) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("{}_index", db_field_names.join("_")))
+    }
+}
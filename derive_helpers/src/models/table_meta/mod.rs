@@ -3,9 +3,15 @@
  * Email: oyelowo.oss@gmail.com
  * Copyright (c) 2024 Oyelowo Oyedayo
  */
+mod changefeed;
 mod derive_attributes;
+mod event;
+mod index;
 mod model_attributes;
 mod table;
 
+pub use changefeed::*;
 pub use derive_attributes::*;
+pub use event::*;
+pub use index::*;
 pub use model_attributes::*;
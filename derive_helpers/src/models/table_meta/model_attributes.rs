@@ -117,6 +117,22 @@ impl<'a> ModelAttributes<'a> {
             .fields)
     }
 
+    pub fn indexes(&self) -> &[TableIndexAttr] {
+        match self {
+            ModelAttributes::Node(node) => &node.0.indexes,
+            ModelAttributes::Edge(edge) => &edge.0.indexes,
+            ModelAttributes::Object(_object) => &[],
+        }
+    }
+
+    pub fn events(&self) -> &[TableEventAttr] {
+        match self {
+            ModelAttributes::Node(node) => &node.0.events,
+            ModelAttributes::Edge(edge) => &edge.0.events,
+            ModelAttributes::Object(_object) => &[],
+        }
+    }
+
     pub fn rename_all(&self) -> Option<&Rename> {
         match self {
             ModelAttributes::Node(node) => node.0.rename_all.as_ref(),
@@ -17,9 +17,129 @@ impl Codegen<'_> {
         self.static_assertions
             .push(self.static_assertion_field_value(self.table_derive_attributes())?);
         self.field_definitions.extend(self.field_defintion_db()?);
+        self.index_definitions
+            .extend(self.field_index_definition()?);
         Ok(())
     }
 
+    /// Generates the indexes and events declared with the struct level
+    /// `index` and `event` attributes.
+    pub fn create_table_index_and_event_definitions(&mut self) -> ExtractorResult<()> {
+        let crate_name = get_crate_name(false);
+        let model_attributes = self.table_derive_attributes();
+
+        for index in model_attributes.indexes() {
+            let db_field_names = index
+                .fields
+                .iter()
+                .map(|path| self.find_db_field_name(path))
+                .collect::<ExtractorResult<Vec<_>>>()?;
+            let index_name = index.index_name(&db_field_names);
+            self.index_definitions.push(Self::define_index_token(
+                &index_name,
+                &db_field_names,
+                index.unique,
+            ));
+        }
+
+        for TableEventAttr { name, when, then } in model_attributes.events() {
+            self.event_definitions.push(
+                quote!(
+                    #crate_name::statements::define_event(#name)
+                        .on_table(Self::table())
+                        .when(#when)
+                        .then(#then)
+                        .to_raw()
+                )
+                .into(),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn field_index_definition(&self) -> ExtractorResult<Option<DefineIndexStatementToken>> {
+        let field_receiver = self.field_receiver();
+        let Some(index) = field_receiver.index.as_ref() else {
+            return Ok(None);
+        };
+
+        if !self.data_type().is_node_or_edge() || self.skip_field()? {
+            return Err(syn::Error::new_spanned(
+                field_receiver.ident()?,
+                "The index attribute can only be used on the database fields of a Node or an Edge",
+            )
+            .into());
+        }
+
+        let index = index.clone().unwrap_or_default();
+        let db_field_name = field_receiver
+            .db_field_name(&self.table_derive_attributes().casing()?)?
+            .to_string();
+        let index_name = index.index_name(&db_field_name);
+
+        Ok(Some(Self::define_index_token(
+            &index_name,
+            &[db_field_name],
+            index.unique,
+        )))
+    }
+
+    fn define_index_token(
+        index_name: &str,
+        db_field_names: &[String],
+        unique: bool,
+    ) -> DefineIndexStatementToken {
+        let crate_name = get_crate_name(false);
+        let unique = unique.then(|| quote!(.unique()));
+
+        quote!(
+            #crate_name::statements::define_index(#index_name)
+                .on_table(#crate_name::Table::from(Self::table()))
+                .fields(::std::vec![#( #crate_name::Field::new(#db_field_names) ), *])
+                #unique
+                .to_raw()
+        )
+        .into()
+    }
+
+    /// Finds the database name of a struct field referenced in a struct level attribute
+    /// by either its rust name or its serialized name.
+    fn find_db_field_name(&self, path: &syn::Path) -> ExtractorResult<String> {
+        let model_attributes = self.table_derive_attributes();
+        let casing = model_attributes.casing()?;
+        let mut db_field_names = vec![];
+
+        for field_receiver in model_attributes.fields()? {
+            if field_receiver
+                .to_relation_type(model_attributes)
+                .is_relate_graph()
+                || field_receiver.skip_serializing
+                || field_receiver.skip
+            {
+                continue;
+            }
+
+            let db_field_name = field_receiver.db_field_name(&casing)?.to_string();
+            if path.is_ident(&field_receiver.ident()?.to_string()) || path.is_ident(&db_field_name)
+            {
+                return Ok(db_field_name);
+            }
+            db_field_names.push(db_field_name);
+        }
+
+        Err(syn::Error::new_spanned(
+            path,
+            format!(
+                "`{}` is not a field of `{}`. Valid fields are: {}",
+                path.to_token_stream(),
+                model_attributes.ident().to_token_stream(),
+                db_field_names.join(", ")
+            ),
+        )
+        .into())
+    }
+
     pub fn skip_field(&self) -> ExtractorResult<bool> {
         let field_receiver = self.field_receiver();
         let model_attributes = self.table_derive_attributes();
@@ -57,6 +177,20 @@ impl Codegen<'_> {
             define_field_methods.push(quote!(.value(#value)));
         }
 
+        let managed_value = if field_receiver.created_at {
+            Some("$before OR time::now()")
+        } else if field_receiver.updated_at {
+            Some("time::now()")
+        } else if field_receiver.version {
+            Some("($before OR 0) + 1")
+        } else {
+            None
+        };
+        if let Some(managed_value) = managed_value {
+            define_field_methods
+                .push(quote!(.value_expression(#crate_name::Raw::new(#managed_value))));
+        }
+
         if let Some(permissions) = field_receiver.permissions.as_ref() {
             define_field_methods.push(permissions.into_token_stream());
         }
@@ -87,6 +221,36 @@ impl Codegen<'_> {
             all_field_defintions.push(quote!(#define.to_raw()).into());
         }
 
+        let vector_index = match (field_receiver.mtree.as_ref(), field_receiver.hnsw.as_ref()) {
+            (Some(_), Some(_)) => {
+                return Err(syn::Error::new_spanned(
+                    field_receiver.ident()?,
+                    "A field can have either an mtree or an hnsw vector index, not both",
+                )
+                .into())
+            }
+            (Some(mtree), None) => Some((
+                mtree.index_name(&db_field_name.to_string()),
+                mtree.mtree_token(),
+            )),
+            (None, Some(hnsw)) => Some((
+                hnsw.index_name(&db_field_name.to_string()),
+                hnsw.hnsw_token(),
+            )),
+            (None, None) => None,
+        };
+
+        if let Some((index_name, vector_index_method)) = vector_index {
+            let vector_index_definition = quote!(
+                #crate_name::statements::define_index(#index_name)
+                    .on_table(#crate_name::Table::from(Self::table()))
+                    .fields(#crate_name::Field::new(#db_field_name))
+                    #vector_index_method
+                    .to_raw()
+            );
+            all_field_defintions.push(vector_index_definition.into());
+        }
+
         Ok(all_field_defintions)
     }
 
@@ -120,6 +284,9 @@ impl Codegen<'_> {
             permissions,
             item_assert,
             relate,
+            created_at,
+            updated_at,
+            version,
             ..
         } = field_receiver;
         let ident = field_receiver.ident()?;
@@ -142,6 +309,26 @@ impl Codegen<'_> {
     item_assert"#).into());
         }
 
+        let managed_attributes_count = [created_at, updated_at, version]
+            .into_iter()
+            .filter(|attr| **attr)
+            .count();
+        if managed_attributes_count > 1 || (managed_attributes_count == 1 && value.is_some()) {
+            return Err(syn::Error::new_spanned(
+                ident,
+                "Invalid combination. Only one of created_at, updated_at, version and value can be used on a field as they all set the value of the field.",
+            )
+            .into());
+        }
+
+        if define.is_some() && managed_attributes_count == 1 {
+            return Err(syn::Error::new_spanned(
+                db_field_name,
+                "Invalid combination. When `define`, created_at, updated_at and version cannot be used as the field is fully defined by `define`.",
+            )
+            .into());
+        }
+
         if relate.is_some()
             && (define.is_some()
                 || assert_.is_some()
@@ -43,6 +43,9 @@ impl Codegen<'_> {
             quote!(pub #field_ident_normalized: #_____field_names::#field_name_pascalized, ).into(),
         );
 
+        self.schema_struct_fields_idents
+            .push(field_ident_normalized.clone());
+
         self.schema_struct_fields_names_kv
             .push(quote!(#field_ident_normalized: #field_name_serialized.into(),).into());
 
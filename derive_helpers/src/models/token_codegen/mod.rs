@@ -68,6 +68,10 @@ pub struct Codegen<'a> {
     /// are not serialized because they are not directly part of the struct.
     /// They are readonly fields that are accessed through the relation field.
     pub serialized_fmt_db_field_names_instance: Vec<SerializableField>,
+    /// Same as `serialized_fmt_db_field_names_instance` but as plain strings for constants.
+    pub serialized_db_field_names: Vec<String>,
+    /// The db names of the fields marked with the version attribute
+    pub version_fields: Vec<String>,
     /// The name of the all fields that are linked i.e line_one, line_many, or line_self.
     pub linked_fields: Vec<LinkedField>,
     /// The names of link_one fields
@@ -212,6 +216,13 @@ pub struct Codegen<'a> {
     /// ```
     pub record_link_fields_methods: Vec<LinkFieldTraversalMethodToken>,
     pub field_definitions: Vec<DefineFieldStatementToken>,
+    /// Indexes from the field level index attribute and the struct level index attributes
+    pub index_definitions: Vec<DefineIndexStatementToken>,
+    /// Events from the struct level event attributes
+    pub event_definitions: Vec<DefineEventStatementToken>,
+    /// The normalized idents of the schema fields e.g timeWritten, used to bring the schema
+    /// fields in scope of the event attributes expressions
+    pub schema_struct_fields_idents: Vec<FieldIdentNormalized>,
     pub field_metadata: Vec<FieldMetadataToken>,
     pub node_edge_metadata: NodeEdgeMetadataLookupTable<'a>,
     pub fields_relations_aliased: Vec<FieldsRelationsAliased>,
@@ -308,6 +319,14 @@ impl<'a> Codegen<'a> {
             tokens_generator.create_relation_aliases_struct_fields_types_kv()?;
             tokens_generator.create_struct_partial_metadata()?;
         }
+        tokens_generator.create_table_index_and_event_definitions()?;
+
+        if tokens_generator.version_fields.len() > 1 {
+            return Err(ExtractorError::Darling(darling::Error::custom(format!(
+                "Only one field can be marked with the version attribute but found: {}",
+                tokens_generator.version_fields.join(", ")
+            ))));
+        }
         Ok(tokens_generator)
     }
 }
@@ -22,6 +22,11 @@ impl Codegen<'_> {
         if !relation_type.is_relate_graph() {
             self.serialized_fmt_db_field_names_instance
                 .push(quote!(#db_field_name .into()).into());
+            self.serialized_db_field_names
+                .push(db_field_name.to_string());
+            if field_receiver.version {
+                self.version_fields.push(db_field_name.to_string());
+            }
         }
 
         let serialized_field_fmt = || quote!(#crate_name::Field::new(#db_field_name));
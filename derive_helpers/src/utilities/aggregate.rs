@@ -0,0 +1,298 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use super::ident::FieldAttribute;
+use crate::models::{CaseString, ExtractorResult, Rename, StructGenerics, StructLevelCasing};
+
+use darling::{ast::Data, util, FromDeriveInput, FromField, FromMeta};
+use proc_macro2::TokenStream;
+use proc_macros_helpers::get_crate_name;
+use quote::{quote, ToTokens};
+use std::str::FromStr;
+use syn::{Expr, Ident, Type};
+
+#[derive(Clone, Debug, FromDeriveInput)]
+#[darling(attributes(agg, serde), forward_attrs(allow, doc, cfg))]
+pub struct AggregateDeriveAttributes {
+    pub(crate) ident: Ident,
+    pub(crate) generics: StructGenerics,
+    pub data: Data<util::Ignored, AggregateFieldAttribute>,
+
+    #[darling(default)]
+    pub(crate) rename_all: Option<Rename>,
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug, FromField)]
+#[darling(attributes(agg, serde), forward_attrs(allow, doc, cfg))]
+pub struct AggregateFieldAttribute {
+    pub(crate) ident: Option<Ident>,
+    pub(crate) ty: Type,
+    pub(crate) attrs: Vec<syn::Attribute>,
+
+    /// The records are grouped by the value e.g group_key = Order::schema().country
+    #[darling(default)]
+    pub(crate) group_key: Option<Expr>,
+
+    /// math::sum of the value e.g sum = Order::schema().amount
+    #[darling(default)]
+    pub(crate) sum: Option<Expr>,
+
+    /// math::mean of the value e.g mean = Order::schema().amount
+    #[darling(default)]
+    pub(crate) mean: Option<Expr>,
+
+    /// math::min of the value e.g min = Order::schema().amount
+    #[darling(default)]
+    pub(crate) min: Option<Expr>,
+
+    /// math::max of the value e.g max = Order::schema().amount
+    #[darling(default)]
+    pub(crate) max: Option<Expr>,
+
+    /// Number of records e.g count, or of the truthy values e.g count = Order::schema().paid
+    #[darling(default)]
+    pub(crate) count: Option<Countable>,
+
+    // Serde attributes
+    #[darling(default)]
+    pub(crate) rename: Option<Rename>,
+
+    #[darling(default)]
+    default: util::Ignored,
+
+    #[darling(default)]
+    alias: util::Ignored,
+
+    #[darling(default)]
+    with: util::Ignored,
+
+    #[darling(default)]
+    deserialize_with: util::Ignored,
+}
+
+/// What `count` counts: every record when used as a word, otherwise the given value
+#[derive(Clone, Debug)]
+pub(crate) struct Countable(Option<Expr>);
+
+impl FromMeta for Countable {
+    fn from_word() -> darling::Result<Self> {
+        Ok(Self(None))
+    }
+
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(Self(Some(expr.clone())))
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct AggregateMetadata {
+    pub(crate) projections: Vec<TokenStream>,
+    pub(crate) group_keys: Vec<TokenStream>,
+}
+
+impl AggregateFieldAttribute {
+    fn aggregation(&self, crate_name: &TokenStream) -> ExtractorResult<(TokenStream, bool)> {
+        let math_functions = [
+            ("sum", &self.sum),
+            ("mean", &self.mean),
+            ("min", &self.min),
+            ("max", &self.max),
+        ];
+        let mut aggregations = math_functions
+            .into_iter()
+            .filter_map(|(name, value)| {
+                value.as_ref().map(|value| {
+                    let function = quote::format_ident!("{name}_fn");
+                    (
+                        quote!(#crate_name::functions::math::#function(#crate_name::Field::from(#value))),
+                        false,
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if let Some(Countable(count)) = &self.count {
+            let countable = match count {
+                Some(value) => quote!(#value),
+                None => quote!(#crate_name::Empty),
+            };
+            aggregations.push((quote!(#crate_name::functions::count_fn(#countable)), false));
+        }
+        if let Some(group_key) = &self.group_key {
+            aggregations.push((quote!(#crate_name::Field::from(#group_key)), true));
+        }
+
+        if aggregations.len() != 1 {
+            let error = darling::Error::custom(
+                "Expected exactly one of `group_key`, `sum`, `mean`, `min`, `max` or `count`",
+            );
+            return Err(match &self.ident {
+                Some(ident) => error.with_span(ident),
+                None => error,
+            }
+            .into());
+        }
+        Ok(aggregations.remove(0))
+    }
+
+    fn alias(&self, struct_casing: &StructLevelCasing) -> ExtractorResult<String> {
+        let field = FieldAttribute {
+            ident: self.ident.clone(),
+            ty: self.ty.clone(),
+            attrs: self.attrs.clone(),
+            rename: self.rename.clone(),
+        };
+        Ok(field
+            .field_name_normaized_de_no_raw(struct_casing)?
+            .to_string())
+    }
+}
+
+impl AggregateDeriveAttributes {
+    pub fn casing(&self) -> ExtractorResult<StructLevelCasing> {
+        let struct_level_casing = self
+            .rename_all
+            .as_ref()
+            .and_then(|case| case.serialize.clone())
+            .map(|case| CaseString::from_str(case.as_str()));
+
+        let casing = match struct_level_casing {
+            Some(Ok(case)) => case,
+            Some(Err(e)) => return Err(darling::Error::custom(e.to_string()).into()),
+            None => CaseString::None,
+        };
+        Ok(casing.into())
+    }
+
+    pub(crate) fn get_meta(&self) -> ExtractorResult<AggregateMetadata> {
+        let crate_name = get_crate_name(false);
+        let fields =
+            self.data.as_ref().take_struct().ok_or(
+                darling::Error::custom("Only structs are supported").with_span(&self.ident),
+            )?;
+        let struct_casing = self.casing()?;
+
+        let mut meta = AggregateMetadata::default();
+
+        for field in fields {
+            let (aggregation, is_group_key) = field.aggregation(&crate_name)?;
+            let alias = field.alias(&struct_casing)?;
+
+            meta.projections.push(quote!(
+                #crate_name::ValueLike::from(#crate_name::Aliasable::__as__(&#aggregation, #alias))
+            ));
+            if is_group_key {
+                meta.group_keys
+                    .push(quote!(#crate_name::Field::new(#alias)));
+            }
+        }
+
+        Ok(meta)
+    }
+}
+
+impl ToTokens for AggregateDeriveAttributes {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let crate_name = get_crate_name(false);
+        let struct_name_ident = &self.ident;
+        let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+            &self.generics.split_for_impl();
+        let AggregateMetadata {
+            projections,
+            group_keys,
+        } = match self.get_meta() {
+            Ok(meta) => meta,
+            Err(err) => return tokens.extend(err.write_errors()),
+        };
+
+        tokens.extend(quote!(
+            impl #struct_impl_generics #crate_name::Aggregate for #struct_name_ident #struct_ty_generics #struct_where_clause {
+                fn projections() -> ::std::vec::Vec<#crate_name::ValueLike> {
+                    ::std::vec![#( #projections ),*]
+                }
+
+                fn group_keys() -> ::std::vec::Vec<#crate_name::Field> {
+                    ::std::vec![#( #group_keys ),*]
+                }
+            }
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aggregate_derive_with_group_keys() {
+        let input = syn::parse_quote! {
+            #[derive(Aggregate)]
+            #[serde(rename_all = "camelCase")]
+            struct SalesPerCountry {
+                #[agg(group_key = Order::schema().country)]
+                country: String,
+                #[agg(sum = Order::schema().amount)]
+                total_amount: f64,
+                #[agg(count)]
+                #[serde(rename = "orders")]
+                order_count: u64,
+            }
+        };
+
+        let aggregate = AggregateDeriveAttributes::from_derive_input(&input).unwrap();
+
+        let expected = quote!(
+            impl surreal_orm::Aggregate for SalesPerCountry {
+                fn projections() -> ::std::vec::Vec<surreal_orm::ValueLike> {
+                    ::std::vec![
+                        surreal_orm::ValueLike::from(surreal_orm::Aliasable::__as__(
+                            &surreal_orm::Field::from(Order::schema().country),
+                            "country"
+                        )),
+                        surreal_orm::ValueLike::from(surreal_orm::Aliasable::__as__(
+                            &surreal_orm::functions::math::sum_fn(surreal_orm::Field::from(
+                                Order::schema().amount
+                            )),
+                            "totalAmount"
+                        )),
+                        surreal_orm::ValueLike::from(surreal_orm::Aliasable::__as__(
+                            &surreal_orm::functions::count_fn(surreal_orm::Empty),
+                            "orders"
+                        ))
+                    ]
+                }
+
+                fn group_keys() -> ::std::vec::Vec<surreal_orm::Field> {
+                    ::std::vec![surreal_orm::Field::new("country")]
+                }
+            }
+        );
+
+        assert_eq!(
+            aggregate.to_token_stream().to_string(),
+            expected.to_string()
+        );
+    }
+
+    #[test]
+    fn test_aggregate_derive_requires_one_aggregation_per_field() {
+        let input = syn::parse_quote! {
+            #[derive(Aggregate)]
+            struct Sales {
+                #[agg(sum = Order::schema().amount, count)]
+                total: f64,
+            }
+        };
+
+        let aggregate = AggregateDeriveAttributes::from_derive_input(&input).unwrap();
+
+        assert!(aggregate
+            .to_token_stream()
+            .to_string()
+            .contains("Expected exactly one of"));
+    }
+}
@@ -3,10 +3,12 @@
  * Email: oyelowo.oss@gmail.com
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
+pub mod aggregate;
 pub mod ident;
 pub mod pick;
 pub mod pickable_table_derive_attr;
 
+pub use aggregate::*;
 pub use ident::*;
 pub use pick::*;
 pub use pickable_table_derive_attr::*;
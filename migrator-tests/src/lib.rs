@@ -12,9 +12,9 @@ use surreal_models::migrations::{
 };
 use surreal_orm::{
     migrator::{
-        Basename, Checksum, DatabaseConnection, DbInfo, Down, Extension, FastForwardDelta,
+        Basename, Check, Checksum, DatabaseConnection, DbInfo, Down, Extension, FastForwardDelta,
         Generate, Init, List, Migration, MigrationFilename, MigrationFlag, Migrator, MockPrompter,
-        Mode, Prune, RenameOrDelete, Reset, RollbackStrategyStruct, Status, SubCommand, Up,
+        Mode, Prune, RenameOrDelete, Reset, RollbackStrategyStruct, Squash, Status, SubCommand, Up,
     },
     statements::info_for,
     DbResources, Runnable,
@@ -364,6 +364,30 @@ impl TestConfig {
         self
     }
 
+    pub async fn run_check(&mut self, codebase_resources: impl DbResources) -> &mut Self {
+        self.set_cmd(Check)
+            .run(
+                Some(codebase_resources),
+                MockPrompter::builder()
+                    .allow_empty_migrations_gen(true)
+                    .rename_or_delete_single_field_change(RenameOrDelete::Rename)
+                    .build(),
+            )
+            .await
+    }
+
+    pub async fn run_squash(&mut self, till: MigrationFilename) -> &mut Self {
+        self.set_cmd(Squash::builder().till(till).build())
+            .run(
+                Some(ResourcesV10),
+                MockPrompter::builder()
+                    .allow_empty_migrations_gen(true)
+                    .rename_or_delete_single_field_change(RenameOrDelete::Rename)
+                    .build(),
+            )
+            .await
+    }
+
     pub async fn run_gen(
         &mut self,
         gen_cmd: Generate,
@@ -397,6 +421,32 @@ impl TestConfig {
         .await
     }
 
+    pub async fn run_down_dry_run(
+        &mut self,
+        rollback_strategy: &RollbackStrategyStruct,
+    ) -> &mut Self {
+        self.set_cmd(SubCommand::Down(
+            Down::builder()
+                .strategy(rollback_strategy.clone())
+                .prune(false)
+                .dry_run(true)
+                .build(),
+        ))
+        .run(None::<MockEmptyResource>, MockPrompter::default())
+        .await
+    }
+
+    pub async fn run_up_dry_run(&mut self, fwd_delta: &FastForwardDelta) -> &mut Self {
+        self.set_cmd(SubCommand::Up(
+            Up::builder()
+                .fast_forward(fwd_delta.clone())
+                .dry_run(true)
+                .build(),
+        ))
+        .run(None::<MockEmptyResource>, MockPrompter::default())
+        .await
+    }
+
     pub async fn run_up(&mut self, fwd_delta: &FastForwardDelta) -> &mut Self {
         self.set_cmd(SubCommand::Up(
             Up::builder().fast_forward(fwd_delta.clone()).build(),
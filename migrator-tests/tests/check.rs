@@ -0,0 +1,154 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use migrator_tests::{current_function, TestConfig};
+use surreal_models::migrations::{ResourcesV10, ResourcesV12};
+use surreal_orm::migrator::{DriftKind, FastForwardDelta, Mode, SchemaDriftReport};
+use tempfile::tempdir;
+use test_case::test_case;
+
+#[test_case(Mode::Strict, true; "Reversible Strict")]
+#[test_case(Mode::Lax, true; "Reversible Lax")]
+#[test_case(Mode::Strict, false; "Non-Reversible Strict")]
+#[test_case(Mode::Lax, false; "Non-Reversible Lax")]
+#[tokio::test]
+async fn test_check_passes_when_database_matches_migrations(mode: Mode, reversible: bool) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(reversible)
+        .await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    let report = SchemaDriftReport::detect(
+        conf.migrator.db(),
+        &conf.migrator.file_manager(),
+        &ResourcesV10,
+    )
+    .await
+    .expect("Failed to detect drift");
+
+    assert!(!report.has_drift(), "{report}");
+    conf.run_check(ResourcesV10).await;
+}
+
+#[test_case(true; "Reversible")]
+#[test_case(false; "Non-Reversible")]
+#[tokio::test]
+async fn test_check_does_not_report_pending_migrations_as_drift(reversible: bool) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(reversible)
+        .await;
+    conf.run_up(&FastForwardDelta::builder().number(5).build())
+        .await;
+
+    let report = SchemaDriftReport::detect(
+        conf.migrator.db(),
+        &conf.migrator.file_manager(),
+        &ResourcesV10,
+    )
+    .await
+    .expect("Failed to detect drift");
+
+    assert!(!report.has_drift(), "{report}");
+}
+
+#[tokio::test]
+async fn test_check_detects_hand_edited_database() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    conf.migrator
+        .db()
+        .query(
+            "DEFINE TABLE hand_made SCHEMALESS;
+            DEFINE FIELD nickname ON animal TYPE string;
+            DEFINE FIELD species ON animal TYPE option<string>;",
+        )
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    let report = SchemaDriftReport::detect(
+        conf.migrator.db(),
+        &conf.migrator.file_manager(),
+        &ResourcesV10,
+    )
+    .await
+    .expect("Failed to detect drift");
+
+    let drifted = report
+        .database
+        .iter()
+        .map(|drift| drift.resource.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        drifted,
+        vec![
+            "TABLE hand_made",
+            "FIELD nickname ON animal",
+            "FIELD species ON animal"
+        ]
+    );
+    assert!(matches!(
+        report.database[0].kind,
+        DriftKind::NotInMigrations { .. }
+    ));
+    assert!(matches!(
+        report.database[2].kind,
+        DriftKind::DefinitionDiffers { .. }
+    ));
+    assert!(report.codebase.is_empty(), "{report}");
+}
+
+#[tokio::test]
+async fn test_check_detects_codebase_changes_without_migration() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    let report = SchemaDriftReport::detect(
+        conf.migrator.db(),
+        &conf.migrator.file_manager(),
+        &ResourcesV12,
+    )
+    .await
+    .expect("Failed to detect drift");
+
+    assert!(report.database.is_empty(), "{report}");
+    assert!(report
+        .codebase
+        .iter()
+        .any(|drift| drift.resource == "TABLE student"));
+}
+
+#[tokio::test]
+#[should_panic(expected = "Schema drift detected")]
+async fn test_check_command_fails_on_drift() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(false).await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    conf.migrator
+        .db()
+        .query("REMOVE TABLE crop;")
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    conf.run_check(ResourcesV10).await;
+}
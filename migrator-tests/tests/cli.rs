@@ -1,4 +1,5 @@
 use std::process::{Command, Stdio};
+use surreal_orm::{migrator::define_mode_parser, statements::DefineMode};
 use tempfile::tempdir;
 
 #[tokio::test]
@@ -64,3 +65,16 @@ async fn test_generate_command_success() {
     //     .spawn()
     //     .expect("Failed to run command");
 }
+
+#[test]
+fn test_define_mode_parser() {
+    assert_eq!(
+        define_mode_parser("if-not-exists"),
+        Ok(DefineMode::IfNotExists)
+    );
+    assert_eq!(define_mode_parser("Overwrite"), Ok(DefineMode::Overwrite));
+    assert_eq!(
+        define_mode_parser("replace"),
+        Err("Invalid define mode: replace. Expected one of if-not-exists, overwrite".to_string())
+    );
+}
@@ -4,7 +4,10 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 use migrator_tests::{current_function, AssertionArg, TestConfig};
-use surreal_orm::migrator::{FastForwardDelta, MigrationFilename, Mode, RollbackStrategyStruct};
+use surreal_orm::migrator::{
+    FastForwardDelta, MigrationFilename, Mode, RollbackOptions, RollbackStrategy,
+    RollbackStrategyStruct,
+};
 use tempfile::tempdir;
 use test_case::test_case;
 
@@ -639,3 +642,81 @@ async fn rollingback_to_nonexisting_filecursor_panics(mode: Mode) {
     .await;
     conf.assert_migration_queries_snapshot();
 }
+
+#[test_case(Mode::Strict; "Reversible Strict")]
+#[test_case(Mode::Lax; "Reversible Lax")]
+#[tokio::test]
+async fn test_dry_run_rollback_makes_no_changes(mode: Mode) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    let rollback_strategy = RollbackStrategyStruct::builder().number(3).build();
+    conf.run_down_dry_run(&rollback_strategy).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 12,
+        expected_db_mig_meta_count: 12,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+
+    let plan = conf
+        .migrator
+        .file_manager()
+        .two_way()
+        .get_down_migrations_plan(
+            conf.migrator.db(),
+            RollbackOptions::new()
+                .strategy(RollbackStrategy::from(&rollback_strategy))
+                .mode(mode),
+        )
+        .await
+        .expect("Failed to plan rollback");
+
+    let planned_files = plan
+        .migrations()
+        .iter()
+        .map(|m| m.filename.clone())
+        .collect::<Vec<_>>();
+    let expected_files = conf
+        .read_down_migrations_from_dir_sorted_asc()
+        .into_iter()
+        .rev()
+        .take(3)
+        .collect::<Vec<_>>();
+    assert_eq!(planned_files, expected_files);
+    assert_eq!(plan.metadata_queries().len(), 3);
+
+    // Checksums of both up and down files are only checked in strict mode
+    let expected_checksum_checks = if mode.is_strict() { 2 } else { 0 };
+    for migration in plan.migrations() {
+        assert_eq!(migration.checksum_checks.len(), expected_checksum_checks);
+    }
+    let plan_output = plan.to_string();
+    assert_eq!(
+        plan_output.matches("-- Checksum check:").count(),
+        3 * expected_checksum_checks
+    );
+
+    conf.run_down(&rollback_strategy, false).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 12,
+        expected_db_mig_meta_count: 9,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "migration_9_gen_after_init".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+}
@@ -3,9 +3,16 @@
  * Email: oyelowo.oss@gmail.com
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
-use surreal_orm::migrator::{
-    self, embed_migrations, DatabaseConnection, Migration, Mode, UpdateStrategy,
+use surreal_models::migrations::{planet, Planet};
+use surreal_orm::{
+    migrator::{
+        self, embed_migrations, DataMigrations, DatabaseConnection, Migration, MigrationError,
+        Mode, RollbackStrategy, UpdateStrategy,
+    },
+    statements::{select, update},
+    All, Model, Raw, ReturnableSelect, SchemaGetter, SetterAssignable, ToRaw,
 };
+use surrealdb::sql;
 
 // Embed migrations as constant
 const MIGRATIONS_ONE_WAY: migrator::EmbeddedMigrationsOneWay =
@@ -14,6 +21,9 @@ const MIGRATIONS_ONE_WAY: migrator::EmbeddedMigrationsOneWay =
 const MIGRATIONS_TWO_WAY: migrator::EmbeddedMigrationsTwoWay =
     embed_migrations!("tests/migrations-twoway", strict);
 
+const MIGRATIONS_DATA: migrator::EmbeddedMigrationsTwoWay =
+    embed_migrations!("tests/migrations-data", strict);
+
 #[test]
 fn test_embedded() {
     assert_eq!(MIGRATIONS_ONE_WAY.get_migrations().len(), 2);
@@ -80,3 +90,226 @@ async fn test_embedded_run_two_way() {
     insta::assert_debug_snapshot!(db_migrations_meta);
     assert_eq!(db_migrations_meta.len(), 1);
 }
+
+fn label_planets_data_migrations() -> DataMigrations {
+    DataMigrations::new()
+        .up("20240102000000_label_planets.up.surql", |db| async move {
+            let planet::Schema { labels, .. } = Planet::schema();
+            let planets = select(All)
+                .from(Planet::table())
+                .return_many::<Planet>(db)
+                .await?;
+
+            Ok(planets
+                .into_iter()
+                .map(|planet| {
+                    update::<Planet>(planet.id)
+                        .set(labels.equal_to(vec![planet.first_name.to_lowercase()]))
+                        .to_raw()
+                })
+                .collect())
+        })
+        .down(
+            "20240102000000_label_planets.down.surql",
+            |_db| async move {
+                let planet::Schema { labels, .. } = Planet::schema();
+                Ok(vec![update::<Planet>(Planet::table())
+                    .set(labels.equal_to(Vec::<String>::new()))
+                    .to_raw()])
+            },
+        )
+}
+
+async fn create_planets(db: surrealdb::Surreal<surrealdb::engine::any::Any>) {
+    db.query(
+        "CREATE planet:mars SET firstName = 'Mars', population = 1, labels = [], createdAt = time::now(), updatedAt = time::now();
+        CREATE planet:venus SET firstName = 'Venus', population = 1, labels = [], createdAt = time::now(), updatedAt = time::now();",
+    )
+    .await
+    .unwrap()
+    .check()
+    .unwrap();
+}
+
+async fn get_labels(db: surrealdb::Surreal<surrealdb::engine::any::Any>) -> Vec<Vec<String>> {
+    let mut planets = select(All)
+        .from(Planet::table())
+        .return_many::<Planet>(db)
+        .await
+        .unwrap();
+    planets.sort_by(|a, b| a.first_name.cmp(&b.first_name));
+    planets.into_iter().map(|p| p.labels).collect()
+}
+
+#[tokio::test]
+async fn test_embedded_data_migrations_run_in_migration_transaction() {
+    let db = DatabaseConnection::default().setup().await.db().unwrap();
+    let data_migrations = label_planets_data_migrations();
+
+    MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Number(1),
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+    create_planets(db.clone()).await;
+    assert_eq!(
+        get_labels(db.clone()).await,
+        vec![vec![], vec![]] as Vec<Vec<String>>
+    );
+
+    MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Latest,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_labels(db.clone()).await,
+        vec![vec!["mars".to_string()], vec!["venus".to_string()]]
+    );
+    let db_migrations_meta = Migration::get_all_desc(db.clone()).await;
+    assert_eq!(db_migrations_meta.len(), 2);
+    assert_eq!(
+        db_migrations_meta[0].name,
+        "20240102000000_label_planets.up.surql"
+    );
+
+    MIGRATIONS_DATA
+        .rollback_with_data_migrations(
+            db.clone(),
+            RollbackStrategy::Previous,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_labels(db.clone()).await,
+        vec![vec![], vec![]] as Vec<Vec<String>>
+    );
+    let db_migrations_meta = Migration::get_all_desc(db.clone()).await;
+    assert_eq!(db_migrations_meta.len(), 1);
+    assert_eq!(
+        db_migrations_meta[0].name,
+        "20240101000000_create_planet.up.surql"
+    );
+}
+
+#[tokio::test]
+async fn test_embedded_data_migration_not_rerun_once_applied() {
+    let db = DatabaseConnection::default().setup().await.db().unwrap();
+    let data_migrations = label_planets_data_migrations();
+
+    MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Latest,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+    create_planets(db.clone()).await;
+
+    MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Latest,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        get_labels(db.clone()).await,
+        vec![vec![], vec![]] as Vec<Vec<String>>
+    );
+    assert_eq!(Migration::get_all_desc(db.clone()).await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_embedded_data_migration_for_unknown_migration_errors() {
+    let db = DatabaseConnection::default().setup().await.db().unwrap();
+    let data_migrations = DataMigrations::new()
+        .up("20240103000000_does_not_exist.up.surql", |_db| async move {
+            Ok(vec![])
+        });
+
+    let result = MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Latest,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(MigrationError::MigrationDoesNotExist { .. })
+    ));
+    assert!(Migration::get_all_desc(db.clone()).await.is_empty());
+}
+
+#[tokio::test]
+async fn test_embedded_data_migration_sees_previous_migrations_applied() {
+    let db = DatabaseConnection::default().setup().await.db().unwrap();
+    let data_migrations =
+        DataMigrations::new().up("20240102000000_label_planets.up.surql", |db| async move {
+            let applied = Migration::get_all_desc(db).await;
+            assert_eq!(applied.len(), 1);
+            assert_eq!(applied[0].name, "20240101000000_create_planet.up.surql");
+            Ok(vec![])
+        });
+
+    MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Latest,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(Migration::get_all_desc(db.clone()).await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_embedded_data_migration_reads_tables_its_migration_defines() {
+    let db = DatabaseConnection::default().setup().await.db().unwrap();
+    let data_migrations =
+        DataMigrations::new().up("20240101000000_create_planet.up.surql", |db| async move {
+            let info: sql::Value = db.query("INFO FOR DB").await?.take(0)?;
+            let tables = info.pick(&[sql::Part::from("tables")]);
+            assert!(matches!(tables, sql::Value::Object(tables) if tables.contains_key("planet")));
+
+            Ok(vec![Raw::new(
+                "CREATE planet:earth SET firstName = 'Earth', population = 1, labels = ['home'], \
+                createdAt = time::now(), updatedAt = time::now();",
+            )])
+        });
+
+    MIGRATIONS_DATA
+        .run_with_data_migrations(
+            db.clone(),
+            UpdateStrategy::Latest,
+            Mode::Strict,
+            &data_migrations,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(get_labels(db.clone()).await, vec![vec!["home".to_string()]]);
+    assert_eq!(Migration::get_all_desc(db.clone()).await.len(), 2);
+}
@@ -6,11 +6,13 @@
 
 use migrator_tests::{current_function, AssertionArg, TestConfig};
 use surreal_models::migrations::{
-    invalid_cases, Animal, AnimalV2, Planet, PlanetV2, Resources, ResourcesV2,
+    invalid_cases, Animal, AnimalV2, Article, ArticleV2, Crop, Library, LibraryV2, Planet,
+    PlanetV2, Resources, ResourcesV11, ResourcesV12, ResourcesV13, ResourcesV2,
 };
 use surreal_orm::{
     create_table_resources,
-    migrator::{Generate, Init, MockPrompter, Mode, RenameOrDelete},
+    migrator::{Generate, Init, Migration, MockPrompter, Mode, RenameOrDelete},
+    statements::DefineMode,
     DbResources,
 };
 use tempfile::tempdir;
@@ -922,3 +924,420 @@ async fn test_should_panic_if_renaming_from_non_existing_field_in_migration_dire
     .await;
     assert!(migration_dir.exists());
 }
+
+#[test_case(Mode::Strict, true; "Reversible Strict")]
+#[test_case(Mode::Lax, true; "Reversible Lax")]
+#[test_case(Mode::Strict, false; "Non-Reversible Strict")]
+#[test_case(Mode::Lax, false; "Non-Reversible Lax")]
+#[tokio::test]
+async fn test_generates_migrations_for_all_non_table_resources(mode: Mode, reversible: bool) {
+    // Users defined with a plain password get a new random hash every time
+    // which also changes the checksums of the migration files containing them.
+    let mut settings = insta::Settings::clone_current();
+    settings.add_filter(
+        r"(DEFINE USER codebreather ON DATABASE PASSHASH )'[^']+'",
+        "$1'[password hash]'",
+    );
+    settings.add_filter(r#""[0-9a-f]{64}""#, r#""[checksum]""#);
+    let _settings_guard = settings.bind_to_scope();
+
+    let mock_prompter = || {
+        MockPrompter::builder()
+            .allow_empty_migrations_gen(false)
+            .rename_or_delete_single_field_change(RenameOrDelete::Delete)
+            .build()
+    };
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    conf.run_init(
+        Init::builder()
+            .reversible(reversible)
+            .name("migration init".into())
+            .run(true)
+            .build(),
+        ResourcesV11,
+        mock_prompter(),
+    )
+    .await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 1,
+        expected_db_mig_meta_count: 1,
+        expected_latest_migration_file_basename_normalized: Some("migration_init".into()),
+        expected_latest_db_migration_meta_basename_normalized: Some("migration_init".into()),
+        code_origin_line: std::line!(),
+    })
+    .await;
+
+    // Analyzers, functions, params, scopes, tokens and users are all
+    // either created, updated or removed in ResourcesV12.
+    conf.run_gen(
+        Generate::builder()
+            .name("non table resources changes".into())
+            .run(true)
+            .build(),
+        ResourcesV12,
+        mock_prompter(),
+    )
+    .await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 2,
+        expected_db_mig_meta_count: 2,
+        expected_latest_migration_file_basename_normalized: Some(
+            "non_table_resources_changes".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "non_table_resources_changes".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+    conf.assert_migration_queries_snapshot();
+
+    // No diff, including for the user defined with a plain password
+    // which gets a new hash every time it is defined.
+    conf.run_gen(
+        Generate::builder()
+            .name("no changes".into())
+            .run(true)
+            .build(),
+        ResourcesV12,
+        mock_prompter(),
+    )
+    .await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 2,
+        expected_db_mig_meta_count: 2,
+        expected_latest_migration_file_basename_normalized: Some(
+            "non_table_resources_changes".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "non_table_resources_changes".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+
+    // Back to the initial resources
+    conf.run_gen(
+        Generate::builder()
+            .name("non table resources reverted".into())
+            .run(true)
+            .build(),
+        ResourcesV11,
+        mock_prompter(),
+    )
+    .await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 3,
+        expected_db_mig_meta_count: 3,
+        expected_latest_migration_file_basename_normalized: Some(
+            "non_table_resources_reverted".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "non_table_resources_reverted".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+    conf.assert_migration_queries_snapshot();
+}
+
+#[test_case(RenameOrDelete::Rename; "Rename")]
+#[test_case(RenameOrDelete::Delete; "Delete")]
+#[tokio::test]
+async fn test_prompts_rename_or_delete_for_single_non_table_resource_change(
+    rename_or_delete: RenameOrDelete,
+) {
+    let mock_prompter = MockPrompter::builder()
+        .allow_empty_migrations_gen(false)
+        .rename_or_delete_single_field_change(RenameOrDelete::Rename)
+        .rename_or_delete_single_resource_change(rename_or_delete)
+        .build();
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.run_init(
+        Init::builder()
+            .reversible(true)
+            .name("migration init".into())
+            .run(true)
+            .build(),
+        ResourcesV11,
+        mock_prompter,
+    )
+    .await;
+
+    // The database user oyelowo is renamed to oyelowo_owner
+    conf.run_gen(
+        Generate::builder()
+            .name("rename user".into())
+            .run(true)
+            .build(),
+        ResourcesV13,
+        mock_prompter,
+    )
+    .await;
+    assert_eq!(Migration::get_all_desc(conf.migrator.db()).await.len(), 2);
+
+    // Both test cases share the snapshot names, so the queries are checked directly
+    let rename_user_up = conf
+        .read_migrations_from_dir_sorted_asc()
+        .into_iter()
+        .find(|filename| filename.basename() == "rename_user".into() && !filename.is_down())
+        .expect("Migration not found");
+    let queries = std::fs::read_to_string(rename_user_up.fullpath(migration_dir)).unwrap();
+    let define_new = queries
+        .find("DEFINE USER oyelowo_owner ON DATABASE")
+        .unwrap();
+    let remove_old = queries.find("REMOVE USER oyelowo ON DATABASE;").unwrap();
+    match rename_or_delete {
+        RenameOrDelete::Rename => {
+            assert!(queries.contains("-- Rename user oyelowo to oyelowo_owner"));
+            assert!(define_new < remove_old);
+        }
+        RenameOrDelete::Delete => {
+            assert!(!queries.contains("-- Rename user"));
+        }
+    }
+}
+
+#[test_case(Mode::Strict; "Strict")]
+#[test_case(Mode::Lax; "Lax")]
+#[tokio::test]
+async fn test_vector_index_dimension_or_distance_change_rebuilds_index(mode: Mode) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    #[derive(Debug, Clone)]
+    pub struct ResourcesV1;
+    impl DbResources for ResourcesV1 {
+        create_table_resources!(Article);
+    }
+    #[derive(Debug, Clone)]
+    pub struct ResourcesV2;
+    impl DbResources for ResourcesV2 {
+        create_table_resources!(ArticleV2);
+    }
+
+    conf.run_init(
+        Init::builder()
+            .reversible(true)
+            .name("migration init".into())
+            .run(false)
+            .build(),
+        ResourcesV1,
+        MockPrompter::default(),
+    )
+    .await;
+
+    conf.run_gen(
+        Generate::builder()
+            .name("vector index change".into())
+            .run(false)
+            .build(),
+        ResourcesV2,
+        MockPrompter::default(),
+    )
+    .await;
+
+    let migration_files = conf.read_migrations_from_dir_sorted_asc();
+    let read_migration = |extension: &str| {
+        let filename = migration_files
+            .iter()
+            .find(|f| {
+                f.basename().to_string() == "vector_index_change"
+                    && f.extension().to_string() == extension
+            })
+            .expect("Migration file not found");
+        std::fs::read_to_string(migration_dir.join(filename.to_string()))
+            .expect("Failed to read migration file")
+    };
+    let up = read_migration("up.surql");
+    let down = read_migration("down.surql");
+
+    let remove = "REMOVE INDEX embedding_vector_index ON TABLE article;";
+    let define_v1 = "DEFINE INDEX embedding_vector_index ON article FIELDS embedding MTREE DIMENSION 4 DIST COSINE";
+    let define_v2 = "DEFINE INDEX embedding_vector_index ON article FIELDS embedding MTREE DIMENSION 8 DIST EUCLIDEAN";
+
+    assert!(up.find(remove).unwrap() < up.find(define_v2).unwrap());
+    assert!(down.find(remove).unwrap() < down.find(define_v1).unwrap());
+}
+
+#[test_case(Mode::Strict; "Strict")]
+#[test_case(Mode::Lax; "Lax")]
+#[tokio::test]
+async fn test_index_and_event_attributes_are_picked_up_by_migrations(mode: Mode) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    #[derive(Debug, Clone)]
+    pub struct ResourcesV1;
+    impl DbResources for ResourcesV1 {
+        create_table_resources!(Library, Crop);
+    }
+    #[derive(Debug, Clone)]
+    pub struct ResourcesV2;
+    impl DbResources for ResourcesV2 {
+        create_table_resources!(LibraryV2, Crop);
+    }
+
+    conf.run_init(
+        Init::builder()
+            .reversible(true)
+            .name("migration init".into())
+            .run(false)
+            .build(),
+        ResourcesV1,
+        MockPrompter::default(),
+    )
+    .await;
+
+    conf.run_gen(
+        Generate::builder()
+            .name("library attributes change".into())
+            .run(false)
+            .build(),
+        ResourcesV2,
+        MockPrompter::default(),
+    )
+    .await;
+
+    let migration_files = conf.read_migrations_from_dir_sorted_asc();
+    let read_migration = |basename: &str, extension: &str| {
+        let filename = migration_files
+            .iter()
+            .find(|f| {
+                f.basename().to_string() == basename && f.extension().to_string() == extension
+            })
+            .expect("Migration file not found");
+        std::fs::read_to_string(migration_dir.join(filename.to_string()))
+            .expect("Failed to read migration file")
+    };
+
+    let init = read_migration("migration_init", "up.surql");
+    assert!(init.contains("DEFINE INDEX code_index ON library FIELDS code UNIQUE;"));
+    assert!(init.contains("DEFINE INDEX name_city_index ON library FIELDS name, city UNIQUE;"));
+    assert!(init.contains("DEFINE EVENT library_opened ON library WHEN isOpen = true"));
+
+    let up = read_migration("library_attributes_change", "up.surql");
+    assert!(up.contains("REMOVE INDEX code_index ON TABLE library;"));
+    assert!(up.contains("DEFINE INDEX name_city_index ON library FIELDS city, name;"));
+    assert!(up.contains(
+        "DEFINE EVENT library_opened ON library WHEN (isOpen = true) AND (city = 'Helsinki')"
+    ));
+
+    let down = read_migration("library_attributes_change", "down.surql");
+    assert!(down.contains("DEFINE INDEX code_index ON library FIELDS code UNIQUE;"));
+    assert!(down.contains("DEFINE INDEX name_city_index ON library FIELDS name, city UNIQUE;"));
+}
+
+#[test_case(Mode::Strict, DefineMode::IfNotExists; "If not exists")]
+#[test_case(Mode::Lax, DefineMode::Overwrite; "Overwrite")]
+#[tokio::test]
+async fn test_generates_idempotent_definitions_with_define_mode(
+    mode: Mode,
+    define_mode: DefineMode,
+) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    #[derive(Debug, Clone)]
+    pub struct ResourcesV1;
+    impl DbResources for ResourcesV1 {
+        create_table_resources!(Library, Planet);
+    }
+    #[derive(Debug, Clone)]
+    pub struct ResourcesV2;
+    impl DbResources for ResourcesV2 {
+        create_table_resources!(LibraryV2, PlanetV2);
+    }
+    let mock_prompter = MockPrompter::builder()
+        .allow_empty_migrations_gen(false)
+        .rename_or_delete_single_field_change(RenameOrDelete::Rename)
+        .build();
+
+    conf.run_init(
+        Init::builder()
+            .reversible(true)
+            .name("migration init".into())
+            .run(false)
+            .build(),
+        ResourcesV1,
+        mock_prompter,
+    )
+    .await;
+
+    conf.run_gen(
+        Generate::builder()
+            .name("migration gen 1".into())
+            .run(false)
+            .define_mode(define_mode)
+            .build(),
+        ResourcesV2,
+        mock_prompter,
+    )
+    .await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 2,
+        expected_db_mig_meta_count: 0,
+        expected_latest_migration_file_basename_normalized: Some("migration_gen_1".into()),
+        expected_latest_db_migration_meta_basename_normalized: None,
+        code_origin_line: std::line!(),
+    })
+    .await;
+
+    let snapshot = conf.assert_migration_queries_snapshot();
+    assert!(snapshot.contains(&format!(
+        "DEFINE FIELD {define_mode} newName ON planet TYPE string PERMISSIONS FULL;\
+        \nUPDATE planet SET newName = firstName;\
+        \nREMOVE FIELD IF EXISTS firstName ON TABLE planet;"
+    )));
+    assert!(snapshot.contains(&format!(
+        "DEFINE FIELD {define_mode} firstName ON planet TYPE string PERMISSIONS FULL;"
+    )));
+    assert!(snapshot.contains(&format!(
+        "DEFINE INDEX {define_mode} code_index ON library FIELDS code UNIQUE;"
+    )));
+    assert!(
+        snapshot.contains("DEFINE TABLE library SCHEMAFULL PERMISSIONS NONE;"),
+        "migrations generated without a define mode are left as they are"
+    );
+    // Changed definitions must still be applied to the existing resources
+    let redefine_mode = match define_mode {
+        DefineMode::IfNotExists => "".to_string(),
+        DefineMode::Overwrite => format!("{define_mode} "),
+    };
+    assert!(snapshot.contains(&format!(
+        "DEFINE INDEX {redefine_mode}name_city_index ON library FIELDS city, name;"
+    )));
+    assert!(snapshot.contains(&format!(
+        "DEFINE INDEX {redefine_mode}name_city_index ON library FIELDS name, city UNIQUE;"
+    )));
+    assert!(snapshot.contains(&format!(
+        "DEFINE EVENT {redefine_mode}library_opened ON library WHEN (isOpen = true) AND (city = 'Helsinki')"
+    )));
+    assert!(!snapshot.contains("DEFINE INDEX IF NOT EXISTS name_city_index"));
+    assert!(!snapshot.contains("DEFINE EVENT IF NOT EXISTS"));
+
+    // The generated definitions are replayed to compare against the codebase
+    // so nothing is left to generate.
+    conf.run_gen(
+        Generate::builder()
+            .name("migration gen 2".into())
+            .run(false)
+            .define_mode(define_mode)
+            .build(),
+        ResourcesV2,
+        mock_prompter,
+    )
+    .await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 2,
+        expected_db_mig_meta_count: 0,
+        expected_latest_migration_file_basename_normalized: Some("migration_gen_1".into()),
+        expected_latest_db_migration_meta_basename_normalized: None,
+        code_origin_line: std::line!(),
+    })
+    .await;
+}
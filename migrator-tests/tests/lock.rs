@@ -0,0 +1,108 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::time::Duration;
+
+use migrator_tests::{current_function, TestConfig};
+use surreal_orm::migrator::{FastForwardDelta, Migration, MigrationError, MigrationLock, Mode};
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_lock_is_exclusive_until_released() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    let db = conf.migrator.db();
+    let ttl = Duration::from_secs(60);
+
+    let lock = MigrationLock::acquire(db.clone(), "pod-a", ttl)
+        .await
+        .expect("Failed to acquire lock");
+    assert_eq!(lock.holder, "pod-a");
+
+    // Reacquiring by the same holder is allowed
+    MigrationLock::acquire(db.clone(), "pod-a", ttl)
+        .await
+        .expect("Failed to reacquire lock");
+
+    let contended = MigrationLock::acquire(db.clone(), "pod-b", ttl).await;
+    assert!(matches!(
+        contended,
+        Err(MigrationError::MigrationLocked { ref holder, .. }) if holder == "pod-a"
+    ));
+
+    // Releasing by a holder that does not own the lock is a noop
+    MigrationLock::release(db.clone(), "pod-b").await.unwrap();
+    assert_eq!(
+        MigrationLock::get(db.clone())
+            .await
+            .unwrap()
+            .unwrap()
+            .holder,
+        "pod-a"
+    );
+
+    MigrationLock::release(db.clone(), "pod-a").await.unwrap();
+    assert!(MigrationLock::get(db.clone()).await.unwrap().is_none());
+
+    let lock = MigrationLock::acquire(db.clone(), "pod-b", ttl)
+        .await
+        .expect("Failed to acquire released lock");
+    assert_eq!(lock.holder, "pod-b");
+}
+
+#[tokio::test]
+async fn test_expired_lock_can_be_taken_over() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    let db = conf.migrator.db();
+
+    MigrationLock::acquire(db.clone(), "crashed-pod", Duration::from_millis(1))
+        .await
+        .expect("Failed to acquire lock");
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let lock = MigrationLock::acquire(db.clone(), "pod-b", Duration::from_secs(60))
+        .await
+        .expect("Failed to take over expired lock");
+    assert_eq!(lock.holder, "pod-b");
+}
+
+#[tokio::test]
+#[should_panic(expected = "Failed to acquire migration lock")]
+async fn test_cannot_run_up_while_another_migrator_holds_the_lock() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+
+    MigrationLock::acquire(conf.migrator.db(), "pod-a", Duration::from_secs(60))
+        .await
+        .expect("Failed to acquire lock");
+
+    conf.run_up(&FastForwardDelta::default()).await;
+}
+
+#[tokio::test]
+async fn test_force_unlock_removes_lock_held_by_another_migrator() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+    let db = conf.migrator.db();
+
+    MigrationLock::acquire(db.clone(), "pod-a", Duration::from_secs(60))
+        .await
+        .expect("Failed to acquire lock");
+
+    conf.migrator.set_force_unlock(true);
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    assert_eq!(Migration::get_all_desc(db.clone()).await.len(), 12);
+    // The lock is released once the command is done
+    assert!(MigrationLock::get(db.clone()).await.unwrap().is_none());
+}
@@ -0,0 +1,183 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use migrator_tests::{current_function, AssertionArg, TestConfig};
+use surreal_models::migrations::ResourcesV10;
+use surreal_orm::migrator::{
+    FastForwardDelta, Migration, MigrationFilename, Mode, RollbackStrategyStruct, SchemaDriftReport,
+};
+use tempfile::tempdir;
+use test_case::test_case;
+
+fn nth_forward_migration(conf: &TestConfig, nth: usize) -> MigrationFilename {
+    conf.read_migrations_from_dir_sorted_asc()
+        .into_iter()
+        .filter(|filename| !filename.is_down())
+        .nth(nth)
+        .expect("Migration not found")
+}
+
+async fn assert_no_drift(conf: &TestConfig) {
+    let report = SchemaDriftReport::detect(
+        conf.migrator.db(),
+        &conf.migrator.file_manager(),
+        &ResourcesV10,
+    )
+    .await
+    .expect("Failed to detect drift");
+    assert!(!report.has_drift(), "{report}");
+}
+
+#[test_case(Mode::Strict, true; "Reversible Strict")]
+#[test_case(Mode::Lax, true; "Reversible Lax")]
+#[test_case(Mode::Strict, false; "Non-Reversible Strict")]
+#[test_case(Mode::Lax, false; "Non-Reversible Lax")]
+#[tokio::test]
+async fn test_squash_applied_migrations_into_baseline(mode: Mode, reversible: bool) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(reversible)
+        .await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    let till = nth_forward_migration(&conf, 5);
+    conf.run_squash(till.clone()).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 7,
+        expected_db_mig_meta_count: 7,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+    conf.assert_migration_queries_snapshot();
+    let baseline = nth_forward_migration(&conf, 0);
+    assert_eq!(baseline.basename(), "baseline".into());
+    assert_eq!(baseline.timestamp(), till.timestamp());
+    assert!(!migration_dir
+        .with_file_name(".migrations-tests-squashed")
+        .exists());
+    assert_no_drift(&conf).await;
+
+    // The baseline is already applied so nothing is pending
+    conf.run_up(&FastForwardDelta::default()).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 7,
+        expected_db_mig_meta_count: 7,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+    assert_no_drift(&conf).await;
+
+    if reversible {
+        // Checksums of the baseline are registered so strict rollback works through it
+        conf.run_down(&RollbackStrategyStruct::builder().number(7).build(), false)
+            .await;
+        conf.assert_with_db_instance(AssertionArg {
+            expected_mig_files_count: 7,
+            expected_db_mig_meta_count: 0,
+            expected_latest_migration_file_basename_normalized: Some(
+                "migration_12_gen_after_init".into(),
+            ),
+            expected_latest_db_migration_meta_basename_normalized: None,
+            code_origin_line: std::line!(),
+        })
+        .await;
+    }
+}
+
+#[test_case(true; "Reversible")]
+#[test_case(false; "Non-Reversible")]
+#[tokio::test]
+async fn test_squash_unapplied_migrations_into_baseline(reversible: bool) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(reversible)
+        .await;
+
+    let till = nth_forward_migration(&conf, 5);
+    conf.run_squash(till).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 7,
+        expected_db_mig_meta_count: 0,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: None,
+        code_origin_line: std::line!(),
+    })
+    .await;
+
+    conf.run_up(&FastForwardDelta::default()).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 7,
+        expected_db_mig_meta_count: 7,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+    assert_no_drift(&conf).await;
+}
+
+#[tokio::test]
+async fn test_squash_again_till_baseline_updates_other_databases() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+    conf.run_up(&FastForwardDelta::default()).await;
+
+    let mut other_env = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    other_env.run_up(&FastForwardDelta::default()).await;
+
+    conf.run_squash(nth_forward_migration(&conf, 5)).await;
+    let files_after_squash = conf.read_migrations_from_dir_sorted_asc();
+
+    let baseline = nth_forward_migration(&conf, 0);
+    other_env.run_squash(baseline.clone()).await;
+
+    assert_eq!(
+        conf.read_migrations_from_dir_sorted_asc(),
+        files_after_squash
+    );
+    let other_env_migrations = Migration::get_all_desc(other_env.migrator.db()).await;
+    assert_eq!(other_env_migrations.len(), 7);
+    assert_eq!(
+        other_env_migrations.last().map(|m| m.name.clone()),
+        Some(baseline.to_string())
+    );
+    assert_no_drift(&other_env).await;
+}
+
+#[tokio::test]
+#[should_panic(expected = "Failed to squash migrations")]
+async fn test_cannot_squash_partially_applied_migrations() {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(Mode::Strict, migration_dir, current_function!()).await;
+    conf.generate_12_test_migrations_reversible(true).await;
+    conf.run_up(&FastForwardDelta::builder().number(3).build())
+        .await;
+
+    conf.run_squash(nth_forward_migration(&conf, 5)).await;
+}
@@ -7,7 +7,7 @@ use migrator_tests::{current_function, AssertionArg, TestConfig};
 use pretty_assertions::assert_eq;
 use surreal_models::migrations::Resources;
 use surreal_orm::migrator::{
-    FastForwardDelta, Informational, Init, MigrationFilename, MockPrompter, Mode,
+    FastForwardDelta, Informational, Init, MigrationFilename, MockPrompter, Mode, UpdateStrategy,
 };
 use tempfile::tempdir;
 use test_case::test_case;
@@ -775,3 +775,81 @@ async fn test_mixture_of_update_strategies(mode: Mode, reversible: bool) {
     })
     .await;
 }
+
+#[test_case(Mode::Strict, true; "Reversible Strict")]
+#[test_case(Mode::Lax, true; "Reversible Lax")]
+#[test_case(Mode::Strict, false; "Non-Reversible Strict")]
+#[test_case(Mode::Lax, false; "Non-Reversible Lax")]
+#[tokio::test]
+async fn test_dry_run_up_makes_no_changes(mode: Mode, reversible: bool) {
+    let migration_dir = tempdir().expect("Failed to create temp directory");
+    let migration_dir = &migration_dir.path().join("migrations-tests");
+    let mut conf = TestConfig::new(mode, migration_dir, current_function!()).await;
+
+    conf.generate_12_test_migrations_reversible(reversible)
+        .await;
+
+    let fwd_delta = FastForwardDelta::builder().number(3).build();
+    conf.run_up_dry_run(&fwd_delta).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 12,
+        expected_db_mig_meta_count: 0,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: None,
+        code_origin_line: std::line!(),
+    })
+    .await;
+
+    let file_manager = conf.migrator.file_manager();
+    let db = conf.migrator.db();
+    let plan = if reversible {
+        file_manager
+            .two_way()
+            .get_up_pending_migrations_plan(db, UpdateStrategy::from(&fwd_delta))
+            .await
+    } else {
+        file_manager
+            .one_way()
+            .get_pending_migrations_plan(db, UpdateStrategy::from(&fwd_delta))
+            .await
+    }
+    .expect("Failed to plan migrations");
+
+    let planned_files = plan
+        .migrations()
+        .iter()
+        .map(|m| m.filename.clone())
+        .collect::<Vec<_>>();
+    let expected_files = conf
+        .read_migrations_from_dir_sorted_asc()
+        .into_iter()
+        .filter(|f| !f.is_down())
+        .take(3)
+        .collect::<Vec<_>>();
+    assert_eq!(planned_files, expected_files);
+    assert_eq!(plan.metadata_queries().len(), 3);
+
+    let plan_output = plan.to_string();
+    assert!(plan_output.starts_with("BEGIN TRANSACTION;"));
+    assert!(plan_output.trim_end().ends_with("COMMIT TRANSACTION;"));
+    for file in &expected_files {
+        assert!(plan_output.contains(&format!("-- File: {file}")));
+    }
+
+    // The plan is what a real run applies
+    conf.run_up(&fwd_delta).await;
+    conf.assert_with_db_instance(AssertionArg {
+        expected_mig_files_count: 12,
+        expected_db_mig_meta_count: 3,
+        expected_latest_migration_file_basename_normalized: Some(
+            "migration_12_gen_after_init".into(),
+        ),
+        expected_latest_db_migration_meta_basename_normalized: Some(
+            "migration_3_gen_after_init".into(),
+        ),
+        code_origin_line: std::line!(),
+    })
+    .await;
+}
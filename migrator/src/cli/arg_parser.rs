@@ -4,9 +4,30 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
+use std::{str::FromStr, time::Duration};
+
+use surreal_query_builder::statements::DefineMode;
+use surrealdb::sql;
+
 use crate::MigrationFilename;
 
 pub fn mig_name_parser(s: &str) -> Result<MigrationFilename, String> {
     let filename = MigrationFilename::try_from(s.to_string()).map_err(|e| e.to_string())?;
     Ok(filename)
 }
+
+pub fn duration_parser(s: &str) -> Result<Duration, String> {
+    let duration = sql::Duration::from_str(s)
+        .map_err(|_| format!("Invalid duration: {s}. Expected a duration e.g 30s, 15m or 1h"))?;
+    Ok(duration.0)
+}
+
+pub fn define_mode_parser(s: &str) -> Result<DefineMode, String> {
+    match s.trim().to_lowercase().as_str() {
+        "if-not-exists" => Ok(DefineMode::IfNotExists),
+        "overwrite" => Ok(DefineMode::Overwrite),
+        _ => Err(format!(
+            "Invalid define mode: {s}. Expected one of if-not-exists, overwrite"
+        )),
+    }
+}
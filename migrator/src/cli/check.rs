@@ -0,0 +1,39 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+use crate::*;
+use clap::Args;
+use surreal_query_builder::DbResources;
+
+/// Checks the live database and the codebase for schema drift from the migrations.
+/// Exits with an error if any is found e.g to catch hand edited schemas in CI.
+/// cargo run -- check
+#[derive(Args, Debug, Clone)]
+pub struct Check;
+
+impl Check {
+    pub async fn run(&self, cli: &mut Migrator, codebase_resources: impl DbResources) {
+        cli.setup_db().await;
+        let file_manager = cli.file_manager();
+        let db = cli.db().clone();
+
+        let report = SchemaDriftReport::detect(db, &file_manager, &codebase_resources).await;
+
+        match report {
+            Ok(report) if report.has_drift() => {
+                println!("{report}");
+                log::error!("Schema drift detected");
+                panic!("Schema drift detected");
+            }
+            Ok(report) => {
+                println!("{report}");
+            }
+            Err(e) => {
+                log::error!("Failed to check for schema drift: {e}");
+                panic!("Failed to check for schema drift: {e}");
+            }
+        }
+    }
+}
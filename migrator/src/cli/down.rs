@@ -22,6 +22,16 @@ pub struct Down {
         default_value_t = false
     )]
     pub(crate) prune: bool,
+
+    /// Print the queries that would run without running them
+    #[arg(
+        global = true,
+        long,
+        help = "Print the SurrealQL that would run, without making any changes",
+        default_value_t = false
+    )]
+    #[builder(default)]
+    pub(crate) dry_run: bool,
 }
 
 impl Down {
@@ -108,6 +118,30 @@ impl Down {
 
         let rollback_strategy = self.rollback_strategy();
 
+        if self.dry_run {
+            let plan = file_manager
+                .two_way()
+                .get_down_migrations_plan(
+                    db.clone(),
+                    RollbackOptions {
+                        rollback_strategy,
+                        mode: cli.mode,
+                    },
+                )
+                .await;
+
+            match plan {
+                Ok(plan) => {
+                    println!("-- Dry run. No changes have been made to the database.\n{plan}");
+                }
+                Err(e) => {
+                    log::error!("Rollback plan failed: {e}");
+                    panic!("Rollback plan failed: {e}");
+                }
+            }
+            return;
+        }
+
         let rollback = file_manager
             .two_way()
             .run_down_migrations(
@@ -8,7 +8,7 @@ use super::up::{FastForwardDelta, Up};
 
 use crate::*;
 use clap::Args;
-use surreal_query_builder::DbResources;
+use surreal_query_builder::{statements::DefineMode, DbResources};
 use typed_builder::TypedBuilder;
 
 /// Generate migrations
@@ -22,6 +22,16 @@ pub struct Generate {
     #[arg(long, help = "Whether to run the migrations after generation")]
     #[builder(default)]
     pub(crate) run: bool,
+
+    /// Generates idempotent definitions
+    #[arg(
+        long,
+        value_parser = define_mode_parser,
+        help = "Generates DEFINE statements with IF NOT EXISTS or OVERWRITE and REMOVE statements \
+            with IF EXISTS e.g if-not-exists, overwrite. Requires SurrealDB 2.0 or later."
+    )]
+    #[builder(default, setter(strip_option))]
+    pub(crate) define_mode: Option<DefineMode>,
 }
 
 impl Generate {
@@ -31,7 +41,10 @@ impl Generate {
         codebase_resources: impl DbResources,
         prompter: impl Prompter,
     ) {
-        let file_manager = cli.file_manager();
+        let file_manager = MigrationConfig {
+            define_mode: self.define_mode,
+            ..cli.file_manager()
+        };
         let migration_basename = &self.name;
         let mig_type = file_manager.detect_migration_type();
 
@@ -83,6 +96,7 @@ impl Generate {
                 number: None,
                 till: None,
             },
+            dry_run: false,
         }
     }
 }
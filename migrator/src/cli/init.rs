@@ -101,6 +101,7 @@ impl Init {
                 number: None,
                 till: None,
             },
+            dry_run: false,
         }
     }
 }
@@ -4,6 +4,7 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 mod arg_parser;
+mod check;
 pub mod config;
 mod down;
 mod generate;
@@ -11,17 +12,20 @@ mod init;
 mod list;
 mod prune;
 mod reset;
+mod squash;
 mod up;
 
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
 pub use arg_parser::*;
+pub use check::Check;
 pub use down::{Down, RollbackStrategy, RollbackStrategyStruct};
 pub use generate::Generate;
 pub use init::Init;
 pub use list::{List, Status};
 pub use prune::Prune;
 pub use reset::Reset;
+pub use squash::Squash;
 
 use surrealdb::{engine::any::Any, Surreal};
 use typed_builder::TypedBuilder;
@@ -31,7 +35,9 @@ use clap::{ArgAction, Parser};
 use surreal_query_builder::DbResources;
 
 pub use self::config::DatabaseConnection;
-use crate::{MigrationConfig, MockPrompter, Mode, Prompter, RealPrompter, RenameOrDelete};
+use crate::{
+    MigrationConfig, MigrationLock, MockPrompter, Mode, Prompter, RealPrompter, RenameOrDelete,
+};
 
 /// Surreal ORM CLI
 #[derive(Parser, Debug, Clone, TypedBuilder)]
@@ -67,6 +73,26 @@ pub struct Migrator {
     )]
     pub(crate) mode: Mode,
 
+    #[arg(
+        global = true,
+        long,
+        help = "Removes the migration lock left behind by another migrator run before running the command. \
+            Only use this if you are sure no other migrator run is in progress.",
+        default_value_t = false
+    )]
+    #[builder(default)]
+    pub(crate) force_unlock: bool,
+
+    #[arg(
+        global = true,
+        long,
+        value_parser = duration_parser,
+        help = "How long the migration lock is held before another migrator run can take it over e.g 30s, 15m, 1h",
+        default_value = "15m"
+    )]
+    #[builder(default = Duration::from_secs(15 * 60))]
+    pub(crate) lock_ttl: Duration,
+
     #[command(flatten)]
     pub(crate) db_connection: DatabaseConnection,
 }
@@ -85,6 +111,11 @@ impl Migrator {
         self
     }
 
+    pub fn set_force_unlock(&mut self, force_unlock: bool) -> &mut Self {
+        self.force_unlock = force_unlock;
+        self
+    }
+
     pub fn file_manager(&self) -> MigrationConfig {
         let fm_init = MigrationConfig::builder()
             .custom_path(self.dir.clone())
@@ -128,6 +159,8 @@ impl Migrator {
         codebase_resources: Option<impl DbResources>,
         prompter: MockPrompter,
     ) {
+        let lock_holder = self.acquire_lock_if_required().await;
+
         match self.subcmd.clone() {
             None => {
                 Up::default().run(self).await;
@@ -155,6 +188,16 @@ impl Migrator {
                 SubCommand::Down(down) => down.run(self).await,
                 SubCommand::Prune(prune) => prune.run(self).await,
                 SubCommand::List(prune) => prune.run(self).await,
+                SubCommand::Check(check) => {
+                    check
+                        .run(
+                            self,
+                            codebase_resources
+                                .expect("resources must be provided for check command"),
+                        )
+                        .await
+                }
+                SubCommand::Squash(squash) => squash.run(self).await,
                 SubCommand::Reset(reset) => {
                     reset
                         .run(
@@ -167,8 +210,13 @@ impl Migrator {
                 }
             },
         };
+
+        self.release_lock(lock_holder).await;
     }
+
     pub async fn run_fn(&mut self, codebase_resources: impl DbResources, prompter: impl Prompter) {
+        let lock_holder = self.acquire_lock_if_required().await;
+
         match self.subcmd.clone() {
             None => {
                 Up::default().run(self).await;
@@ -182,9 +230,79 @@ impl Migrator {
                 SubCommand::Down(down) => down.run(self).await,
                 SubCommand::Prune(prune) => prune.run(self).await,
                 SubCommand::List(prune) => prune.run(self).await,
+                SubCommand::Check(check) => check.run(self, codebase_resources).await,
+                SubCommand::Squash(squash) => squash.run(self).await,
                 SubCommand::Reset(reset) => reset.run(self, codebase_resources, prompter).await,
             },
         };
+
+        self.release_lock(lock_holder).await;
+    }
+
+    // Commands that change the database take the migration lock so that
+    // concurrent migrator runs e.g from multiple deploy pods do not interleave.
+    fn requires_lock(&self) -> bool {
+        match &self.subcmd {
+            None => true,
+            Some(SubCommand::Up(up)) => !up.dry_run,
+            Some(SubCommand::Down(down)) => !down.dry_run,
+            Some(SubCommand::Reset(_) | SubCommand::Prune(_) | SubCommand::Squash(_)) => true,
+            Some(
+                SubCommand::Init(_)
+                | SubCommand::Generate(_)
+                | SubCommand::List(_)
+                | SubCommand::Check(_),
+            ) => false,
+        }
+    }
+
+    async fn acquire_lock_if_required(&mut self) -> Option<String> {
+        if !self.requires_lock() {
+            return None;
+        }
+
+        self.setup_db().await;
+        let db = self.db();
+
+        if self.force_unlock {
+            match MigrationLock::force_unlock(db.clone()).await {
+                Ok(Some(lock)) => {
+                    log::warn!(
+                        "Removed migration lock held by {} since {}",
+                        lock.holder,
+                        lock.acquired_at
+                    );
+                }
+                Ok(None) => log::info!("No migration lock to remove"),
+                Err(e) => {
+                    log::error!("Failed to remove migration lock: {e}");
+                    panic!("Failed to remove migration lock: {e}");
+                }
+            }
+        }
+
+        let holder = MigrationLock::new_holder_id();
+        if let Err(e) = MigrationLock::acquire(db, &holder, self.lock_ttl).await {
+            log::error!("Failed to acquire migration lock: {e}");
+            panic!("Failed to acquire migration lock: {e}");
+        }
+        log::info!("Acquired migration lock as {holder}");
+
+        Some(holder)
+    }
+
+    // If a command panics, the lock is left in place until its ttl elapses
+    // or it is removed with --force-unlock.
+    async fn release_lock(&self, holder: Option<String>) {
+        let Some(holder) = holder else {
+            return;
+        };
+
+        if let Err(e) = MigrationLock::release(self.db(), &holder).await {
+            log::error!("Failed to release migration lock: {e}");
+            panic!("Failed to release migration lock: {e}");
+        }
+        log::info!("Released migration lock");
     }
 
     pub(crate) fn setup_logging(&self) {
@@ -228,6 +346,10 @@ pub enum SubCommand {
     List(List),
     /// Delete Unapplied local migration files that have not been applied to the current database instance
     Prune(Prune),
+    /// Check the live database and the codebase for schema drift from the migrations
+    Check(Check),
+    /// Squash all migrations up to a migration into a single baseline migration
+    Squash(Squash),
 }
 
 macro_rules! impl_from {
@@ -246,3 +368,5 @@ impl_from!(Down);
 impl_from!(Reset);
 impl_from!(List);
 impl_from!(Prune);
+impl_from!(Check);
+impl_from!(Squash);
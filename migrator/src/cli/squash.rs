@@ -0,0 +1,53 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+use clap::Args;
+use typed_builder::TypedBuilder;
+
+use crate::*;
+
+/// Squashes all migrations up to and including a migration into a single baseline migration
+/// cargo run -- squash --till 20240107015727114_create_first.up.surql
+#[derive(Args, Debug, TypedBuilder, Clone)]
+pub struct Squash {
+    /// Last migration to squash
+    #[arg(
+        long,
+        value_parser = mig_name_parser,
+        help = "Squash all migrations up to and including this migration"
+    )]
+    pub(crate) till: MigrationFilename,
+
+    /// Name of the baseline migration
+    #[arg(
+        long,
+        default_value = "baseline",
+        help = "Name of the baseline migration"
+    )]
+    #[builder(default = "baseline".into())]
+    pub(crate) name: Basename,
+}
+
+impl Squash {
+    pub async fn run(&self, cli: &mut Migrator) {
+        cli.setup_db().await;
+        let file_manager = cli.file_manager();
+        let db = cli.db().clone();
+
+        let squashed = file_manager
+            .squash_migrations(db, &self.till, &self.name)
+            .await;
+
+        match squashed {
+            Ok(baseline) => {
+                log::info!("Squash successful. Baseline: {}", baseline.name_forward());
+            }
+            Err(e) => {
+                log::error!("Failed to squash migrations: {e}");
+                panic!("Failed to squash migrations: {e}");
+            }
+        }
+    }
+}
@@ -15,10 +15,21 @@ use typed_builder::TypedBuilder;
 /// cargo run -- up -l
 /// cargo run -- up -n 2
 /// cargo run -- up -t 2021-09-09-xxxxx
+/// cargo run -- up --dry-run
 #[derive(Args, Debug, TypedBuilder, Clone, Default)]
 pub struct Up {
     #[command(flatten)]
     pub(crate) fast_forward: FastForwardDelta,
+
+    /// Print the queries that would run without running them
+    #[arg(
+        global = true,
+        long,
+        help = "Print the SurrealQL that would run, without making any changes",
+        default_value_t = false
+    )]
+    #[builder(default)]
+    pub(crate) dry_run: bool,
 }
 
 impl Up {
@@ -96,8 +107,45 @@ impl Up {
         let update_strategy = self.update_strategy();
         let db = cli.db().clone();
 
-        match file_manager.detect_migration_type() {
-            Ok(MigrationFlag::TwoWay) => {
+        let migration_type = match file_manager.detect_migration_type() {
+            Ok(migration_type) => migration_type,
+            Err(e) => {
+                log::error!("Failed to detect migration type. Make sure the migration  \
+                is first initialized or reset by running cargo run -- init -n '<migration name>'. Error: {e}");
+                panic!("Failed to detect migration type.");
+            }
+        };
+
+        if self.dry_run {
+            let plan = match migration_type {
+                MigrationFlag::TwoWay => {
+                    file_manager
+                        .two_way()
+                        .get_up_pending_migrations_plan(db.clone(), update_strategy)
+                        .await
+                }
+                MigrationFlag::OneWay => {
+                    file_manager
+                        .one_way()
+                        .get_pending_migrations_plan(db.clone(), update_strategy)
+                        .await
+                }
+            };
+
+            match plan {
+                Ok(plan) => {
+                    println!("-- Dry run. No changes have been made to the database.\n{plan}");
+                }
+                Err(e) => {
+                    log::error!("Failed to plan migrations: {e}");
+                    panic!("Failed to plan migrations: {e}");
+                }
+            }
+            return;
+        }
+
+        match migration_type {
+            MigrationFlag::TwoWay => {
                 log::info!("Running two way migrations");
                 let run = file_manager
                     .two_way()
@@ -108,7 +156,7 @@ impl Up {
                     panic!("Failed to run migrations. Migration already run or not found");
                 }
             }
-            Ok(MigrationFlag::OneWay) => {
+            MigrationFlag::OneWay => {
                 log::info!("Running one way migrations");
                 let run = file_manager
                     .one_way()
@@ -119,11 +167,6 @@ impl Up {
                     panic!("Failed to run migrations. Migration already run or not found");
                 }
             }
-            Err(e) => {
-                log::error!("Failed to detect migration type. Make sure the migration  \
-                is first initialized or reset by running cargo run -- init -n '<migration name>'. Error: {e}");
-                panic!("Failed to detect migration type.");
-            }
         };
 
         let info = info_for().database().get_data::<DbInfo>(db.clone()).await;
@@ -0,0 +1,188 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::{fmt::Debug, future::Future, pin::Pin, sync::Arc};
+
+use surreal_query_builder::Raw;
+use surrealdb::{engine::any::Any, Surreal};
+
+use crate::*;
+
+pub type DataMigrationFuture = Pin<Box<dyn Future<Output = MigrationResult<Vec<Raw>>> + Send>>;
+
+type DataMigrationFn = Arc<dyn Fn(Surreal<Any>) -> DataMigrationFuture + Send + Sync>;
+
+#[derive(Clone)]
+struct DataMigration {
+    migration: String,
+    up: Option<DataMigrationFn>,
+    down: Option<DataMigrationFn>,
+}
+
+/// Rust functions run alongside migrations e.g to backfill a field computed from others.
+/// Each function is registered by the name of the migration it belongs to and gets the database
+/// to read from. The migration is tracked by its `Migration` record as usual.
+///
+/// With data migrations, the pending migrations are applied one after the other, so a function
+/// sees the migrations before its own applied.
+///
+/// An up function runs after its migration's SurrealQL is committed, so it can read the tables
+/// and fields the migration defines. The queries it returns are then run in a second transaction
+/// along with the `Migration` record. **Applying such a migration is not atomic**: SurrealDB
+/// transactions cannot span multiple requests, so if the function or its queries fail, the
+/// migration's SurrealQL stays applied while the migration is not recorded, and it is run again
+/// on the next attempt. Generate the migration with `--define-mode` so that rerunning it is safe.
+///
+/// A down function runs before its migration's SurrealQL, so it can read what the migration
+/// removes, and the queries it returns are run after that SurrealQL in the same transaction.
+/// Migrations without a function are applied in a single transaction each.
+///
+/// Examples
+/// ```rust, ignore
+/// use surreal_orm::{*, migrator::DataMigrations};
+///
+/// let data_migrations = DataMigrations::new()
+///     .up("20240102000000_label_planets.up.surql", |db| async move {
+///         let planet::Schema { labels, .. } = Planet::schema();
+///         let planets = select(All).from(Planet::table()).return_many::<Planet>(db).await?;
+///
+///         Ok(planets
+///             .into_iter()
+///             .map(|planet| {
+///                 update::<Planet>(planet.id)
+///                     .set(labels.equal_to(vec![planet.first_name]))
+///                     .to_raw()
+///             })
+///             .collect())
+///     })
+///     .down("20240102000000_label_planets.down.surql", |_db| async move {
+///         let planet::Schema { labels, .. } = Planet::schema();
+///         Ok(vec![update::<Planet>(Planet::table())
+///             .set(labels.equal_to(Vec::<String>::new()))
+///             .to_raw()])
+///     });
+/// ```
+#[derive(Clone, Default)]
+pub struct DataMigrations {
+    migrations: Vec<DataMigration>,
+}
+
+impl Debug for DataMigrations {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.migrations.iter().map(|m| &m.migration))
+            .finish()
+    }
+}
+
+impl DataMigrations {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the function to run when applying the migration.
+    /// The migration can be named by either its up or down filename in two way mode.
+    pub fn up<F, Fut>(mut self, migration: impl Into<String>, data_migration: F) -> Self
+    where
+        F: Fn(Surreal<Any>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = MigrationResult<Vec<Raw>>> + Send + 'static,
+    {
+        self.entry(migration.into()).up = Some(Self::boxed(data_migration));
+        self
+    }
+
+    /// Registers the function to run when rolling back the migration.
+    pub fn down<F, Fut>(mut self, migration: impl Into<String>, data_migration: F) -> Self
+    where
+        F: Fn(Surreal<Any>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = MigrationResult<Vec<Raw>>> + Send + 'static,
+    {
+        self.entry(migration.into()).down = Some(Self::boxed(data_migration));
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.migrations.is_empty()
+    }
+
+    fn boxed<F, Fut>(data_migration: F) -> DataMigrationFn
+    where
+        F: Fn(Surreal<Any>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = MigrationResult<Vec<Raw>>> + Send + 'static,
+    {
+        Arc::new(move |db| Box::pin(data_migration(db)))
+    }
+
+    fn entry(&mut self, migration: String) -> &mut DataMigration {
+        let index = match self
+            .migrations
+            .iter()
+            .position(|m| m.migration == migration)
+        {
+            Some(index) => index,
+            None => {
+                self.migrations.push(DataMigration {
+                    migration,
+                    up: None,
+                    down: None,
+                });
+                self.migrations.len() - 1
+            }
+        };
+        &mut self.migrations[index]
+    }
+
+    /// Makes sure every function belongs to one of the migrations
+    pub(crate) fn validate(&self, migrations: &[MigrationFilename]) -> MigrationResult<()> {
+        for data_migration in &self.migrations {
+            let filename = MigrationFilename::try_from(data_migration.migration.clone())?;
+            if !migrations.iter().any(|m| m.to_up() == filename.to_up()) {
+                return Err(MigrationError::MigrationDoesNotExist { filename });
+            }
+        }
+        Ok(())
+    }
+
+    /// The functions registered for the migration file e.g the down function
+    /// for a down migration file.
+    fn functions_for(
+        &self,
+        migration: &MigrationFilename,
+    ) -> MigrationResult<Vec<DataMigrationFn>> {
+        let mut functions = vec![];
+        for data_migration in &self.migrations {
+            let filename = MigrationFilename::try_from(data_migration.migration.clone())?;
+            if filename.to_up() != migration.to_up() {
+                continue;
+            }
+
+            let function = if migration.is_down() {
+                &data_migration.down
+            } else {
+                &data_migration.up
+            };
+            functions.extend(function.clone());
+        }
+        Ok(functions)
+    }
+
+    pub(crate) fn has_function_for(&self, migration: &MigrationFilename) -> MigrationResult<bool> {
+        Ok(!self.functions_for(migration)?.is_empty())
+    }
+
+    /// Runs the functions registered for the migration file and returns the queries they produced.
+    pub(crate) async fn queries_for(
+        &self,
+        db: Surreal<Any>,
+        migration: &MigrationFilename,
+    ) -> MigrationResult<Vec<Raw>> {
+        let mut queries = vec![];
+        for function in self.functions_for(migration)? {
+            queries.extend(function(db.clone()).await?);
+        }
+        Ok(queries)
+    }
+}
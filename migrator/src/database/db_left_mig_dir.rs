@@ -45,6 +45,7 @@ impl LeftDatabase {
             .map(|m: &MigrationFileTwoWayPair| m.up.content.to_string())
             .collect::<Vec<_>>()
             .join("\n");
+        let queries = strip_define_modes(&queries)?;
 
         // Run them as a transaction against a local in-memory database
         if !queries.trim().is_empty() {
@@ -66,6 +67,7 @@ impl LeftDatabase {
             .map(|m| m.content().to_string())
             .collect::<Vec<_>>()
             .join("\n");
+        let queries = strip_define_modes(&queries)?;
 
         log::info!("Running queries: {}", queries);
 
@@ -90,6 +92,7 @@ impl LeftDatabase {
             .map(|m| m.content().to_string())
             .collect::<Vec<_>>()
             .join("\n");
+        let queries = strip_define_modes(&queries)?;
 
         // Run them as a transaction against a local in-memory database
         if !queries.trim().is_empty() {
@@ -3,7 +3,7 @@
  * Email: oyelowo.oss@gmail.com
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
-use std::{collections::BTreeSet, path::PathBuf};
+use std::collections::BTreeSet;
 
 use surreal_query_builder::{statements::*, *};
 use surrealdb::{engine::any::Any, Connection, Surreal};
@@ -57,14 +57,43 @@ impl MigrationRunner {
         fm: &MigrationConfig,
         rollback_options: RollbackOptions,
     ) -> MigrationResult<()> {
+        let plan = Self::get_rollback_plan(db.clone(), fm, rollback_options).await?;
+
+        if plan.is_empty() {
+            log::info!("No migrations to rollback");
+        } else {
+            plan.execute(db.clone()).await?;
+
+            log::info!("Rolled back {} migrations", plan.migrations().len());
+        }
+
+        Ok(())
+    }
+
+    /// Resolves the rollback strategy into the queries a rollback would run
+    /// without running them.
+    pub async fn get_rollback_plan(
+        db: Surreal<Any>,
+        fm: &MigrationConfig,
+        rollback_options: RollbackOptions,
+    ) -> MigrationResult<MigrationPlan> {
+        let all_migrations_from_dir = fm.get_two_way_migrations_sorted_desc(false)?;
+        Self::get_rollback_plan_for_migrations(db, all_migrations_from_dir, rollback_options).await
+    }
+
+    /// Same as `get_rollback_plan` but for the given migrations e.g embedded ones,
+    /// sorted from the latest.
+    pub async fn get_rollback_plan_for_migrations(
+        db: Surreal<Any>,
+        all_migrations_from_dir: Vec<MigrationFileTwoWayPair>,
+        rollback_options: RollbackOptions,
+    ) -> MigrationResult<MigrationPlan> {
         let RollbackOptions {
             ref rollback_strategy,
             mode: ref strictness,
         } = rollback_options;
 
-        let all_migrations_from_dir = fm.get_two_way_migrations_sorted_desc(false)?;
-
-        let (queries_to_run, _rolledback_file_paths) = match rollback_strategy {
+        let plan = match rollback_strategy {
             RollbackStrategy::Previous => {
                 let latest_migration = Self::get_latest_migration(db.clone()).await?;
                 match latest_migration {
@@ -78,19 +107,13 @@ impl MigrationRunner {
                 "The latest migration - {} - does not have a corresponding down migration file",
                 latest_migration_name
             )))?;
-                        Self::generate_rollback_queries_and_filepaths(
-                            fm,
+                        Self::generate_rollback_plan(
                             vec![migrations_from_dir.clone()],
                             vec![latest_migration],
                             strictness,
                         )?
                     }
-                    None => Self::generate_rollback_queries_and_filepaths(
-                        fm,
-                        vec![],
-                        vec![],
-                        strictness,
-                    )?,
+                    None => Self::generate_rollback_plan(vec![], vec![], strictness)?,
                 }
             }
             RollbackStrategy::Number(count) => {
@@ -111,8 +134,7 @@ impl MigrationRunner {
                     .take(*count as usize)
                     .collect::<Vec<_>>();
 
-                Self::generate_rollback_queries_and_filepaths(
-                    fm,
+                Self::generate_rollback_plan(
                     migrations_to_rollback,
                     migrations_from_db,
                     strictness,
@@ -172,8 +194,7 @@ impl MigrationRunner {
                     })
                     .collect::<Vec<_>>();
 
-                Self::generate_rollback_queries_and_filepaths(
-                    fm,
+                Self::generate_rollback_plan(
                     migrations_files_to_rollback,
                     migrations_from_db,
                     strictness,
@@ -181,19 +202,7 @@ impl MigrationRunner {
             }
         };
 
-        if queries_to_run.build().trim().is_empty() {
-            log::info!("No migrations to rollback");
-        } else {
-            begin_transaction()
-                .query(queries_to_run)
-                .commit_transaction()
-                .run(db.clone())
-                .await?;
-
-            log::info!("Rolled back {} migrations", 1);
-        }
-
-        Ok(())
+        Ok(plan)
     }
 
     async fn get_latest_migration(
@@ -286,12 +295,12 @@ impl MigrationRunner {
         Ok(())
     }
 
-    fn generate_rollback_queries_and_filepaths(
-        fm: &MigrationConfig,
+    fn generate_rollback_plan(
         migrations_to_rollback: Vec<MigrationFileTwoWayPair>,
         migrations_from_db: Vec<Migration>,
         mode: &Mode,
-    ) -> MigrationResult<(Raw, Vec<PathBuf>)> {
+    ) -> MigrationResult<MigrationPlan> {
+        let mut checksum_checks = vec![];
         if mode.is_strict() {
             for (m_from_file, m_from_db) in
                 migrations_to_rollback.iter().zip(migrations_from_db.iter())
@@ -302,13 +311,14 @@ impl MigrationRunner {
                     .checksum_up
                     .verify(&m_from_file.up.name, &m_from_file.up.content)?;
 
-                m_from_db
-                    .clone()
-                    .checksum_down
-                    .ok_or(MigrationError::NoChecksumInDb {
-                        migration_name: m_from_db.name.clone(),
-                    })?
-                    .verify(&m_from_file.down.name, &m_from_file.down.content)?;
+                let checksum_down =
+                    m_from_db
+                        .clone()
+                        .checksum_down
+                        .ok_or(MigrationError::NoChecksumInDb {
+                            migration_name: m_from_db.name.clone(),
+                        })?;
+                checksum_down.verify(&m_from_file.down.name, &m_from_file.down.content)?;
 
                 if m_from_file.up.name != db_mig_name.to_up() {
                     return Err(MigrationError::MigrationFileVsDbNamesMismatch {
@@ -316,53 +326,49 @@ impl MigrationRunner {
                         migration_db_name: db_mig_name.to_string(),
                     });
                 }
+
+                checksum_checks.push(vec![
+                    ChecksumCheck {
+                        filename: m_from_file.up.name.clone(),
+                        expected_checksum: m_from_db.checksum_up.clone(),
+                    },
+                    ChecksumCheck {
+                        filename: m_from_file.down.name.clone(),
+                        expected_checksum: checksum_down,
+                    },
+                ]);
             }
         }
 
         log::info!("Rolling back {} migration(s)", migrations_to_rollback.len());
 
-        let rollback_queries = migrations_to_rollback
-            .clone()
+        let mut checksum_checks = checksum_checks.into_iter();
+        let rollback_migrations = migrations_to_rollback
             .into_iter()
-            .map(|m| m.down.content.to_string())
-            .collect::<Vec<_>>()
-            .join("\n");
+            .map(|m| PlannedMigration {
+                filename: m.down.name,
+                content: m.down.content,
+                checksum_checks: checksum_checks.next().unwrap_or_default(),
+                data_migration: vec![],
+            })
+            .collect::<Vec<_>>();
 
         let rollbacked_migration_deletion_queries = migrations_from_db
             .iter()
             // We are deleting by upname because that's how theyre are stored
-            .map(|m| Migration::delete_raw(&m.id).build())
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        let all = format!(
-            "{}\n{}",
-            rollback_queries, rollbacked_migration_deletion_queries
-        );
-
-        let file_paths = migrations_to_rollback
-            .iter()
-            .map(|m| {
-                fm.get_migration_dir().map(|d| {
-                    vec![
-                        d.join(m.up.name.to_string()),
-                        d.join(m.down.name.to_string()),
-                    ]
-                })
-            })
-            .collect::<MigrationResult<Vec<_>>>();
+            .map(|m| Migration::delete_raw(&m.id))
+            .collect::<Vec<_>>();
 
-        Ok((
-            Raw::new(all),
-            file_paths?.iter().flatten().cloned().collect::<Vec<_>>(),
+        Ok(MigrationPlan::new(
+            rollback_migrations,
+            rollbacked_migration_deletion_queries,
         ))
     }
 
-    async fn run_up_pending_migrations(
-        db: Surreal<impl Connection>,
+    fn plan_up_pending_migrations(
         filtered_pending_migrations: Vec<PendingMigrationFile>,
-    ) -> MigrationResult<()> {
-        let mut migration_queries: Vec<FileContent> = vec![];
+    ) -> MigrationResult<MigrationPlan> {
+        let mut migrations: Vec<PlannedMigration> = vec![];
         let mut mark_queries_registered_queries: Vec<Raw> = vec![];
 
         for mf in filtered_pending_migrations.into_iter() {
@@ -371,7 +377,12 @@ impl MigrationRunner {
                     let created_registered_mig =
                         Migration::create_raw(m.name(), &m.content().as_checksum()?, None);
 
-                    migration_queries.push(m.content().to_owned());
+                    migrations.push(PlannedMigration {
+                        filename: m.name().to_owned(),
+                        content: m.content().to_owned(),
+                        checksum_checks: vec![],
+                        data_migration: vec![],
+                    });
                     mark_queries_registered_queries.push(created_registered_mig);
                 }
                 MigrationFile::TwoWay(m) => {
@@ -381,48 +392,60 @@ impl MigrationRunner {
                         Some(&m.down.content.as_checksum()?),
                     );
 
-                    migration_queries.push(m.up.content);
+                    migrations.push(PlannedMigration {
+                        filename: m.up.name,
+                        content: m.up.content,
+                        checksum_checks: vec![],
+                        data_migration: vec![],
+                    });
                     mark_queries_registered_queries.push(created_registered_mig);
                 }
             }
         }
 
-        let migration_queries_str = migration_queries
-            .iter()
-            .map(ToString::to_string)
-            .collect::<Vec<_>>()
-            .join("\n");
-
-        // Create queries to mark migrations as applied
-        let mark_queries_registered_queries_str = mark_queries_registered_queries
-            .iter()
-            .map(|q| q.build())
-            .collect::<Vec<_>>()
-            .join("\n");
+        Ok(MigrationPlan::new(
+            migrations,
+            mark_queries_registered_queries,
+        ))
+    }
 
-        log::info!("Running {} migrations", migration_queries.len());
+    pub(crate) async fn run_up_pending_migrations(
+        db: Surreal<impl Connection>,
+        plan: MigrationPlan,
+    ) -> MigrationResult<()> {
+        log::info!("Running {} migrations", plan.migrations().len());
         log::info!(
             "Marking {} query(ies) as registered",
-            mark_queries_registered_queries.len()
+            plan.metadata_queries().len()
         );
 
-        // Join migrations with mark queries
-        let all = format!(
-            "{}\n{}",
-            migration_queries_str, mark_queries_registered_queries_str
-        );
+        if plan.is_empty() {
+            log::info!("No new migrations to apply");
+        } else {
+            println!("{}", plan.queries().build());
+            plan.execute(db.clone()).await?;
+
+            log::info!("Applied {} migrations", plan.migrations().len());
+        }
+
+        Ok(())
+    }
 
-        if all.trim().is_empty() {
+    pub(crate) async fn run_up_pending_migrations_with_data_migrations(
+        db: Surreal<Any>,
+        plan: MigrationPlan,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        log::info!("Running {} migrations", plan.migrations().len());
+
+        if plan.is_empty() {
             log::info!("No new migrations to apply");
         } else {
-            println!("{}", &all);
-            begin_transaction()
-                .query(Raw::new(all))
-                .commit_transaction()
-                .run(db.clone())
+            let count = plan.migrations().len();
+            plan.execute_with_data_migrations(db, data_migrations)
                 .await?;
 
-            log::info!("Applied {} migrations", migration_queries.len());
+            log::info!("Applied {count} migrations");
         }
 
         Ok(())
@@ -434,7 +457,19 @@ impl MigrationRunner {
         update_strategy: UpdateStrategy,
     ) -> MigrationResult<()> {
         log::info!("Running pending migrations");
+        let plan =
+            Self::get_pending_migrations_plan(db.clone(), all_migrations, update_strategy).await?;
+
+        Self::run_up_pending_migrations(db.clone(), plan).await
+    }
 
+    /// Resolves the update strategy into the queries applying the pending migrations
+    /// would run without running them.
+    pub async fn get_pending_migrations_plan(
+        db: Surreal<impl Connection>,
+        all_migrations: Vec<impl Into<MigrationFile> + ::std::fmt::Debug>,
+        update_strategy: UpdateStrategy,
+    ) -> MigrationResult<MigrationPlan> {
         let filtered_pending_migrations = match update_strategy {
             UpdateStrategy::Latest => {
                 Self::get_pending_migrations(all_migrations, db.clone()).await?
@@ -471,7 +506,7 @@ impl MigrationRunner {
             }
         };
 
-        Self::run_up_pending_migrations(db.clone(), filtered_pending_migrations).await
+        Self::plan_up_pending_migrations(filtered_pending_migrations)
     }
 
     pub(crate) async fn list_migrations(
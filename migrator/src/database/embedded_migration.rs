@@ -7,8 +7,8 @@
 use surrealdb::{engine::any::Any, Surreal};
 
 use crate::{
-    FileMetadata, MigrationConfig, MigrationFileOneWay, MigrationFileTwoWayPair, MigrationResult,
-    Mode, UpdateStrategy,
+    DataMigrations, FileMetadata, MigrationConfig, MigrationFileOneWay, MigrationFileTwoWayPair,
+    MigrationResult, Mode, RollbackOptions, RollbackStrategy, UpdateStrategy,
 };
 
 #[derive(Clone, Debug)]
@@ -43,6 +43,58 @@ impl EmbeddedMigrationsTwoWay {
         Ok(())
     }
 
+    /// Runs the pending migrations along with the Rust data migrations registered for them.
+    pub async fn run_with_data_migrations(
+        self,
+        db: Surreal<Any>,
+        update_strategy: UpdateStrategy,
+        mode: Mode,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        let files_config = MigrationConfig::new().set_mode(mode);
+
+        let two_way = files_config.two_way();
+        two_way
+            .run_up_embedded_pending_migrations_with_data_migrations(
+                db.clone(),
+                self,
+                update_strategy,
+                data_migrations,
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn rollback(
+        self,
+        db: Surreal<Any>,
+        rollback_strategy: RollbackStrategy,
+        mode: Mode,
+    ) -> MigrationResult<()> {
+        self.rollback_with_data_migrations(db, rollback_strategy, mode, &DataMigrations::new())
+            .await
+    }
+
+    /// Rolls back migrations along with the down data migrations registered for them.
+    pub async fn rollback_with_data_migrations(
+        self,
+        db: Surreal<Any>,
+        rollback_strategy: RollbackStrategy,
+        mode: Mode,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        let files_config = MigrationConfig::new().set_mode(mode);
+        let rollback_options = RollbackOptions::new()
+            .strategy(rollback_strategy)
+            .mode(mode);
+
+        files_config
+            .two_way()
+            .run_down_embedded_migrations(db.clone(), self, rollback_options, data_migrations)
+            .await?;
+        Ok(())
+    }
+
     pub const fn get_migrations(&self) -> &'static [EmbeddedMigrationTwoWay] {
         self.migrations
     }
@@ -60,7 +112,7 @@ impl EmbeddedMigrationsTwoWay {
                     .expect("Invalid migration name");
                 let up_content = meta.up.content.to_string().into();
                 let down_name = meta
-                    .up
+                    .down
                     .name
                     .to_string()
                     .try_into()
@@ -102,6 +154,27 @@ impl EmbeddedMigrationsOneWay {
         Ok(())
     }
 
+    /// Runs the pending migrations along with the Rust data migrations registered for them.
+    pub async fn run_with_data_migrations(
+        self,
+        db: Surreal<Any>,
+        update_strategy: UpdateStrategy,
+        mode: Mode,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        let files_config = MigrationConfig::new().set_mode(mode);
+        files_config
+            .one_way()
+            .run_embedded_pending_migrations_with_data_migrations(
+                db.clone(),
+                self,
+                update_strategy,
+                data_migrations,
+            )
+            .await?;
+        Ok(())
+    }
+
     pub fn to_migrations_one_way(&self) -> MigrationResult<Vec<MigrationFileOneWay>> {
         let migs = self
             .migrations
@@ -9,7 +9,7 @@ use std::{collections::BTreeMap, ops::Deref};
 use surreal_query_builder::{statements::info_for, *};
 use surrealdb::{
     engine::local::{Db, Mem},
-    Surreal,
+    Connection, Surreal,
 };
 
 use crate::*;
@@ -52,9 +52,11 @@ impl ComparisonDatabase {
     }
 }
 
+// Defaults to a local in-memory database but can also wrap e.g
+// the live database to read its resources.
 #[derive(Debug, Clone)]
-pub struct MigratorDatabase {
-    pub db: Surreal<Db>,
+pub struct MigratorDatabase<C: Connection = Db> {
+    pub db: Surreal<C>,
 }
 
 impl MigratorDatabase {
@@ -63,8 +65,10 @@ impl MigratorDatabase {
         db.use_ns("test").use_db("test").await.unwrap();
         Self { db }
     }
+}
 
-    pub fn db(&self) -> Surreal<Db> {
+impl<C: Connection> MigratorDatabase<C> {
+    pub fn db(&self) -> Surreal<C> {
         self.db.clone()
     }
 
@@ -77,6 +81,15 @@ impl MigratorDatabase {
         Ok(info)
     }
 
+    pub async fn get_scope_info(&self, scope: String) -> MigrationResult<ScopeInfo> {
+        let info = info_for()
+            .scope(scope)
+            .get_data::<ScopeInfo>(self.db())
+            .await?
+            .expect("Scope not found");
+        Ok(info)
+    }
+
     pub async fn get_table_info(&self, table: String) -> MigrationResult<TableResourcesData> {
         let info = info_for()
             .table(table)
@@ -87,7 +100,18 @@ impl MigratorDatabase {
     }
 
     pub async fn get_all_resources(&self) -> MigrationResult<FullDbInfo> {
-        let top_level_resources = self.get_db_info().await?;
+        let mut top_level_resources = self.get_db_info().await?;
+
+        // Tokens can also be defined on scopes which INFO FOR DB does not include.
+        // Namespace level definitions are not tracked since the migrator is
+        // signed in at the database level.
+        for scope in top_level_resources.scopes().get_names() {
+            let scope_info = self.get_scope_info(scope.clone()).await?;
+            top_level_resources
+                .tokens
+                .merge_from_level(scope_info.tokens, format!("SCOPE {scope}"));
+        }
+
         let mut fields_by_table = BTreeMap::new();
         for table in top_level_resources.tables().get_names() {
             let table_info = self.get_table_info(table.clone()).await?;
@@ -105,7 +129,9 @@ impl MigratorDatabase {
         self.db().query(query).await?;
         Ok(())
     }
+}
 
+impl MigratorDatabase {
     pub async fn generate_migrations(
         migration_basename: &Basename,
         file_manager: &MigrationConfig,
@@ -140,27 +166,68 @@ impl MigratorDatabase {
             prompter: &prompter,
         };
 
-        let tables = init.new_tables(&codebase_resources).queries()?;
+        let migration_reset =
+            Self::get_migration_reset_queries(file_manager)?.intersperse_new_lines();
+        let mut resources = vec![migration_reset];
+        for queries in Self::get_resources_queries(&init, &codebase_resources)? {
+            resources.push(queries.with_define_mode(file_manager.define_mode)?);
+        }
+
+        let (up_queries_str, down_queries_str) = Self::join_resources_queries(resources);
+
+        let query_str = format!("{up_queries_str}{down_queries_str}");
+
+        let migration_file = MigrationFile::new(
+            &migration_basename,
+            &file_manager.migration_flag_checked()?,
+            &up_queries_str.into(),
+            &down_queries_str.into(),
+        )?;
+
+        if query_str.trim().is_empty() {
+            match prompter.prompt_empty_migrations_trigger() {
+                Ok(true) => {
+                    migration_file.create_file(file_manager)?;
+                    log::info!("New migration generated.");
+                }
+                Ok(false) => {
+                    log::info!("No migration created");
+                }
+                Err(e) => {
+                    return Err(MigrationError::PromptError(e));
+                }
+            };
+        } else {
+            migration_file.create_file(file_manager)?;
+            log::info!("New migration generated.");
+        };
+
+        Ok(())
+    }
+
+    /// Queries taking the left resources to the right ones, grouped by resource type
+    pub(crate) fn get_resources_queries<'a, R: DbResources>(
+        init: &'a ComparisonsInit<'a>,
+        codebase_resources: &'a R,
+    ) -> MigrationResult<Vec<Queries>> {
+        let tables = init.new_tables(codebase_resources).queries()?;
         let analyzers = init.new_analyzers().queries()?.intersperse_new_lines();
         let params = init.new_params().queries()?.intersperse_new_lines();
         let functions = init.new_functions().queries()?.intersperse_new_lines();
         let scopes = init.new_scopes().queries()?.intersperse_new_lines();
         let tokens = init.new_tokens().queries()?.intersperse_new_lines();
-        let users = init.new_users().queries()?.intersperse_new_lines();
-        let migration_reset =
-            Self::get_migration_reset_queries(file_manager)?.intersperse_new_lines();
-
-        let resources = vec![
-            migration_reset,
-            tables,
-            analyzers,
-            params,
-            functions,
-            scopes,
-            tokens,
-            users,
-        ];
+        let users = init
+            .new_users(codebase_resources)
+            .queries()?
+            .intersperse_new_lines();
+
+        Ok(vec![
+            tables, analyzers, params, functions, scopes, tokens, users,
+        ])
+    }
 
+    /// Joins the queries of all resources into the up and down migration contents
+    pub(crate) fn join_resources_queries(resources: Vec<Queries>) -> (String, String) {
         let mut up_queries = vec![];
         let mut down_queries = vec![];
         for resource in resources {
@@ -198,34 +265,7 @@ impl MigratorDatabase {
             .trim()
             .to_string();
 
-        let query_str = format!("{up_queries_str}{down_queries_str}");
-
-        let migration_file = MigrationFile::new(
-            &migration_basename,
-            &file_manager.migration_flag_checked()?,
-            &up_queries_str.into(),
-            &down_queries_str.into(),
-        )?;
-
-        if query_str.trim().is_empty() {
-            match prompter.prompt_empty_migrations_trigger() {
-                Ok(true) => {
-                    migration_file.create_file(file_manager)?;
-                    log::info!("New migration generated.");
-                }
-                Ok(false) => {
-                    log::info!("No migration created");
-                }
-                Err(e) => {
-                    return Err(MigrationError::PromptError(e));
-                }
-            };
-        } else {
-            migration_file.create_file(file_manager)?;
-            log::info!("New migration generated.");
-        };
-
-        Ok(())
+        (up_queries_str, down_queries_str)
     }
 
     fn get_migration_reset_queries(file_manager: &MigrationConfig) -> MigrationResult<Queries> {
@@ -8,7 +8,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use surreal_query_builder::DbResources;
+use surreal_query_builder::{statements::DefineMode, DbResources};
 use surrealdb::{engine::any::Any, Connection, Surreal};
 use typed_builder::TypedBuilder;
 
@@ -34,6 +34,11 @@ pub struct MigrationConfig {
     // After init => Some(MigrationFlag)
     #[builder(default)]
     pub migration_flag: Option<MigrationFlag>,
+    /// Generates `DEFINE` statements of new resources with `IF NOT EXISTS` and
+    /// `REMOVE` statements with `IF EXISTS`, so migrations don't fail on resources
+    /// that were already changed by hand. Changed definitions are kept as plain `DEFINE`.
+    #[builder(default)]
+    pub define_mode: Option<DefineMode>,
 }
 
 impl MigrationConfig {
@@ -51,6 +56,11 @@ impl MigrationConfig {
             .ok_or(MigrationError::MigrationFlagNotSet)
     }
 
+    pub fn set_define_mode(mut self, define_mode: DefineMode) -> Self {
+        self.define_mode = Some(define_mode);
+        self
+    }
+
     pub fn set_mode(mut self, mode: Mode) -> Self {
         self.mode = mode;
         self
@@ -265,6 +275,16 @@ impl FileManagerUni {
         Ok(())
     }
 
+    /// Gets the queries running the pending migrations would run, without running them
+    pub async fn get_pending_migrations_plan(
+        &self,
+        db: Surreal<impl Connection>,
+        update_strategy: UpdateStrategy,
+    ) -> MigrationResult<MigrationPlan> {
+        let migrations = self.get_migrations()?;
+        MigrationRunner::get_pending_migrations_plan(db, migrations, update_strategy).await
+    }
+
     pub async fn run_embedded_pending_migrations(
         &self,
         db: Surreal<impl Connection>,
@@ -277,6 +297,30 @@ impl FileManagerUni {
         Ok(())
     }
 
+    /// Runs the pending embedded migrations along with the Rust data migrations registered for them
+    pub async fn run_embedded_pending_migrations_with_data_migrations(
+        &self,
+        db: Surreal<Any>,
+        one_way_embedded_migrations: EmbeddedMigrationsOneWay,
+        update_strategy: UpdateStrategy,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        let migrations = one_way_embedded_migrations.to_migrations_one_way()?;
+        let filenames = migrations
+            .iter()
+            .map(|m| m.name().clone())
+            .collect::<Vec<_>>();
+        data_migrations.validate(&filenames)?;
+
+        let plan =
+            MigrationRunner::get_pending_migrations_plan(db.clone(), migrations, update_strategy)
+                .await?;
+        MigrationRunner::run_up_pending_migrations_with_data_migrations(db, plan, data_migrations)
+            .await?;
+
+        Ok(())
+    }
+
     /// List all migrations
     pub async fn list_migrations(
         &self,
@@ -351,6 +395,16 @@ impl FileManagerBi {
         Ok(())
     }
 
+    /// Gets the queries running the pending migrations would run, without running them
+    pub async fn get_up_pending_migrations_plan(
+        &self,
+        db: Surreal<impl Connection>,
+        update_strategy: UpdateStrategy,
+    ) -> MigrationResult<MigrationPlan> {
+        let migrations = self.get_migrations()?;
+        MigrationRunner::get_pending_migrations_plan(db.clone(), migrations, update_strategy).await
+    }
+
     /// For running embedded migrations
     pub async fn run_up_embedded_pending_migrations(
         &self,
@@ -364,6 +418,65 @@ impl FileManagerBi {
         Ok(())
     }
 
+    /// For running embedded migrations along with the Rust data migrations registered for them
+    pub async fn run_up_embedded_pending_migrations_with_data_migrations(
+        &self,
+        db: Surreal<Any>,
+        two_way_embedded_migrations: EmbeddedMigrationsTwoWay,
+        update_strategy: UpdateStrategy,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        let migrations = two_way_embedded_migrations.to_migrations_two_way()?;
+        let filenames = migrations
+            .iter()
+            .map(|m| m.up.name.clone())
+            .collect::<Vec<_>>();
+        data_migrations.validate(&filenames)?;
+
+        let plan =
+            MigrationRunner::get_pending_migrations_plan(db.clone(), migrations, update_strategy)
+                .await?;
+        MigrationRunner::run_up_pending_migrations_with_data_migrations(db, plan, data_migrations)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Rollback embedded migrations using various strategies
+    pub async fn run_down_embedded_migrations(
+        &self,
+        db: Surreal<Any>,
+        two_way_embedded_migrations: EmbeddedMigrationsTwoWay,
+        rollback_options: RollbackOptions,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        let mut migrations = two_way_embedded_migrations.to_migrations_two_way()?;
+        migrations.sort_by(|a, b| b.up.name.cmp(&a.up.name));
+        let filenames = migrations
+            .iter()
+            .map(|m| m.up.name.clone())
+            .collect::<Vec<_>>();
+        data_migrations.validate(&filenames)?;
+
+        let plan = MigrationRunner::get_rollback_plan_for_migrations(
+            db.clone(),
+            migrations,
+            rollback_options,
+        )
+        .await?;
+
+        if plan.is_empty() {
+            log::info!("No migrations to rollback");
+        } else {
+            let count = plan.migrations().len();
+            plan.execute_with_data_migrations(db.clone(), data_migrations)
+                .await?;
+            log::info!("Rolled back {count} migrations");
+        }
+
+        Ok(())
+    }
+
     /// Rollback migration using various strategies
     pub async fn run_down_migrations(
         &self,
@@ -375,6 +488,15 @@ impl FileManagerBi {
         Ok(())
     }
 
+    /// Gets the queries a rollback would run, without running them
+    pub async fn get_down_migrations_plan(
+        &self,
+        db: Surreal<Any>,
+        rollback_options: RollbackOptions,
+    ) -> MigrationResult<MigrationPlan> {
+        MigrationRunner::get_rollback_plan(db.clone(), self, rollback_options).await
+    }
+
     /// List all migrations
     pub async fn list_migrations(
         &self,
@@ -0,0 +1,130 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use surreal_query_builder::{SurrealOrmError, Table};
+use surrealdb::{
+    sql::{self, Thing},
+    Connection, Surreal,
+};
+
+use crate::*;
+
+/// Advisory lock record preventing concurrent migrator runs against the same database.
+/// The lock lives in its own table next to the migration table. The table only exists
+/// while the lock is held, so it does not show up as part of the database schema otherwise.
+/// A lock whose ttl has elapsed can be taken over by another holder, so a crashed run
+/// only blocks others until then.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MigrationLock {
+    pub id: Thing,
+    pub holder: String,
+    pub acquired_at: sql::Datetime,
+    pub ttl: sql::Duration,
+}
+
+impl MigrationLock {
+    pub fn table() -> Table {
+        Table::new("migration_lock")
+    }
+
+    pub fn lock_id() -> Thing {
+        Thing {
+            tb: Self::table().to_string(),
+            id: "lock".into(),
+        }
+    }
+
+    /// Identifies the process holding the lock e.g `deploy-pod-1:4242:<uuid>`.
+    pub fn new_holder_id() -> String {
+        let host = std::env::var("HOSTNAME").unwrap_or_else(|_| "unknown-host".into());
+        let pid = std::process::id();
+        let uuid = sql::Uuid::new_v4();
+        format!("{host}:{pid}:{uuid}")
+    }
+
+    /// Gets the current lock, if any. This may be an expired lock.
+    pub async fn get(db: Surreal<impl Connection>) -> MigrationResult<Option<Self>> {
+        let lock = db
+            .select::<Option<Self>>(("migration_lock", "lock"))
+            .await
+            .map_err(SurrealOrmError::QueryRun)?;
+        Ok(lock)
+    }
+
+    /// Takes the lock if it is free, expired or already held by the same holder.
+    /// Errors with `MigrationError::MigrationLocked` if another holder has it.
+    pub async fn acquire(
+        db: Surreal<impl Connection>,
+        holder: &str,
+        ttl: Duration,
+    ) -> MigrationResult<Self> {
+        let lock_id = Self::lock_id();
+        let acquire_query = format!(
+            "BEGIN TRANSACTION;
+            LET $lock = (SELECT * FROM {lock_id})[0];
+            IF $lock = NONE OR $lock.holder = $holder OR $lock.acquired_at + $lock.ttl <= time::now() {{
+                UPDATE {lock_id} CONTENT {{ holder: $holder, acquired_at: time::now(), ttl: $ttl }};
+            }};
+            COMMIT TRANSACTION;"
+        );
+
+        let acquired = db
+            .query(acquire_query)
+            .bind(("holder", holder))
+            .bind(("ttl", sql::Duration::from(ttl)))
+            .await
+            .and_then(|response| response.check());
+
+        // The lock is read back rather than relying on the error of the transaction
+        // which may also fail due to a conflict with a concurrent run taking the lock.
+        match Self::get(db.clone()).await? {
+            Some(lock) if lock.holder == holder => Ok(lock),
+            Some(lock) => Err(MigrationError::MigrationLocked {
+                holder: lock.holder,
+                acquired_at: lock.acquired_at.to_string(),
+                ttl: lock.ttl.to_string(),
+            }),
+            None => Err(MigrationError::MigrationLockFailed(
+                acquired.err().map(|e| e.to_string()).unwrap_or_default(),
+            )),
+        }
+    }
+
+    /// Releases the lock if it is held by the holder.
+    pub async fn release(db: Surreal<impl Connection>, holder: &str) -> MigrationResult<()> {
+        let lock_id = Self::lock_id();
+        let lock_table = Self::table();
+        let release_query = format!(
+            "BEGIN TRANSACTION;
+            LET $lock = (SELECT * FROM {lock_id})[0];
+            IF $lock.holder = $holder {{
+                REMOVE TABLE {lock_table};
+            }};
+            COMMIT TRANSACTION;"
+        );
+
+        db.query(release_query)
+            .bind(("holder", holder))
+            .await
+            .and_then(|response| response.check())
+            .map_err(SurrealOrmError::QueryRun)?;
+        Ok(())
+    }
+
+    /// Removes the lock regardless of who holds it or whether it has expired.
+    pub async fn force_unlock(db: Surreal<impl Connection>) -> MigrationResult<Option<Self>> {
+        let lock = Self::get(db.clone()).await?;
+        if lock.is_some() {
+            db.query(format!("REMOVE TABLE {};", Self::table()))
+                .await
+                .and_then(|response| response.check())
+                .map_err(SurrealOrmError::QueryRun)?;
+        }
+        Ok(lock)
+    }
+}
@@ -0,0 +1,194 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+use std::fmt::Display;
+
+use surreal_query_builder::{statements::begin_transaction, *};
+use surrealdb::{engine::any::Any, Connection, Surreal};
+
+use crate::*;
+
+/// A checksum the runner verifies against the one registered
+/// in the database before running a migration file.
+#[derive(Debug, Clone)]
+pub struct ChecksumCheck {
+    pub filename: MigrationFilename,
+    pub expected_checksum: Checksum,
+}
+
+/// A migration file that is part of a plan.
+#[derive(Debug, Clone)]
+pub struct PlannedMigration {
+    pub filename: MigrationFilename,
+    pub content: FileContent,
+    pub checksum_checks: Vec<ChecksumCheck>,
+    /// Queries returned by the Rust data migration registered for the file, if any
+    pub data_migration: Vec<Raw>,
+}
+
+/// The queries `up` or `down` would run against the database, in the order they would run.
+/// All the queries are run in a single transaction.
+#[derive(Debug, Clone, Default)]
+pub struct MigrationPlan {
+    migrations: Vec<PlannedMigration>,
+    // Registers or deletes the migration metadata after the migration files have run
+    metadata_queries: Vec<Raw>,
+}
+
+impl MigrationPlan {
+    pub fn new(migrations: Vec<PlannedMigration>, metadata_queries: Vec<Raw>) -> Self {
+        Self {
+            migrations,
+            metadata_queries,
+        }
+    }
+
+    pub fn migrations(&self) -> &[PlannedMigration] {
+        &self.migrations
+    }
+
+    pub fn metadata_queries(&self) -> &[Raw] {
+        &self.metadata_queries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.queries().build().trim().is_empty()
+    }
+
+    /// The queries of the migration files followed by the migration metadata queries
+    pub fn queries(&self) -> Raw {
+        let migration_queries = self
+            .migrations
+            .iter()
+            .map(|m| {
+                let data_migration = m
+                    .data_migration
+                    .iter()
+                    .map(|q| q.build())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("{}\n{data_migration}", m.content)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let metadata_queries = self
+            .metadata_queries
+            .iter()
+            .map(|q| q.build())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Raw::new(format!("{migration_queries}\n{metadata_queries}"))
+    }
+
+    pub async fn execute(&self, db: Surreal<impl Connection>) -> MigrationResult<()> {
+        begin_transaction()
+            .query(self.queries())
+            .commit_transaction()
+            .run(db)
+            .await?;
+        Ok(())
+    }
+
+    /// Runs the planned files one after the other, each in its own transaction along with
+    /// its migration metadata query, so every data migration sees the files before it applied.
+    /// An up file with a data migration is committed before its data migration runs, which can
+    /// then read what the file defines, and the queries it returns are run in a second transaction
+    /// with the metadata query. See `DataMigrations`.
+    /// Without data migrations, the whole plan runs in a single transaction as usual.
+    pub async fn execute_with_data_migrations(
+        self,
+        db: Surreal<Any>,
+        data_migrations: &DataMigrations,
+    ) -> MigrationResult<()> {
+        if data_migrations.is_empty() {
+            return self.execute(db).await;
+        }
+
+        let MigrationPlan {
+            migrations,
+            metadata_queries,
+        } = self;
+        // The metadata query of each file is at the same position as the file
+        let last = migrations.len().saturating_sub(1);
+        let mut metadata_queries = metadata_queries.into_iter();
+        for (i, mut migration) in migrations.into_iter().enumerate() {
+            let metadata = match i == last {
+                true => metadata_queries.by_ref().collect(),
+                false => metadata_queries.next().into_iter().collect(),
+            };
+
+            if !migration.filename.is_down()
+                && data_migrations.has_function_for(&migration.filename)?
+            {
+                let filename = migration.filename.clone();
+                MigrationPlan::new(vec![migration], vec![])
+                    .execute(db.clone())
+                    .await?;
+                let data_migration = PlannedMigration {
+                    data_migration: data_migrations.queries_for(db.clone(), &filename).await?,
+                    filename,
+                    content: FileContent::empty(),
+                    checksum_checks: vec![],
+                };
+                MigrationPlan::new(vec![data_migration], metadata)
+                    .execute(db.clone())
+                    .await?;
+                continue;
+            }
+
+            migration.data_migration = data_migrations
+                .queries_for(db.clone(), &migration.filename)
+                .await?;
+            MigrationPlan::new(vec![migration], metadata)
+                .execute(db.clone())
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for MigrationPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "-- Nothing to run");
+        }
+
+        writeln!(f, "BEGIN TRANSACTION;")?;
+
+        for migration in &self.migrations {
+            writeln!(f, "\n-- File: {}", migration.filename)?;
+            for check in &migration.checksum_checks {
+                writeln!(
+                    f,
+                    "-- Checksum check: {} must match checksum {} registered in the database",
+                    check.filename, check.expected_checksum
+                )?;
+            }
+            let content = migration.content.to_string();
+            if content.trim().is_empty() {
+                writeln!(f, "-- Empty migration")?;
+            } else {
+                writeln!(f, "{}", content.trim())?;
+            }
+            if !migration.data_migration.is_empty() {
+                writeln!(f, "-- Data migration")?;
+                for query in &migration.data_migration {
+                    writeln!(f, "{}", query.build())?;
+                }
+            }
+        }
+
+        if !self.metadata_queries.is_empty() {
+            writeln!(f, "\n-- Migration metadata")?;
+            for query in &self.metadata_queries {
+                writeln!(f, "{}", query.build())?;
+            }
+        }
+
+        writeln!(f, "\nCOMMIT TRANSACTION;")
+    }
+}
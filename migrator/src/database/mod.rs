@@ -5,6 +5,7 @@
  */
 
 mod checksum;
+mod data_migration;
 mod db_left_mig_dir;
 mod db_right_codebase;
 mod db_runner;
@@ -15,12 +16,17 @@ mod file_manager;
 mod file_metadata;
 mod file_name;
 mod full_db_info;
+mod migration_lock;
 mod migration_model;
+mod migration_plan;
 mod prompter;
 mod queries;
+mod schema_drift;
 mod settings;
+mod squash;
 
 pub use checksum::*;
+pub use data_migration::*;
 pub use db_left_mig_dir::*;
 pub use db_right_codebase::*;
 pub use db_runner::*;
@@ -31,7 +37,10 @@ pub use file_manager::*;
 pub use file_metadata::*;
 pub use file_name::*;
 pub use full_db_info::*;
+pub use migration_lock::*;
 pub use migration_model::*;
+pub use migration_plan::*;
 pub use prompter::*;
 pub use queries::*;
+pub use schema_drift::*;
 pub use settings::*;
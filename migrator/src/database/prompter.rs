@@ -35,6 +35,20 @@ where
         .prompt();
         confirmation
     }
+
+    fn prompt_single_resource_rename_or_delete(
+        &self,
+        delete_option: SingleResourceChangeType,
+        rename_option: SingleResourceChangeType,
+    ) -> Result<SingleResourceChangeType, InquireError> {
+        let confirmation = inquire::Select::new(
+            "Do you want to rename this resource or delete the old one and create the new one",
+            vec![delete_option, rename_option],
+        )
+        .with_help_message("Use the arrow keys to navigate. Press enter to select.")
+        .prompt();
+        confirmation
+    }
 }
 
 // What is use in the actual codebase and typically just uses the default implementation
@@ -68,6 +82,13 @@ pub struct MockPrompter {
     // to rename - which would transfer the data to the new field,
     // or delete the old field completely without transferring the data.
     pub rename_or_delete_single_field_change: RenameOrDelete,
+
+    // triggered when a single resource other than a table or field
+    // e.g a user, scope or token is removed while another one of the
+    // same kind is created. Same as the single field change above
+    // but defaults to deleting the old resource.
+    #[builder(default = RenameOrDelete::Delete)]
+    pub rename_or_delete_single_resource_change: RenameOrDelete,
 }
 
 impl Default for MockPrompter {
@@ -75,6 +96,7 @@ impl Default for MockPrompter {
         Self {
             allow_empty_migrations_gen: true,
             rename_or_delete_single_field_change: RenameOrDelete::Rename,
+            rename_or_delete_single_resource_change: RenameOrDelete::Delete,
         }
     }
 }
@@ -94,4 +116,15 @@ impl Prompter for MockPrompter {
             RenameOrDelete::Delete => Ok(delete_option),
         }
     }
+
+    fn prompt_single_resource_rename_or_delete(
+        &self,
+        delete_option: SingleResourceChangeType,
+        rename_option: SingleResourceChangeType,
+    ) -> Result<SingleResourceChangeType, InquireError> {
+        match self.rename_or_delete_single_resource_change {
+            RenameOrDelete::Rename => Ok(rename_option),
+            RenameOrDelete::Delete => Ok(delete_option),
+        }
+    }
 }
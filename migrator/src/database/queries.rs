@@ -7,12 +7,13 @@
 use std::{fmt::Display, ops::Deref};
 
 use itertools::intersperse;
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use surreal_query_builder::{
     statements::{
         remove_analyzer, remove_database, remove_event, remove_field, remove_function,
         remove_index, remove_model, remove_namespace, remove_param, remove_scope, remove_table,
-        remove_token, remove_user,
+        remove_token, remove_user, DefineMode,
     },
     *,
 };
@@ -22,6 +23,8 @@ use crate::*;
 #[derive(Debug, Clone)]
 pub enum QueryType {
     Define(DefineStatementRaw),
+    /// Changes the definition of an existing resource, so it is never skipped with `IF NOT EXISTS`
+    Redefine(DefineStatementRaw),
     Remove(RemoveStatementRaw),
     Update(UpdateStatementRaw),
     DeleteAll(Raw),
@@ -32,7 +35,7 @@ pub enum QueryType {
 impl Display for QueryType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let query = match self {
-            QueryType::Define(def) => def.to_string(),
+            QueryType::Define(def) | QueryType::Redefine(def) => def.to_string(),
             QueryType::Remove(rem) => rem.to_string(),
             QueryType::Update(upd) => upd.to_string(),
             QueryType::DeleteAll(del) => del.build(),
@@ -48,6 +51,91 @@ impl Display for QueryType {
     }
 }
 
+impl QueryType {
+    /// Makes new definitions and removals not fail when the resource already exists or is missing.
+    /// Changed definitions are only given `OVERWRITE`, as `IF NOT EXISTS` would skip them.
+    pub(crate) fn with_define_mode(self, define_mode: DefineMode) -> MigrationResult<Self> {
+        let query = match self {
+            QueryType::Define(def) => QueryType::Define(def.with_define_mode(define_mode)?),
+            QueryType::Redefine(def) if define_mode == DefineMode::Overwrite => {
+                QueryType::Redefine(def.with_define_mode(define_mode)?)
+            }
+            QueryType::Remove(rem) => QueryType::Remove(rem.if_exists()?),
+            query => query,
+        };
+        Ok(query)
+    }
+}
+
+/// Removes `IF NOT EXISTS`, `OVERWRITE` and `IF EXISTS` from the definitions and removals in migration files.
+/// The migrator replays the files into an empty in-memory database to get the resources they
+/// define, and the parser of the embedded engine rejects these clauses. They do not change
+/// the outcome when starting from an empty database anyway. Strings and comments are left as they are.
+pub(crate) fn strip_define_modes(queries: &str) -> MigrationResult<String> {
+    let re =
+        Regex::new(r"(?i)\b(DEFINE|REMOVE)(\s+\w+)\s+(IF\s+NOT\s+EXISTS|IF\s+EXISTS|OVERWRITE)\b")?;
+    let stripped = split_code_and_literals(queries)
+        .into_iter()
+        .map(|(segment, is_code)| match is_code {
+            true => re.replace_all(segment, "$1$2"),
+            false => segment.into(),
+        })
+        .collect();
+    Ok(stripped)
+}
+
+/// Splits the queries into the code and the strings, quoted identifiers and comments around it.
+/// The flag is true for code.
+fn split_code_and_literals(queries: &str) -> Vec<(&str, bool)> {
+    let mut segments = vec![];
+    let mut code_start = 0;
+    let mut i = 0;
+
+    while let Some(rest) = queries.get(i..).filter(|rest| !rest.is_empty()) {
+        let literal_len =
+            if rest.starts_with("--") || rest.starts_with("//") || rest.starts_with('#') {
+                Some(rest.find('\n').unwrap_or(rest.len()))
+            } else if rest.starts_with("/*") {
+                Some(rest.find("*/").map_or(rest.len(), |end| end + 2))
+            } else if rest.starts_with('⟨') {
+                Some(
+                    rest.find('⟩')
+                        .map_or(rest.len(), |end| end + '⟩'.len_utf8()),
+                )
+            } else {
+                ['\'', '"', '`']
+                    .into_iter()
+                    .find(|quote| rest.starts_with(*quote))
+                    .map(|quote| quoted_len(rest, quote))
+            };
+
+        match literal_len {
+            Some(len) => {
+                segments.push((&queries[code_start..i], true));
+                segments.push((&rest[..len], false));
+                i += len;
+                code_start = i;
+            }
+            None => i += rest.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    segments.push((&queries[code_start..], true));
+    segments
+}
+
+/// Length of the quoted text at the start of `rest`, including the quotes
+fn quoted_len(rest: &str, quote: char) -> usize {
+    let mut escaped = false;
+    for (i, c) in rest.char_indices().skip(1) {
+        match c {
+            '\\' if !escaped => escaped = true,
+            c if c == quote && !escaped => return i + c.len_utf8(),
+            _ => escaped = false,
+        }
+    }
+    rest.len()
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct Queries {
     pub(crate) up: Vec<QueryType>,
@@ -105,6 +193,23 @@ impl Queries {
     pub(crate) fn add_comment_to_down(&mut self, comment: impl Into<String>) {
         self.down.push(QueryType::Comment(comment.into()));
     }
+
+    pub(crate) fn with_define_mode(self, define_mode: Option<DefineMode>) -> MigrationResult<Self> {
+        let Some(define_mode) = define_mode else {
+            return Ok(self);
+        };
+        let with_define_mode = |queries: Vec<QueryType>| {
+            queries
+                .into_iter()
+                .map(|query| query.with_define_mode(define_mode))
+                .collect::<MigrationResult<Vec<_>>>()
+        };
+
+        Ok(Queries {
+            up: with_define_mode(self.up)?,
+            down: with_define_mode(self.down)?,
+        })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
@@ -138,6 +243,14 @@ impl Display for RemoveStatementRaw {
     }
 }
 
+impl RemoveStatementRaw {
+    /// e.g `REMOVE TABLE user` => `REMOVE TABLE IF EXISTS user`
+    pub(crate) fn if_exists(&self) -> MigrationResult<Self> {
+        let re = Regex::new(r"^(\s*REMOVE\s+\w+)\s+")?;
+        Ok(Self(re.replace(&self.0, "$1 IF EXISTS ").to_string()))
+    }
+}
+
 pub struct DefineStmtName(String);
 
 impl Display for DefineStmtName {
@@ -291,6 +404,13 @@ impl DefineStatementRaw {
     pub fn trim(&self) -> &str {
         self.0.trim()
     }
+
+    /// e.g `DEFINE TABLE user` => `DEFINE TABLE IF NOT EXISTS user` or `DEFINE TABLE OVERWRITE user`
+    pub(crate) fn with_define_mode(&self, define_mode: DefineMode) -> MigrationResult<Self> {
+        let re = Regex::new(r"^(\s*DEFINE\s+\w+)\s+")?;
+        let replacement = format!("$1 {define_mode} ");
+        Ok(Self(re.replace(&self.0, replacement.as_str()).to_string()))
+    }
 }
 
 impl Display for DefineStatementRaw {
@@ -0,0 +1,257 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+use std::fmt::Display;
+
+use surreal_query_builder::DbResources;
+use surrealdb::{engine::any::Any, Surreal};
+
+use crate::*;
+
+/// How a resource differs from what the migrations define.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DriftKind {
+    /// Defined e.g by hand, but not by the migrations.
+    NotInMigrations { definition: DefineStatementRaw },
+    /// Defined by the migrations, but not found.
+    Missing { definition: DefineStatementRaw },
+    /// Defined by both, but with different definitions.
+    DefinitionDiffers {
+        migrations: DefineStatementRaw,
+        found: DefineStatementRaw,
+    },
+}
+
+/// A resource e.g `FIELD name ON user` that has drifted from the migrations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDrift {
+    pub resource: String,
+    pub kind: DriftKind,
+}
+
+impl Display for SchemaDrift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let resource = &self.resource;
+        match &self.kind {
+            DriftKind::NotInMigrations { definition } => {
+                write!(f, "{resource} is not in migrations:\n    {definition}")
+            }
+            DriftKind::Missing { definition } => {
+                write!(f, "{resource} is defined in migrations but missing:\n    {definition}")
+            }
+            DriftKind::DefinitionDiffers { migrations, found } => write!(
+                f,
+                "{resource} differs from migrations:\n    migrations: {migrations}\n    found:      {found}"
+            ),
+        }
+    }
+}
+
+/// Result of comparing the live database and the codebase against the migration directory.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaDriftReport {
+    /// The live database compared with the migrations applied to it
+    /// e.g resources defined or altered by hand.
+    pub database: Vec<SchemaDrift>,
+    /// The codebase compared with all the local migrations
+    /// i.e changes for which no migration has been generated yet.
+    pub codebase: Vec<SchemaDrift>,
+}
+
+impl SchemaDriftReport {
+    /// Replays the local migrations into in-memory databases and compares the
+    /// resources they define with those of the live database and the codebase.
+    /// Only the migrations applied to the live database are replayed for comparing
+    /// against it, so pending migrations are not reported as drift.
+    pub async fn detect(
+        db: Surreal<Any>,
+        file_manager: &MigrationConfig,
+        codebase_resources: &impl DbResources,
+    ) -> MigrationResult<Self> {
+        let migration_flag = file_manager.detect_migration_type()?;
+        let migrations = match migration_flag {
+            MigrationFlag::TwoWay => file_manager
+                .get_two_way_migrations_sorted_asc(false)?
+                .into_iter()
+                .map(MigrationFileOneWay::from)
+                .collect::<Vec<_>>(),
+            MigrationFlag::OneWay => file_manager.get_oneway_migrations_sorted_asc(false)?,
+        };
+
+        let latest_applied = Migration::get_latest(db.clone()).await;
+        let applied_migrations = migrations
+            .iter()
+            .filter(|m| {
+                latest_applied
+                    .as_ref()
+                    .is_some_and(|latest| m.name().timestamp() <= latest.timestamp)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let applied = MigratorDatabase::init().await;
+        LeftDatabase::run_local_dir_oneway_content_migrations(applied.db(), applied_migrations)
+            .await?;
+        let all_migrations = MigratorDatabase::init().await;
+        LeftDatabase::run_local_dir_oneway_content_migrations(all_migrations.db(), migrations)
+            .await?;
+        let codebase = RightDatabase(MigratorDatabase::init().await);
+        codebase
+            .run_codebase_schema_queries(codebase_resources, migration_flag)
+            .await?;
+
+        let live_resources = MigratorDatabase { db }.get_all_resources().await?;
+        // The lock table is not part of the schema and only exists while a run holds it
+        let lock_table = format!("TABLE {}", MigrationLock::table());
+        let database = Self::diff(&applied.get_all_resources().await?, &live_resources)
+            .into_iter()
+            .filter(|drift| drift.resource != lock_table)
+            .collect();
+
+        let codebase_resources_info = codebase.resources().await;
+        let codebase = Self::diff(
+            &all_migrations.get_all_resources().await?,
+            &codebase_resources_info,
+        )
+        .into_iter()
+        .filter(|drift| match &drift.kind {
+            DriftKind::DefinitionDiffers { migrations, found } => {
+                !is_only_rehashed_password(migrations, found, codebase_resources)
+            }
+            _ => true,
+        })
+        .collect();
+
+        Ok(Self { database, codebase })
+    }
+
+    pub fn has_drift(&self) -> bool {
+        !self.database.is_empty() || !self.codebase.is_empty()
+    }
+
+    /// Compares the resources defined by the migrations with the ones found elsewhere.
+    pub fn diff(migrations: &FullDbInfo, found: &FullDbInfo) -> Vec<SchemaDrift> {
+        let mut drifts = vec![];
+        drifts.extend(diff_resource(
+            "ANALYZER",
+            &migrations.analyzers(),
+            &found.analyzers(),
+        ));
+        drifts.extend(diff_resource(
+            "TABLE",
+            &migrations.tables(),
+            &found.tables(),
+        ));
+        drifts.extend(diff_resource(
+            "PARAM",
+            &migrations.params(),
+            &found.params(),
+        ));
+        drifts.extend(diff_resource(
+            "FUNCTION",
+            &migrations.functions(),
+            &found.functions(),
+        ));
+        drifts.extend(diff_resource(
+            "SCOPE",
+            &migrations.scopes(),
+            &found.scopes(),
+        ));
+        drifts.extend(diff_resource(
+            "TOKEN",
+            &migrations.tokens(),
+            &found.tokens(),
+        ));
+        drifts.extend(diff_resource("USER", &migrations.users(), &found.users()));
+
+        // Fields, indexes and events of tables missing on either side are
+        // already covered by the table itself.
+        for table in migrations.get_tables() {
+            let (Some(left), Some(right)) = (
+                migrations.get_table_info(table.clone()),
+                found.get_table_info(table.clone()),
+            ) else {
+                continue;
+            };
+            let table_drifts = [
+                diff_resource("FIELD", &left.fields(), &right.fields()),
+                diff_resource("INDEX", &left.indexes(), &right.indexes()),
+                diff_resource("EVENT", &left.events(), &right.events()),
+            ];
+            drifts.extend(table_drifts.into_iter().flatten().map(|drift| SchemaDrift {
+                resource: format!("{} ON {table}", drift.resource),
+                ..drift
+            }));
+        }
+
+        drifts
+    }
+}
+
+impl Display for SchemaDriftReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.has_drift() {
+            return writeln!(f, "No schema drift detected");
+        }
+
+        if !self.database.is_empty() {
+            writeln!(f, "Database has drifted from the applied migrations:")?;
+            for drift in &self.database {
+                writeln!(f, "  {drift}")?;
+            }
+        }
+
+        if !self.codebase.is_empty() {
+            writeln!(
+                f,
+                "Codebase has changes not in migrations. Generate a migration for them:"
+            )?;
+            for drift in &self.codebase {
+                writeln!(f, "  {drift}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn diff_resource(
+    resource_type: &str,
+    migrations: &impl Informational,
+    found: &impl Informational,
+) -> Vec<SchemaDrift> {
+    let names = migrations.get_names_as_set();
+    let names = names
+        .union(&found.get_names_as_set())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let kind = match (
+                migrations.get_definition(&name),
+                found.get_definition(&name),
+            ) {
+                (None, Some(found)) => DriftKind::NotInMigrations {
+                    definition: found.clone(),
+                },
+                (Some(migrations), None) => DriftKind::Missing {
+                    definition: migrations.clone(),
+                },
+                (Some(migrations), Some(found)) if migrations != found => {
+                    DriftKind::DefinitionDiffers {
+                        migrations: migrations.clone(),
+                        found: found.clone(),
+                    }
+                }
+                _ => return None,
+            };
+            Some(SchemaDrift {
+                resource: format!("{resource_type} {name}"),
+                kind,
+            })
+        })
+        .collect()
+}
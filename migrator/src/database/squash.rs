@@ -0,0 +1,243 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use std::path::{Path, PathBuf};
+
+use surreal_query_builder::{statements::begin_transaction, DbResources, Raw, Runnable};
+use surrealdb::{engine::any::Any, Surreal};
+
+use crate::*;
+
+// The baseline is generated from the replayed migrations alone,
+// so there are no codebase resources e.g field renames to consider.
+struct NoCodebaseResources;
+impl DbResources for NoCodebaseResources {}
+
+impl MigrationConfig {
+    /// Replaces all migrations up to and including `till` with a single baseline migration
+    /// defining the schema at that point. The baseline keeps the timestamp of `till`
+    /// so later migrations stay in order. In two way mode, its down migration removes
+    /// everything the baseline defines.
+    ///
+    /// The database is expected to either have all the squashed migrations applied,
+    /// in which case their records are replaced by one for the baseline so it is not
+    /// applied again, or none of them. Other databases the squashed migrations have been
+    /// applied to can be brought in line by squashing again till the baseline against them.
+    /// If writing the baseline or replacing the records fails, the squashed files are restored.
+    pub async fn squash_migrations(
+        &self,
+        db: Surreal<Any>,
+        till: &MigrationFilename,
+        basename: &Basename,
+    ) -> MigrationResult<MigrationFile> {
+        let migration_flag = self.detect_migration_type()?;
+        let migrations = match migration_flag {
+            MigrationFlag::TwoWay => self
+                .get_two_way_migrations_sorted_asc(false)?
+                .into_iter()
+                .map(MigrationFile::TwoWay)
+                .collect::<Vec<_>>(),
+            MigrationFlag::OneWay => self
+                .get_oneway_migrations_sorted_asc(false)?
+                .into_iter()
+                .map(MigrationFile::OneWay)
+                .collect::<Vec<_>>(),
+        };
+
+        if !migrations
+            .iter()
+            .any(|m| m.name_forward().timestamp() == till.timestamp())
+        {
+            return Err(MigrationError::MigrationDoesNotExist {
+                filename: till.clone(),
+            });
+        }
+
+        let squashed = migrations
+            .into_iter()
+            .filter(|m| m.name_forward().timestamp() <= till.timestamp())
+            .collect::<Vec<_>>();
+
+        let latest_applied = Migration::get_latest(db.clone()).await;
+        let replace_db_records = match latest_applied {
+            Some(latest) if latest.timestamp < till.timestamp() => {
+                return Err(MigrationError::SquashedMigrationsNotApplied(till.clone()));
+            }
+            Some(_) => true,
+            None => false,
+        };
+
+        let baseline = Self::generate_baseline(&squashed, till, basename, migration_flag).await?;
+
+        let dir = self.get_migration_dir()?;
+        let staging_dir = Self::staging_dir(&dir);
+        let squashed_files = squashed
+            .iter()
+            .flat_map(Self::filenames)
+            .collect::<Vec<_>>();
+
+        // The squashed files are moved aside rather than deleted until the baseline is
+        // written and the database records replaced, so they can be put back on failure.
+        Self::move_files(&squashed_files, &dir, &staging_dir)?;
+        let squashed_result = match baseline.create_file(self) {
+            Ok(()) if replace_db_records => {
+                Self::replace_squashed_records(db.clone(), &baseline, till).await
+            }
+            result => result,
+        };
+        if let Err(e) = squashed_result {
+            for filename in Self::filenames(&baseline) {
+                let _ = std::fs::remove_file(filename.fullpath(&dir));
+            }
+            Self::move_files(&squashed_files, &staging_dir, &dir)?;
+            let _ = std::fs::remove_dir(&staging_dir);
+            return Err(e);
+        }
+
+        std::fs::remove_dir_all(&staging_dir).map_err(|e| {
+            MigrationError::IoError(format!(
+                "Failed to delete squashed migration files in: {}. Error: {e}",
+                staging_dir.to_string_lossy()
+            ))
+        })?;
+        log::info!(
+            "Squashed {} migration(s) into {}",
+            squashed.len(),
+            baseline.name_forward()
+        );
+
+        Ok(baseline)
+    }
+
+    async fn replace_squashed_records(
+        db: Surreal<Any>,
+        baseline: &MigrationFile,
+        till: &MigrationFilename,
+    ) -> MigrationResult<()> {
+        let checksum_up = baseline.up_content().as_checksum()?;
+        let checksum_down = baseline
+            .down_content()
+            .map(|content| content.as_checksum())
+            .transpose()?;
+        let delete_squashed_records = Raw::new(format!(
+            "DELETE {} WHERE {} <= {};",
+            Migration::table(),
+            Migration::schema().timestamp,
+            till.timestamp()
+        ));
+
+        begin_transaction()
+            .query(delete_squashed_records)
+            .query(Migration::create_raw(
+                baseline.name_forward(),
+                &checksum_up,
+                checksum_down.as_ref(),
+            ))
+            .commit_transaction()
+            .run(db)
+            .await?;
+        log::info!("Replaced the squashed migration records in the database with the baseline");
+
+        Ok(())
+    }
+
+    fn filenames(migration: &MigrationFile) -> Vec<MigrationFilename> {
+        match migration {
+            MigrationFile::OneWay(m) => vec![m.name().clone()],
+            MigrationFile::TwoWay(m) => vec![m.up.name.clone(), m.down.name.clone()],
+        }
+    }
+
+    // Kept next to the migrations directory so the files are moved within the same
+    // filesystem and are not mistaken for migrations if left behind.
+    fn staging_dir(migration_dir: &Path) -> PathBuf {
+        let dir_name = migration_dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        migration_dir.with_file_name(format!(".{dir_name}-squashed"))
+    }
+
+    /// Moves the files from one directory to another, moving back the ones
+    /// already moved if any of them fails.
+    fn move_files(filenames: &[MigrationFilename], from: &Path, to: &Path) -> MigrationResult<()> {
+        std::fs::create_dir_all(to).map_err(|e| {
+            MigrationError::IoError(format!(
+                "Failed to create directory: {}. Error: {e}",
+                to.to_string_lossy()
+            ))
+        })?;
+
+        for (i, filename) in filenames.iter().enumerate() {
+            let source = filename.fullpath(from);
+            if let Err(e) = std::fs::rename(&source, filename.fullpath(to)) {
+                for moved in &filenames[..i] {
+                    let _ = std::fs::rename(moved.fullpath(to), moved.fullpath(from));
+                }
+                return Err(MigrationError::IoError(format!(
+                    "Failed to move migration file: {}. Error: {e}",
+                    source.to_string_lossy()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn generate_baseline(
+        squashed: &[MigrationFile],
+        till: &MigrationFilename,
+        basename: &Basename,
+        migration_flag: MigrationFlag,
+    ) -> MigrationResult<MigrationFile> {
+        let empty = LeftDatabase(MigratorDatabase::init().await);
+        let replayed = RightDatabase(MigratorDatabase::init().await);
+        let queries = squashed
+            .iter()
+            .map(|m| m.up_content().to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !queries.trim().is_empty() {
+            begin_transaction()
+                .query(Raw::new(queries))
+                .commit_transaction()
+                .run(replayed.db())
+                .await?;
+        }
+
+        let prompter = MockPrompter::default();
+        let init = ComparisonsInit {
+            left_resources: &empty.resources().await,
+            right_resources: &replayed.resources().await,
+            prompter: &prompter,
+        };
+        let resources = MigratorDatabase::get_resources_queries(&init, &NoCodebaseResources)?;
+        let (up_queries, down_queries) = MigratorDatabase::join_resources_queries(resources);
+
+        let basename = basename.normalize_ensure();
+        let timestamp = till.timestamp();
+        let baseline = match migration_flag {
+            MigrationFlag::TwoWay => MigrationFile::TwoWay(MigrationFileTwoWayPair::new(
+                FileMetadata::new(
+                    format!("{timestamp}_{basename}.up.surql").try_into()?,
+                    up_queries.into(),
+                ),
+                FileMetadata::new(
+                    format!("{timestamp}_{basename}.down.surql").try_into()?,
+                    down_queries.into(),
+                ),
+            )),
+            MigrationFlag::OneWay => {
+                MigrationFile::OneWay(MigrationFileOneWay::new(FileMetadata::new(
+                    format!("{timestamp}_{basename}.surql").try_into()?,
+                    up_queries.into(),
+                )))
+            }
+        };
+
+        Ok(baseline)
+    }
+}
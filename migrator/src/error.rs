@@ -180,6 +180,21 @@ There are {one_way_filecount} up only files and {two_way_filecount} up and down
         using the command 'cargo run -- up' to apply it/them or delete all unapplied migrations using 'cargo run -- prune'.")]
     UnappliedMigrationExists { migration_count: usize },
 
+    #[error("Migrations are locked by {holder} since {acquired_at} for {ttl}. Another migrator run may be in progress. \
+        If you are sure that is not the case, rerun the command with --force-unlock to remove the lock.")]
+    MigrationLocked {
+        holder: String,
+        acquired_at: String,
+        ttl: String,
+    },
+
+    #[error("Failed to acquire migration lock. {0}")]
+    MigrationLockFailed(String),
+
+    #[error("Some of the migrations up to {0} have not been applied to the database. \
+        Apply them before squashing or squash against a database with no applied migrations.")]
+    SquashedMigrationsNotApplied(MigrationFilename),
+
     #[error("Invalid migration flag detection: {0}")]
     MigrationFlagDetectionError(String),
 
@@ -145,6 +145,36 @@ impl Display for SingleFieldChangeType {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct ResourceChangeMeta {
+    pub resource_type: &'static str,
+    pub old_name: String,
+    pub new_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum SingleResourceChangeType {
+    Delete(ResourceChangeMeta),
+    Rename(ResourceChangeMeta),
+}
+
+impl Display for SingleResourceChangeType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SingleResourceChangeType::Delete(change) => write!(
+                f,
+                "Delete old {} '{}' and create a new one '{}'",
+                change.resource_type, change.old_name, change.new_name
+            ),
+            SingleResourceChangeType::Rename(change) => write!(
+                f,
+                "Rename old {} '{}' to new {} '{}'",
+                change.resource_type, change.old_name, change.resource_type, change.new_name
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DeltaTypeResource {
     NoChange,
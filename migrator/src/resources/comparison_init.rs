@@ -10,7 +10,7 @@ use surreal_query_builder::DbResources;
 use crate::*;
 
 macro_rules! define_top_level_resource {
-    ($($resource_name:ident, $resource_type:ident);*) => {
+    ($($resource_name:ident, $resource_type:ident, $resource_type_name:literal);*) => {
         paste::paste! {
             $(
                 #[derive(Debug)]
@@ -26,6 +26,14 @@ macro_rules! define_top_level_resource {
                     fn get_right(&self) -> [<$resource_type>] {
                         self.resources.right_resources.[<$resource_name>]()
                     }
+
+                    fn resource_type(&self) -> &'static str {
+                        $resource_type_name
+                    }
+
+                    fn prompter(&self) -> &dyn Prompter {
+                        self.resources.prompter
+                    }
                 }
             )*
         }
@@ -34,12 +42,11 @@ macro_rules! define_top_level_resource {
 
 define_top_level_resource!(
     // tables, Tables;
-    analyzers, Analyzers;
-    functions, Functions;
-    params, Params;
-    scopes, Scopes;
-    tokens, Tokens;
-    users, Users
+    analyzers, Analyzers, "analyzer";
+    functions, Functions, "function";
+    params, Params, "param";
+    scopes, Scopes, "scope";
+    tokens, Tokens, "token"
 );
 
 #[derive(Debug, Clone)]
@@ -76,8 +83,11 @@ impl<'a> ComparisonsInit<'a> {
         ComparisonTokens { resources: self }
     }
 
-    pub fn new_users(&self) -> ComparisonUsers {
-        ComparisonUsers { resources: self }
+    pub fn new_users<R: DbResources>(&self, codebase_resources: &'a R) -> ComparisonUsers<R> {
+        ComparisonUsers {
+            resources: self,
+            codebase_resources,
+        }
     }
 
     pub fn new_functions(&self) -> ComparisonFunctions {
@@ -125,3 +135,9 @@ impl DbInfo {
         self.users.clone()
     }
 }
+
+// Scope level resources. Tokens are the only resources defined on scopes.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct ScopeInfo {
+    pub tokens: Tokens,
+}
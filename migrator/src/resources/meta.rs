@@ -18,34 +18,120 @@ where
     // Right is from codebase
     fn get_right(&self) -> T;
 
+    // e.g user, scope. Used in the rename or delete prompt and migration comments
+    fn resource_type(&self) -> &'static str;
+
+    fn prompter(&self) -> &dyn Prompter;
+
     fn queries(&self) -> MigrationResult<Queries> {
         let mut queries = Queries::default();
-        let left = self.get_left().get_names_as_set();
-        let right = self.get_right().get_names_as_set();
-        let resources = right.union(&left);
-
-        for name in resources {
-            let def_right = self.get_right().get_definition(name).cloned();
-            let def_left = self.get_left().get_definition(name).cloned();
-
-            match DeltaTypeResource::from((def_left, def_right)) {
-                DeltaTypeResource::Create { right } => {
-                    queries.add_up(QueryType::Define(right.clone()));
-                    queries.add_down(QueryType::Remove(right.as_remove_statement()?));
-                }
-                DeltaTypeResource::Remove { left } => {
-                    queries.add_up(QueryType::Remove(left.as_remove_statement()?));
-                    queries.add_down(QueryType::Define(left));
+        let left_defs = self.get_left();
+        let right_defs = self.get_right();
+        let left = left_defs.get_names_as_set();
+        let right = right_defs.get_names_as_set();
+
+        // Like a single field change, a single resource removed while another is
+        // created could either be a rename or a deletion, so we ask the user.
+        let removed = left.difference(&right).collect::<Vec<_>>();
+        let created = right.difference(&left).collect::<Vec<_>>();
+        let renamed = match (removed.as_slice(), created.as_slice()) {
+            ([old_name], [new_name]) => {
+                let change_meta = ResourceChangeMeta {
+                    resource_type: self.resource_type(),
+                    old_name: old_name.to_string(),
+                    new_name: new_name.to_string(),
+                };
+                match self.prompter().prompt_single_resource_rename_or_delete(
+                    SingleResourceChangeType::Delete(change_meta.clone()),
+                    SingleResourceChangeType::Rename(change_meta),
+                )? {
+                    SingleResourceChangeType::Rename(meta) => Some(meta),
+                    SingleResourceChangeType::Delete(_) => None,
                 }
-                DeltaTypeResource::Update { left, right } => {
-                    queries.add_up(QueryType::Define(right));
-                    queries.add_down(QueryType::Define(left));
-                }
-                DeltaTypeResource::NoChange => {}
-            };
+            }
+            _ => None,
+        };
+
+        if let Some(meta) = &renamed {
+            let left_def = left_defs.get_definition(&meta.old_name).cloned();
+            let right_def = right_defs.get_definition(&meta.new_name).cloned();
+            if let (Some(left_def), Some(right_def)) = (left_def, right_def) {
+                Self::add_rename_queries(&mut queries, meta, left_def, right_def)?;
+            }
+        }
+
+        for name in right.union(&left) {
+            let is_renamed = renamed
+                .as_ref()
+                .is_some_and(|meta| &meta.old_name == name || &meta.new_name == name);
+            if is_renamed {
+                continue;
+            }
+
+            let def_right = right_defs.get_definition(name).cloned();
+            let def_left = left_defs.get_definition(name).cloned();
+            self.add_delta_queries(&mut queries, DeltaTypeResource::from((def_left, def_right)))?;
         }
         Ok(queries)
     }
+
+    fn add_delta_queries(
+        &self,
+        queries: &mut Queries,
+        delta: DeltaTypeResource,
+    ) -> MigrationResult<()> {
+        add_resource_delta_queries(queries, delta)
+    }
+
+    // The new definition is made before the old one is removed
+    // so the resource is never missing in between.
+    fn add_rename_queries(
+        queries: &mut Queries,
+        meta: &ResourceChangeMeta,
+        left: DefineStatementRaw,
+        right: DefineStatementRaw,
+    ) -> MigrationResult<()> {
+        let ResourceChangeMeta {
+            resource_type,
+            old_name,
+            new_name,
+        } = meta;
+
+        queries.add_comment_to_up(format!("Rename {resource_type} {old_name} to {new_name}"));
+        queries.add_up(QueryType::Define(right.clone()));
+        queries.add_up(QueryType::Remove(left.as_remove_statement()?));
+        queries.add_comment_to_up(format!("Rename {resource_type} ending"));
+
+        queries.add_comment_to_down(format!(
+            "Revert {resource_type} name change. Change {resource_type} {new_name} back to {old_name}"
+        ));
+        queries.add_down(QueryType::Define(left));
+        queries.add_down(QueryType::Remove(right.as_remove_statement()?));
+        queries.add_comment_to_down(format!("Revert {resource_type} name change ending"));
+        Ok(())
+    }
+}
+
+pub(crate) fn add_resource_delta_queries(
+    queries: &mut Queries,
+    delta: DeltaTypeResource,
+) -> MigrationResult<()> {
+    match delta {
+        DeltaTypeResource::Create { right } => {
+            queries.add_up(QueryType::Define(right.clone()));
+            queries.add_down(QueryType::Remove(right.as_remove_statement()?));
+        }
+        DeltaTypeResource::Remove { left } => {
+            queries.add_up(QueryType::Remove(left.as_remove_statement()?));
+            queries.add_down(QueryType::Define(left));
+        }
+        DeltaTypeResource::Update { left, right } => {
+            queries.add_up(QueryType::Redefine(right));
+            queries.add_down(QueryType::Redefine(left));
+        }
+        DeltaTypeResource::NoChange => {}
+    };
+    Ok(())
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, Default)]
@@ -132,6 +218,17 @@ macro_rules! define_object_info {
                 }
             }
 
+            impl $ident {
+                /// Merges in definitions from another level e.g a scope.
+                /// Their names are qualified by the level so they do not clash with
+                /// database level definitions of the same name.
+                pub fn merge_from_level(&mut self, other: Self, level: impl ::std::fmt::Display) {
+                    for (name, definition) in other.0.0 {
+                        self.0.0.insert(format!("{name} ON {level}"), definition);
+                    }
+                }
+            }
+
         )*
     };
 }
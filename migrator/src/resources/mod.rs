@@ -11,8 +11,10 @@ pub mod table_events;
 pub mod table_fields;
 pub mod table_indexes;
 pub mod tables;
+pub mod users;
 
 pub use change_detection::*;
 pub use comparison_init::*;
 pub use meta::*;
 pub use tables::*;
+pub use users::*;
@@ -188,8 +188,8 @@ impl<'a, R: DbResources> ComparisonFields<'a, R> {
         left: &DefineStatementRaw,
         right: &DefineStatementRaw,
     ) -> &'a mut Queries {
-        acc.add_up(QueryType::Define(right.clone()));
-        acc.add_down(QueryType::Define(left.clone()));
+        acc.add_up(QueryType::Redefine(right.clone()));
+        acc.add_down(QueryType::Redefine(left.clone()));
         acc
     }
 
@@ -32,4 +32,78 @@ impl TableResourcesMeta<Indexes> for ComparisonIndexes<'_> {
     fn get_table(&self) -> &Table {
         self.table
     }
+
+    fn queries(&self) -> MigrationResult<Queries> {
+        let left = self.get_left().get_names_as_set();
+        let right = self.get_right().get_names_as_set();
+        let index_names = right.union(&left);
+
+        let mut queries = Queries::default();
+        for name in index_names {
+            let def_right = self.get_right().get_definition(name).cloned();
+            let def_left = self.get_left().get_definition(name).cloned();
+
+            match DeltaTypeResource::from((def_left, def_right)) {
+                DeltaTypeResource::Create { right } => {
+                    queries.add_down(QueryType::Remove(right.as_remove_statement()?));
+                    queries.add_up(QueryType::Define(right));
+                }
+                DeltaTypeResource::Remove { left } => {
+                    queries.add_up(QueryType::Remove(left.as_remove_statement()?));
+                    queries.add_down(QueryType::Define(left));
+                }
+                // A vector index built for one dimension or distance cannot be reused
+                // for another, so it has to be dropped and rebuilt from scratch.
+                DeltaTypeResource::Update { left, right }
+                    if vector_params_changed(&left, &right) =>
+                {
+                    queries.add_up(QueryType::Remove(left.as_remove_statement()?));
+                    queries.add_up(QueryType::Define(right.clone()));
+                    queries.add_down(QueryType::Remove(right.as_remove_statement()?));
+                    queries.add_down(QueryType::Define(left));
+                }
+                DeltaTypeResource::Update { left, right } => {
+                    queries.add_up(QueryType::Redefine(right));
+                    queries.add_down(QueryType::Redefine(left));
+                }
+                DeltaTypeResource::NoChange => {}
+            };
+        }
+
+        Ok(queries)
+    }
+}
+
+/// Kind, dimension and distance of an M-Tree or HNSW index definition. They are read from
+/// the definition itself, as the SQL parser of this SurrealDB version does not know HNSW.
+fn vector_params(definition: &DefineStatementRaw) -> Option<(String, String, String)> {
+    let definition = definition.to_string().to_uppercase();
+    let tokens = definition
+        .split_whitespace()
+        .map(|token| token.trim_end_matches(';'))
+        .collect::<Vec<_>>();
+    let kind_position = tokens
+        .windows(2)
+        .position(|pair| matches!(pair[0], "MTREE" | "HNSW") && pair[1] == "DIMENSION")?;
+    let params = &tokens[kind_position + 1..];
+    let param = |name: &str| {
+        params
+            .iter()
+            .position(|token| *token == name)
+            .and_then(|position| params.get(position + 1))
+            .map(|value| value.to_string())
+    };
+
+    Some((
+        tokens[kind_position].to_string(),
+        param("DIMENSION")?,
+        param("DIST").unwrap_or_else(|| "EUCLIDEAN".to_string()),
+    ))
+}
+
+fn vector_params_changed(left: &DefineStatementRaw, right: &DefineStatementRaw) -> bool {
+    match (vector_params(left), vector_params(right)) {
+        (None, None) => false,
+        (left, right) => left != right,
+    }
 }
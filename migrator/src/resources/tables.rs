@@ -54,6 +54,14 @@ impl<R: DbResources> DbResourcesMeta<Tables> for ComparisonTables<'_, R> {
         self.resources.right_resources.tables()
     }
 
+    fn resource_type(&self) -> &'static str {
+        "table"
+    }
+
+    fn prompter(&self) -> &dyn Prompter {
+        self.prompter
+    }
+
     fn queries(&self) -> MigrationResult<Queries> {
         let left = self.get_left().get_names_as_set();
         let right = self.get_right().get_names_as_set();
@@ -104,11 +112,11 @@ impl<R: DbResources> DbResourcesMeta<Tables> for ComparisonTables<'_, R> {
                     extend_table_resources_down(&mut queries);
                 }
                 DeltaTypeResource::Update { left, right } => {
-                    queries.add_up(QueryType::Define(right));
+                    queries.add_up(QueryType::Redefine(right));
                     extend_table_resources_up(&mut queries);
                     extend_table_resources_down(&mut queries);
 
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_down(QueryType::Redefine(left));
                 }
                 DeltaTypeResource::Create { right } => {
                     queries.add_down(QueryType::Remove(right.as_remove_statement()?));
@@ -176,8 +184,8 @@ where
                     queries.add_down(QueryType::Define(left));
                 }
                 DeltaTypeResource::Update { left, right } => {
-                    queries.add_up(QueryType::Define(right));
-                    queries.add_down(QueryType::Define(left));
+                    queries.add_up(QueryType::Redefine(right));
+                    queries.add_down(QueryType::Redefine(left));
                 }
                 DeltaTypeResource::NoChange => {}
             };
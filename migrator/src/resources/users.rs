@@ -0,0 +1,134 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use surreal_query_builder::{
+    sql::{self, statements::DefineStatement, statements::DefineUserStatement, Statement},
+    Buildable, DbResources,
+};
+
+use crate::*;
+
+pub struct ComparisonUsers<'a, R: DbResources> {
+    pub resources: &'a ComparisonsInit<'a>,
+    pub codebase_resources: &'a R,
+}
+
+impl<R: DbResources> DbResourcesMeta<Users> for ComparisonUsers<'_, R> {
+    fn get_left(&self) -> Users {
+        self.resources.left_resources.users()
+    }
+
+    fn get_right(&self) -> Users {
+        self.resources.right_resources.users()
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "user"
+    }
+
+    fn prompter(&self) -> &dyn Prompter {
+        self.resources.prompter
+    }
+
+    // This does not use default implementation because users defined
+    // with a plain PASSWORD in the codebase get a new random password hash
+    // every time they are defined which would otherwise always show up as a change.
+    fn add_delta_queries(
+        &self,
+        queries: &mut Queries,
+        delta: DeltaTypeResource,
+    ) -> MigrationResult<()> {
+        match delta {
+            DeltaTypeResource::Update { left, right }
+                if is_only_rehashed_password(&left, &right, self.codebase_resources) =>
+            {
+                Ok(())
+            }
+            delta => add_resource_delta_queries(queries, delta),
+        }
+    }
+}
+
+/// Whether the user definitions only differ by the password hash of a user defined
+/// with a plain PASSWORD in the codebase, which gets a new random hash on every definition.
+pub(crate) fn is_only_rehashed_password(
+    left: &DefineStatementRaw,
+    right: &DefineStatementRaw,
+    codebase_resources: &impl DbResources,
+) -> bool {
+    let (Some(left), Some(right)) = (
+        parse_define_user(left.to_string()),
+        parse_define_user(right.to_string()),
+    ) else {
+        return false;
+    };
+
+    let same_apart_from_password = DefineUserStatement {
+        hash: String::new(),
+        code: String::new(),
+        ..left.clone()
+    } == DefineUserStatement {
+        hash: String::new(),
+        code: String::new(),
+        ..right
+    };
+
+    if !same_apart_from_password {
+        return false;
+    }
+
+    let Ok(left_hash) = PasswordHash::new(&left.hash) else {
+        return false;
+    };
+
+    codebase_resources
+        .users()
+        .iter()
+        .filter_map(|user| {
+            let raw = user.build();
+            let definition = parse_define_user(&raw)?;
+            let is_same_user = definition.name == left.name && definition.base == left.base;
+            is_same_user.then(|| extract_password(&raw)).flatten()
+        })
+        .any(|password| {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &left_hash)
+                .is_ok()
+        })
+}
+
+fn parse_define_user(definition: impl AsRef<str>) -> Option<DefineUserStatement> {
+    match sql::parse(definition.as_ref()).ok()?.first().cloned()? {
+        Statement::Define(DefineStatement::User(user)) => Some(user),
+        _ => None,
+    }
+}
+
+// Gets the plain password from a `DEFINE USER ... PASSWORD '...'` statement.
+fn extract_password(definition: &str) -> Option<String> {
+    let (_, after_keyword) = definition.split_once(" PASSWORD ")?;
+    let quote = after_keyword.chars().next()?;
+    if quote != '\'' && quote != '"' {
+        return None;
+    }
+
+    let mut escaped = false;
+    let literal_end = after_keyword
+        .char_indices()
+        .skip(1)
+        .find(|&(_, c)| {
+            let is_end = !escaped && c == quote;
+            escaped = !escaped && c == '\\';
+            is_end
+        })
+        .map(|(i, _)| i)?;
+
+    match sql::value(&after_keyword[..=literal_end]).ok()? {
+        sql::Value::Strand(password) => Some(password.0),
+        _ => None,
+    }
+}
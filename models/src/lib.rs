@@ -15,6 +15,7 @@ mod models;
 pub use models::alien::*;
 pub use models::attributes::*;
 pub use models::configuration::*;
+pub use models::document::*;
 pub use models::planet::*;
 pub use models::spaceship::*;
 pub use models::user::*;
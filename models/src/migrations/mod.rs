@@ -25,11 +25,13 @@ pub struct Resources;
 
 define_function!(get_animal_by_id(id: int){ return id;} );
 define_function!(get_animal_by_id2(id: int){ return id;} );
+define_function!(get_animal_name(name: string){ return name;} );
 
 #[allow(dead_code)]
 fn test_funcs() {
     get_animal_by_id!(1);
     get_animal_by_id2!(1);
+    get_animal_name!("lion");
 }
 
 create_param_name_fn!(__some_test_param1);
@@ -203,6 +205,253 @@ impl DbResources for ResourcesV10 {
     create_table_resources!(Animal, Crop, Planet);
 }
 
+/// Same as `Resources` but with the scope token defined on an existing scope.
+#[derive(Debug, Clone)]
+pub struct ResourcesV11;
+
+impl DbResources for ResourcesV11 {
+    create_table_resources!(
+        Animal,
+        AnimalSnakeCase,
+        Crop,
+        AnimalEatsCrop,
+        AnimalSnakeCaseEatsCrop,
+        Student,
+        Planet
+    );
+
+    fn analyzers(&self) -> Vec<Raw> {
+        Resources.analyzers()
+    }
+
+    fn functions(&self) -> Vec<Raw> {
+        Resources.functions()
+    }
+
+    fn params(&self) -> Vec<Raw> {
+        Resources.params()
+    }
+
+    fn scopes(&self) -> Vec<Raw> {
+        Resources.scopes()
+    }
+
+    fn tokens(&self) -> Vec<Raw> {
+        let token1 = define_token("oyelowo_token")
+            .on_namespace()
+            .type_(TokenType::PS512)
+            .value("abrakradabra");
+
+        let token2 = define_token("token2")
+            .on_database()
+            .type_(TokenType::EDDSA)
+            .value("abrakradabra");
+
+        let token3 = define_token("oyedayo_token")
+            .on_scope("scope1")
+            .type_(TokenType::HS256)
+            .value("abrakradabra");
+
+        vec![token1.to_raw(), token2.to_raw(), token3.to_raw()]
+    }
+
+    fn users(&self) -> Vec<Raw> {
+        Resources.users()
+    }
+}
+
+/// Same tables as `ResourcesV11` but with every other resource category
+/// created, updated or removed.
+#[derive(Debug, Clone)]
+pub struct ResourcesV12;
+
+impl DbResources for ResourcesV12 {
+    create_table_resources!(
+        Animal,
+        AnimalSnakeCase,
+        Crop,
+        AnimalEatsCrop,
+        AnimalSnakeCaseEatsCrop,
+        Student,
+        Planet
+    );
+
+    fn analyzers(&self) -> Vec<Raw> {
+        // Updated
+        let analyzer1 = define_analyzer("ascii")
+            .tokenizers([Tokenizer::Blank, Tokenizer::Class])
+            .filters([AnalyzerFilter::Lowercase, AnalyzerFilter::Ascii])
+            .to_raw();
+        // Created
+        let analyzer2 = define_analyzer("english")
+            .tokenizers([Tokenizer::Blank])
+            .filters([AnalyzerFilter::Snowball(SnowballLanguage::English)])
+            .to_raw();
+        vec![analyzer1, analyzer2]
+    }
+
+    fn functions(&self) -> Vec<Raw> {
+        // get_animal_by_id2 removed and get_animal_name created
+        vec![
+            get_animal_by_id_statement().to_raw(),
+            get_animal_name_statement().to_raw(),
+        ]
+    }
+
+    fn params(&self) -> Vec<Raw> {
+        // __some_test_param1 updated and __some_test_param3 removed
+        vec![
+            define_param(__some_test_param1())
+                .value("test param one updated")
+                .to_raw(),
+            define_param(__some_test_param2())
+                .value("test param two")
+                .to_raw(),
+        ]
+    }
+
+    fn scopes(&self) -> Vec<Raw> {
+        let user_credentials::Schema {
+            email,
+            passwordHash,
+            ..
+        } = &UserCredentials::schema();
+        let pass_input = "1234";
+        let scope = |scope, session_days: u64| {
+            define_scope(scope)
+                .session(std::time::Duration::from_secs(60 * 60 * 24 * session_days))
+                .signup(
+                    UserCredentials {
+                        id: UserCredentials::create_id("oyelowo".to_string()),
+                        email: "oyelowo.oss@gmail.com".into(),
+                        password_hash: "1234".into(),
+                    }
+                    .create(),
+                )
+                .signin(
+                    select(All).from(UserCredentials::table()).where_(
+                        cond(email.equal("oyelowo@codebreather.com"))
+                            .and(crypto::argon2::compare!(pass_input, passwordHash.deref())),
+                    ),
+                )
+                .to_raw()
+        };
+        // scope2 updated and scope3 created
+        vec![scope("scope1", 30), scope("scope2", 7), scope("scope3", 1)]
+    }
+
+    fn tokens(&self) -> Vec<Raw> {
+        // Namespace level definitions are left as they are since the migrator
+        // only manages database and scope level definitions.
+        let token1 = define_token("oyelowo_token")
+            .on_namespace()
+            .type_(TokenType::PS512)
+            .value("abrakradabra");
+
+        // Updated
+        let token2 = define_token("token2")
+            .on_database()
+            .type_(TokenType::EDDSA)
+            .value("abrakradabra_updated");
+
+        // Scope token updated
+        let token3 = define_token("oyedayo_token")
+            .on_scope("scope1")
+            .type_(TokenType::HS512)
+            .value("abrakradabra");
+
+        // Scope token created on the new scope
+        let token4 = define_token("oyedayo_token")
+            .on_scope("scope3")
+            .type_(TokenType::HS256)
+            .value("abrakradabra");
+
+        vec![
+            token1.to_raw(),
+            token2.to_raw(),
+            token3.to_raw(),
+            token4.to_raw(),
+        ]
+    }
+
+    fn users(&self) -> Vec<Raw> {
+        // Updated
+        let user1 = define_user("oyelowo")
+            .on_database()
+            .passhash("$argon2id$v=19$m=19456,t=2,p=1$u1CPdtdC0Ek5GE1gvidj/g$fjFa7PZM+4hp4hlUJN1fz/FaDAf7KY1Qu48F5m5P0V8")
+            .role(UserRole::Editor)
+            .to_raw();
+
+        let user2 = define_user("oyedayo")
+            .on_namespace()
+            .passhash("$argon2id$v=19$m=19456,t=2,p=1$u1CPdtdC0Ek5GE1gvidj/g$fjFa7PZM+4hp4hlUJN1fz/FaDAf7KY1Qu48F5m5P0V8")
+            .role(UserRole::Editor)
+            .to_raw();
+
+        // Created with a plain password which gets a new hash every time it is defined
+        let user3 = define_user("codebreather")
+            .on_database()
+            .password("codebreather_password")
+            .role(UserRole::Viewer)
+            .to_raw();
+
+        vec![user1, user2, user3]
+    }
+}
+
+/// Same as `ResourcesV11` but with a database user renamed.
+#[derive(Debug, Clone)]
+pub struct ResourcesV13;
+
+impl DbResources for ResourcesV13 {
+    create_table_resources!(
+        Animal,
+        AnimalSnakeCase,
+        Crop,
+        AnimalEatsCrop,
+        AnimalSnakeCaseEatsCrop,
+        Student,
+        Planet
+    );
+
+    fn analyzers(&self) -> Vec<Raw> {
+        ResourcesV11.analyzers()
+    }
+
+    fn functions(&self) -> Vec<Raw> {
+        ResourcesV11.functions()
+    }
+
+    fn params(&self) -> Vec<Raw> {
+        ResourcesV11.params()
+    }
+
+    fn scopes(&self) -> Vec<Raw> {
+        ResourcesV11.scopes()
+    }
+
+    fn tokens(&self) -> Vec<Raw> {
+        ResourcesV11.tokens()
+    }
+
+    fn users(&self) -> Vec<Raw> {
+        // oyelowo renamed
+        let user1 = define_user("oyelowo_owner")
+            .on_database()
+            .passhash("$argon2id$v=19$m=19456,t=2,p=1$u1CPdtdC0Ek5GE1gvidj/g$fjFa7PZM+4hp4hlUJN1fz/FaDAf7KY1Qu48F5m5P0V8")
+            .role(UserRole::Owner)
+            .to_raw();
+
+        let user2 = define_user("oyedayo")
+            .on_namespace()
+            .passhash("$argon2id$v=19$m=19456,t=2,p=1$u1CPdtdC0Ek5GE1gvidj/g$fjFa7PZM+4hp4hlUJN1fz/FaDAf7KY1Qu48F5m5P0V8")
+            .role(UserRole::Editor)
+            .to_raw();
+
+        vec![user1, user2]
+    }
+}
+
 pub mod invalid_cases {
     use super::*;
 
@@ -291,6 +540,70 @@ pub struct PlanetV2 {
     pub labels: Vec<String>,
 }
 
+#[derive(Node, TableResources, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = article, schemafull)]
+pub struct Article {
+    pub id: SurrealSimpleId<Self>,
+    pub title: String,
+    #[orm(ty = "array<float>", mtree(dimension = 4, dist = cosine))]
+    pub embedding: Vec<f64>,
+}
+
+#[derive(Node, TableResources, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = article, schemafull, relax_table)]
+pub struct ArticleV2 {
+    pub id: SurrealSimpleId<Self>,
+    pub title: String,
+    #[orm(ty = "array<float>", mtree(dimension = 8, dist = euclidean))]
+    pub embedding: Vec<f64>,
+}
+
+#[derive(Node, TableResources, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(
+    table = library,
+    schemafull,
+    index(unique, fields(name, city)),
+    event(
+        name = "library_opened",
+        when = cond(isOpen.eq(true)),
+        then = select(All).from(Crop::table())
+    )
+)]
+pub struct Library {
+    pub id: SurrealSimpleId<Self>,
+    #[orm(index(unique))]
+    pub code: String,
+    pub name: String,
+    pub city: String,
+    #[orm(index)]
+    pub is_open: bool,
+}
+
+#[derive(Node, TableResources, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(
+    table = library,
+    schemafull,
+    relax_table,
+    index(fields(city, name), name = "name_city_index"),
+    event(
+        name = "library_opened",
+        when = cond(isOpen.eq(true)).and(city.eq("Helsinki")),
+        then = select(All).from(Crop::table())
+    )
+)]
+pub struct LibraryV2 {
+    pub id: SurrealSimpleId<Self>,
+    pub code: String,
+    pub name: String,
+    pub city: String,
+    #[orm(index)]
+    pub is_open: bool,
+}
+
 #[derive(Node, TableResources, Serialize, Deserialize, Debug, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 #[orm(table = student, schemafull)]
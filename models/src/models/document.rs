@@ -0,0 +1,31 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use serde::{Deserialize, Serialize};
+use surreal_orm::{Node, SurrealSimpleId};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = document)]
+pub struct Document {
+    pub id: SurrealSimpleId<Self>,
+    pub title: String,
+    #[orm(ty = "array<float>", mtree(dimension = 4, dist = cosine, ty = f32))]
+    pub embedding: Vec<f64>,
+}
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = passage)]
+pub struct Passage {
+    pub id: SurrealSimpleId<Self>,
+    pub text: String,
+    #[orm(
+        ty = "array<float>",
+        hnsw(dimension = 4, dist = euclidean, ty = f32, efc = 150, m = 12)
+    )]
+    pub embedding: Vec<f64>,
+}
@@ -0,0 +1,11 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+#[test]
+fn test_query_macro_rejects_unknown_schema_references() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/query_*.rs");
+}
@@ -0,0 +1,8 @@
+use surreal_models::Weapon;
+use surreal_orm::query;
+use surrealdb::{engine::local::Db, Surreal};
+
+fn main() {
+    let db: Surreal<Db> = Surreal::init();
+    let _query = query!(db, models = [Weapon], "SELECT name FROM weapons");
+}
@@ -0,0 +1,126 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::ops::Deref;
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    functions::crypto,
+    statements::{define_scope, for_permission, select, Permissions},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = member, account = "member_scope", permissions = member_permissions())]
+struct Member {
+    id: SurrealSimpleId<Self>,
+    email: String,
+    pass: String,
+}
+
+fn member_permissions() -> Permissions {
+    let member::Schema { id, .. } = Member::schema();
+
+    Permissions::from(vec![
+        for_permission(CrudType::Select).where_(id.equal(Param::new("auth")))
+    ])
+}
+
+#[derive(Serialize)]
+struct Credentials<'a> {
+    email: &'a str,
+    pass: &'a str,
+}
+
+async fn define_member_scope(db: Surreal<surrealdb::engine::local::Db>) -> SurrealOrmResult<()> {
+    let member::Schema { email, pass, .. } = Member::schema();
+
+    Member::define_table().run(db.clone()).await?;
+    // The scope name is bound as a parameter, which DEFINE SCOPE does not accept,
+    // so the bindings are inlined with to_raw
+    define_scope(Member::scope().to_string())
+        .session(std::time::Duration::from_secs(60 * 60 * 24))
+        .signup(Raw::new(
+            "CREATE member SET email = $email, pass = crypto::argon2::generate($pass)",
+        ))
+        .signin(
+            select(All).from(Member::table()).where_(
+                cond(email.equal(Param::new("email")))
+                    .and(crypto::argon2::compare!(pass.deref(), Param::new("pass"))),
+            ),
+        )
+        .to_raw()
+        .run(db)
+        .await?;
+    Ok(())
+}
+
+#[test]
+fn test_account_attribute_sets_scope() {
+    assert_eq!(Member::scope().to_string(), "member_scope");
+}
+
+#[tokio::test]
+async fn test_account_signup_and_signin() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    define_member_scope(db.clone()).await?;
+
+    let credentials = Credentials {
+        email: "oyelowo@codebreather.com",
+        pass: "1234",
+    };
+
+    let signed_up = Member::signup(db.clone(), "test", "test", &credentials).await?;
+    assert_eq!(signed_up.account.email, "oyelowo@codebreather.com");
+    assert_ne!(signed_up.account.pass, "1234");
+    assert!(!signed_up.token.as_insecure_token().is_empty());
+
+    let signed_in = Member::signin(db.clone(), "test", "test", &credentials).await?;
+    assert_eq!(
+        signed_in.account.id.to_thing(),
+        signed_up.account.id.to_thing()
+    );
+    assert!(!signed_in.token.as_insecure_token().is_empty());
+
+    let authenticated = Member::authenticated_account(db.clone()).await?;
+    assert_eq!(authenticated.id.to_thing(), signed_up.account.id.to_thing());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_account_signin_with_wrong_credentials_fails() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    define_member_scope(db.clone()).await?;
+
+    Member::signup(
+        db.clone(),
+        "test",
+        "test",
+        Credentials {
+            email: "oyelowo@codebreather.com",
+            pass: "1234",
+        },
+    )
+    .await?;
+
+    let signed_in = Member::signin(
+        db.clone(),
+        "test",
+        "test",
+        Credentials {
+            email: "oyelowo@codebreather.com",
+            pass: "wrong",
+        },
+    )
+    .await;
+    assert!(matches!(signed_in, Err(SurrealOrmError::Authentication(_))));
+    Ok(())
+}
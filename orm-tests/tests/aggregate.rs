@@ -0,0 +1,144 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{statements::insert, *};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    Surreal,
+};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = sale)]
+struct Sale {
+    id: SurrealSimpleId<Self>,
+    country: String,
+    amount: f64,
+    paid: bool,
+}
+
+#[derive(Aggregate, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SalesPerCountry {
+    #[agg(group_key = Sale::schema().country)]
+    country: String,
+    #[agg(sum = Sale::schema().amount)]
+    total_amount: f64,
+    #[agg(max = Sale::schema().amount)]
+    largest_amount: f64,
+    #[agg(count)]
+    sales: u64,
+    #[agg(count = Sale::schema().paid)]
+    paid_sales: u64,
+}
+
+#[derive(Aggregate, Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct SalesSummary {
+    #[agg(mean = Sale::schema().amount)]
+    mean_amount: f64,
+    #[agg(min = Sale::schema().amount)]
+    smallest_amount: f64,
+    #[agg(count)]
+    #[serde(rename = "count")]
+    sales: u64,
+}
+
+async fn create_sales() -> SurrealOrmResult<Surreal<Db>> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let sales = [
+        ("Nigeria", 10.5, true),
+        ("Nigeria", 20.0, false),
+        ("Finland", 30.25, true),
+        ("Nigeria", 4.5, true),
+    ]
+    .into_iter()
+    .map(|(country, amount, paid)| Sale {
+        id: Sale::create_simple_id(),
+        country: country.to_string(),
+        amount,
+        paid,
+    })
+    .collect::<Vec<_>>();
+    insert(sales).return_many(db.clone()).await?;
+
+    Ok(db)
+}
+
+#[test]
+fn test_aggregate_query() {
+    let sale::Schema { amount, .. } = Sale::schema();
+
+    let statement = SalesPerCountry::aggregate(Sale::table()).where_(amount.gt(5));
+
+    assert_eq!(
+        statement.to_raw().build(),
+        "SELECT country AS country, math::sum(amount) AS totalAmount, \
+        math::max(amount) AS largestAmount, count() AS sales, count(paid) AS paidSales \
+        FROM sale WHERE amount > 5 GROUP BY country;"
+    );
+    assert_eq!(
+        SalesSummary::aggregate(Sale::table()).to_raw().build(),
+        "SELECT math::mean(amount) AS meanAmount, math::min(amount) AS smallestAmount, \
+        count() AS count FROM sale GROUP ALL;"
+    );
+}
+
+#[tokio::test]
+async fn test_aggregate_with_group_keys() -> SurrealOrmResult<()> {
+    let db = create_sales().await?;
+
+    let mut sales = SalesPerCountry::aggregate(Sale::table())
+        .return_many::<SalesPerCountry>(db.clone())
+        .await?;
+    sales.sort_by(|a, b| a.country.cmp(&b.country));
+
+    assert_eq!(
+        sales,
+        vec![
+            SalesPerCountry {
+                country: "Finland".to_string(),
+                total_amount: 30.25,
+                largest_amount: 30.25,
+                sales: 1,
+                paid_sales: 1,
+            },
+            SalesPerCountry {
+                country: "Nigeria".to_string(),
+                total_amount: 35.0,
+                largest_amount: 20.0,
+                sales: 3,
+                paid_sales: 2,
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_aggregate_without_group_keys() -> SurrealOrmResult<()> {
+    let db = create_sales().await?;
+    let sale::Schema { paid, .. } = Sale::schema();
+
+    let summary = SalesSummary::aggregate(Sale::table())
+        .where_(paid.is(true))
+        .return_one::<SalesSummary>(db.clone())
+        .await?;
+
+    assert_eq!(
+        summary,
+        Some(SalesSummary {
+            mean_amount: 15.083333333333334,
+            smallest_amount: 4.5,
+            sales: 3,
+        })
+    );
+    Ok(())
+}
@@ -6,7 +6,7 @@
 
 use surreal_models::{space_ship, weapon, SpaceShip, Weapon};
 use surreal_orm::{
-    statements::{insert, select, select_value},
+    statements::{insert, select, select_value, CursorPage, Page},
     *,
 };
 use surrealdb::{
@@ -323,3 +323,233 @@ async fn test_delete_where() -> SurrealOrmResult<()> {
     assert_eq!(found_spaceships.len(), 0);
     Ok(())
 }
+
+#[tokio::test]
+async fn test_paginate() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    create_test_data(db.clone()).await;
+    let weapon::Schema { strength, .. } = &Weapon::schema();
+
+    let paginated = Weapon::find_where(strength.gte(500))
+        .order_by(strength.asc())
+        .paginate(2, 30);
+
+    assert_eq!(
+        paginated.items_statement().to_raw().build(),
+        "SELECT * FROM weapon WHERE strength >= 500 ORDER BY strength ASC LIMIT 30 START AT 30;"
+    );
+    assert_eq!(
+        paginated.count_statement().to_raw().build(),
+        "SELECT VALUE count FROM (SELECT count() FROM weapon WHERE strength >= 500 GROUP ALL);"
+    );
+
+    let page = paginated.get(db.clone()).await?;
+
+    assert_eq!(page.items.len(), 30);
+    assert_eq!(page.items[0].strength, 530.0);
+    assert_eq!(page.total, 500);
+    assert_eq!(page.total_pages(), 17);
+    assert!(page.has_prev());
+    assert!(page.has_next());
+
+    let last_page = Weapon::find_where(strength.gte(500))
+        .order_by(strength.asc())
+        .paginate(17, 30)
+        .get(db.clone())
+        .await?;
+
+    assert_eq!(last_page.items.len(), 20);
+    assert_eq!(last_page.items[19].strength, 999.0);
+    assert!(last_page.has_prev());
+    assert!(!last_page.has_next());
+
+    let first_page: Page<Weapon> = select(All)
+        .from(Weapon::table())
+        .paginate(0, 1000)
+        .get(db.clone())
+        .await?;
+
+    assert_eq!(first_page.page, 1);
+    assert_eq!(first_page.items.len(), 1000);
+    assert!(!first_page.has_prev());
+    assert!(!first_page.has_next());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_after_cursor() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    create_test_data(db.clone()).await;
+    let weapon::Schema { strength, .. } = &Weapon::schema();
+
+    let mut cursor = None;
+    let mut strengths = vec![];
+    let mut pages = 0;
+    loop {
+        let page = Weapon::find_where(strength.lt(250))
+            .order_by(strength.desc())
+            .after(cursor.as_deref(), 100)
+            .get(db.clone())
+            .await?;
+        pages += 1;
+        strengths.extend(page.items.iter().map(|w| w.strength));
+
+        match page.next_cursor {
+            Some(next_cursor) => cursor = Some(next_cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(pages, 3);
+    assert_eq!(
+        strengths,
+        (0..250).rev().map(|i| i as f64).collect::<Vec<_>>()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_after_cursor_breaks_ties_by_id() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let weapons = (0..10)
+        .map(|i| Weapon {
+            name: format!("weapon-{i}"),
+            strength: (i % 2) as f64,
+            ..Default::default()
+        })
+        .collect::<Vec<Weapon>>();
+    insert(weapons).run(db.clone()).await.unwrap();
+    let weapon::Schema { id, strength, .. } = &Weapon::schema();
+
+    let first_page: CursorPage<Weapon> = select(All)
+        .from(Weapon::table())
+        .order_by(strength.asc())
+        .after(None, 4)
+        .get(db.clone())
+        .await?;
+    let second_page_statement = select(All)
+        .from(Weapon::table())
+        .order_by(strength.asc())
+        .after::<Weapon>(first_page.next_cursor.as_deref(), 4);
+    let second_page = second_page_statement.get(db.clone()).await?;
+    let third_page = select(All)
+        .from(Weapon::table())
+        .order_by(strength.asc())
+        .after::<Weapon>(second_page.next_cursor.as_deref(), 4)
+        .get(db.clone())
+        .await?;
+
+    assert!(second_page_statement
+        .build()
+        .starts_with("SELECT * FROM weapon WHERE (strength > $_param_"));
+    assert!(second_page_statement
+        .build()
+        .ends_with("ORDER BY strength ASC, id ASC LIMIT 5;"));
+    assert!(first_page.has_next());
+    assert!(second_page.has_next());
+    assert!(!third_page.has_next());
+
+    let ordered_ids = select(All)
+        .from(Weapon::table())
+        .order_by(&[strength.asc(), id.asc()])
+        .return_many::<Weapon>(db.clone())
+        .await?
+        .into_iter()
+        .map(|w| w.id.to_thing())
+        .collect::<Vec<_>>();
+    let paged_ids = [first_page, second_page, third_page]
+        .into_iter()
+        .flat_map(|page| page.items)
+        .map(|w| w.id.to_thing())
+        .collect::<Vec<_>>();
+    assert_eq!(paged_ids, ordered_ids);
+
+    let invalid = Weapon::find_where(strength.gte(0))
+        .order_by(strength.asc())
+        .after(Some("not-a-cursor"), 3)
+        .get(db.clone())
+        .await;
+    assert!(matches!(invalid, Err(SurrealOrmError::QueryBuilder(_))));
+
+    // The values of a cursor are bound as parameters, so a subquery in it must never run
+    let malicious_cursor = "[(DELETE weapon), weapon:one]"
+        .bytes()
+        .map(|b| format!("{b:02x}"))
+        .collect::<String>();
+    let malicious = Weapon::find_where(strength.gte(0))
+        .order_by(strength.asc())
+        .after(Some(&malicious_cursor), 3)
+        .get(db.clone())
+        .await;
+    assert!(matches!(malicious, Err(SurrealOrmError::QueryBuilder(_))));
+    assert_eq!(
+        Weapon::count_all().get(db.clone()).await?,
+        ordered_ids.len()
+    );
+
+    let unordered = Weapon::find_where(strength.gte(0))
+        .after(None, 3)
+        .get(db.clone())
+        .await;
+    assert!(matches!(unordered, Err(SurrealOrmError::QueryBuilder(_))));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_after_cursor_with_multiple_ordering_fields() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let weapons = (0..6)
+        .map(|i| Weapon {
+            name: format!("weapon-{i}"),
+            strength: (i % 2) as f64,
+            ..Default::default()
+        })
+        .collect::<Vec<Weapon>>();
+    insert(weapons).run(db.clone()).await.unwrap();
+    let weapon::Schema { name, strength, .. } = &Weapon::schema();
+
+    let first_page: CursorPage<Weapon> = select(All)
+        .from(Weapon::table())
+        .order_by(&[strength.desc(), name.asc()])
+        .after(None, 4)
+        .get(db.clone())
+        .await?;
+    let second_page_statement = select(All)
+        .from(Weapon::table())
+        .order_by(&[strength.desc(), name.asc()])
+        .after::<Weapon>(first_page.next_cursor.as_deref(), 4);
+    let second_page = second_page_statement.get(db.clone()).await?;
+
+    assert_eq!(
+        second_page_statement.fine_tune_params(),
+        "SELECT * FROM weapon WHERE (strength < $_param_00000001) \
+            OR ((strength = $_param_00000002) AND (name > $_param_00000003)) \
+            OR ((strength = $_param_00000004) AND (name = $_param_00000005) AND (id < $_param_00000006)) \
+            ORDER BY strength DESC, name ASC, id DESC LIMIT 5;"
+    );
+    assert!(first_page.has_next());
+    assert!(!second_page.has_next());
+
+    let paged_names = [first_page, second_page]
+        .into_iter()
+        .flat_map(|page| page.items)
+        .map(|w| w.name)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        paged_names,
+        ["weapon-1", "weapon-3", "weapon-5", "weapon-0", "weapon-2", "weapon-4"]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    statements::{create, define_scope, for_permission, Permissions},
+    *,
+};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    sql, Surreal,
+};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = employee, schemafull)]
+struct Employee {
+    id: SurrealSimpleId<Self>,
+    #[orm(index(unique))]
+    email: String,
+    #[orm(assert = cond(value().gte(18)))]
+    age: u8,
+}
+
+// Writes to the same table as `Employee` without its type guarantees
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = employee, relax_table)]
+struct EmployeeDraft {
+    id: SurrealSimpleId<Self>,
+    email: String,
+    age: String,
+}
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = member, account = "member_scope", permissions = member_permissions())]
+struct Member {
+    id: SurrealSimpleId<Self>,
+    email: String,
+}
+
+fn member_permissions() -> Permissions {
+    let member::Schema { id, .. } = Member::schema();
+
+    Permissions::from(vec![
+        for_permission(CrudType::Select).where_(id.equal(Param::new("auth")))
+    ])
+}
+
+async fn setup() -> SurrealOrmResult<Surreal<Db>> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    Employee::define_table().run(db.clone()).await?;
+    for definition in Employee::define_fields()
+        .into_iter()
+        .chain(Employee::define_indexes())
+    {
+        definition.run(db.clone()).await?;
+    }
+    Ok(db)
+}
+
+fn new_employee(email: &str, age: u8) -> Employee {
+    Employee {
+        id: Employee::create_simple_id(),
+        email: email.to_string(),
+        age,
+    }
+}
+
+#[tokio::test]
+async fn test_unique_index_violation() -> SurrealOrmResult<()> {
+    let db = setup().await?;
+    create()
+        .content(new_employee("oyelowo@codebreather.com", 30))
+        .get_one(db.clone())
+        .await?;
+
+    let result = create()
+        .content(new_employee("oyelowo@codebreather.com", 40))
+        .get_one(db.clone())
+        .await;
+
+    match result {
+        Err(SurrealOrmError::UniqueViolation {
+            index,
+            table,
+            value,
+        }) => {
+            assert_eq!(index, "email_index");
+            assert_eq!(table, "employee");
+            assert_eq!(value, "'oyelowo@codebreather.com'");
+        }
+        _ => panic!("Expected a unique violation but got {result:?}"),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_field_assertion_failure() -> SurrealOrmResult<()> {
+    let db = setup().await?;
+
+    let result = new_employee("oyelowo@codebreather.com", 17)
+        .save()
+        .get_one(db.clone())
+        .await;
+
+    match result {
+        Err(SurrealOrmError::AssertionFailed { field, value }) => {
+            assert_eq!(field, "age");
+            assert_eq!(value, "17");
+        }
+        _ => panic!("Expected a failed assertion but got {result:?}"),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_schema_type_mismatch() -> SurrealOrmResult<()> {
+    let db = setup().await?;
+    let draft = EmployeeDraft {
+        id: EmployeeDraft::create_simple_id(),
+        email: "oyelowo@codebreather.com".to_string(),
+        age: "thirty".to_string(),
+    };
+
+    let result = draft.save().return_none(db.clone()).await;
+
+    match result {
+        Err(SurrealOrmError::SchemaTypeMismatch { field, expected }) => {
+            assert_eq!(field, "age");
+            assert_eq!(expected, "int");
+        }
+        _ => panic!("Expected a schema type mismatch but got {result:?}"),
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statement_errors_of_a_run_query() -> SurrealOrmResult<()> {
+    let db = setup().await?;
+
+    let mut response = Raw::new("CREATE employee SET email = 'oyelowo@codebreather.com', age = 3")
+        .run(db.clone())
+        .await?;
+    let result = response
+        .take::<sql::Value>(0)
+        .map_err(SurrealOrmError::from);
+
+    assert!(matches!(
+        result,
+        Err(SurrealOrmError::AssertionFailed { .. })
+    ));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_permission_denied() -> SurrealOrmResult<()> {
+    let db = setup().await?;
+    Member::define_table().run(db.clone()).await?;
+    define_scope(Member::scope().to_string())
+        .session(std::time::Duration::from_secs(60 * 60))
+        .signup(Raw::new("CREATE member SET email = $email"))
+        .to_raw()
+        .run(db.clone())
+        .await?;
+
+    #[derive(Serialize)]
+    struct Credentials<'a> {
+        email: &'a str,
+    }
+    Member::signup(
+        db.clone(),
+        "test",
+        "test",
+        Credentials {
+            email: "oyelowo@codebreather.com",
+        },
+    )
+    .await?;
+
+    // Scope users are not allowed to define tables
+    let mut response = Employee::define_table().run(db.clone()).await?;
+    let result = response
+        .take::<sql::Value>(0)
+        .map_err(SurrealOrmError::from);
+
+    assert!(matches!(result, Err(SurrealOrmError::PermissionDenied(_))));
+    Ok(())
+}
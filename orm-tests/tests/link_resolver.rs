@@ -0,0 +1,192 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use async_graphql::dataloader::DataLoader;
+use chrono::Utc;
+use geo::{line_string, point, polygon};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+use surreal_models::{alien, Alien, SpaceShip, Weapon};
+use surreal_orm::{
+    statements::{create, select},
+    *,
+};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    Surreal,
+};
+
+fn new_alien(name: &str, weapon: LinkOne<Weapon>, space_ships: LinkMany<SpaceShip>) -> Alien {
+    Alien {
+        id: Alien::create_simple_id(),
+        name: name.to_string(),
+        age: 20,
+        created: Utc::now(),
+        life_expectancy: Duration::from_secs(100),
+        line_string: line_string![(x: 40.02, y: 116.34), (x: 40.03, y: 116.35)],
+        territory_area: polygon![(x: 40.02, y: 116.34), (x: 40.02, y: 116.35), (x: 40.03, y: 116.35), (x: 40.02, y: 116.34)],
+        home: point! { x: 40.02f64, y: 116.34 },
+        tags: vec![],
+        ally: LinkSelf::null(),
+        weapon,
+        space_ships,
+        planets_to_visit: Relate::null(),
+    }
+}
+
+fn new_space_ship(id: &str, name: &str) -> SpaceShip {
+    SpaceShip {
+        id: SpaceShip::create_id(id.to_string()),
+        name: name.to_string(),
+        created: Utc::now(),
+    }
+}
+
+async fn create_weapon(db: Surreal<Db>, name: &str) -> SurrealOrmResult<Weapon> {
+    create()
+        .content(Weapon {
+            name: name.to_string(),
+            ..Default::default()
+        })
+        .get_one(db)
+        .await
+}
+
+async fn load_aliens(db: Surreal<Db>) -> SurrealOrmResult<Vec<Alien>> {
+    let alien::Schema { name, .. } = Alien::schema();
+    select(All)
+        .from(Alien::table())
+        .order_by(name.asc())
+        .return_many::<Alien>(db)
+        .await
+}
+
+#[tokio::test]
+async fn test_link_resolver_resolves_links_of_many_records() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let laser = create_weapon(db.clone(), "Laser").await?;
+    let sword = create_weapon(db.clone(), "Sword").await?;
+    let falcon = create()
+        .content(new_space_ship("falcon", "Falcon"))
+        .get_one(db.clone())
+        .await?;
+    let unsaved_ship = new_space_ship("unsaved", "Unsaved");
+
+    for (name, weapon, space_ships) in [
+        ("Ada", LinkOne::from(laser.clone()), vec![falcon.clone()]),
+        ("Bola", LinkOne::from(laser.clone()), vec![]),
+        (
+            "Chidi",
+            LinkOne::from(sword.clone()),
+            vec![falcon.clone(), unsaved_ship.clone()],
+        ),
+        ("Dayo", LinkOne::null(), vec![]),
+    ] {
+        create()
+            .content(new_alien(name, weapon, LinkMany::from(space_ships)))
+            .get_one(db.clone())
+            .await?;
+    }
+
+    let mut aliens = load_aliens(db.clone()).await?;
+    let resolver = LinkResolver::new(db.clone());
+    resolver
+        .resolve(&mut aliens, |alien| &mut alien.weapon)
+        .await?;
+    resolver
+        .resolve(&mut aliens, |alien| &mut alien.space_ships)
+        .await?;
+
+    let weapons = aliens
+        .iter()
+        .map(|alien| alien.weapon.value().map(|weapon| weapon.name.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        weapons,
+        vec![Some("Laser"), Some("Laser"), Some("Sword"), None]
+    );
+
+    let chidi = &aliens[2];
+    assert_eq!(chidi.space_ships.values_truthy()[0].name, "Falcon");
+    // A link to a record which does not exist is left as an id
+    assert_eq!(
+        chidi.space_ships[1].get_id(),
+        Some(&unsaved_ship.id.to_thing())
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_link_resolver_caches_loaded_records() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let laser = create_weapon(db.clone(), "Laser").await?;
+    create()
+        .content(new_alien(
+            "Ada",
+            LinkOne::from(laser.clone()),
+            LinkMany::null(),
+        ))
+        .get_one(db.clone())
+        .await?;
+
+    let resolver = LinkResolver::new(db.clone());
+    let mut aliens = load_aliens(db.clone()).await?;
+    resolver
+        .resolve(&mut aliens, |alien| &mut alien.weapon)
+        .await?;
+    assert_eq!(aliens[0].weapon.value().unwrap().name, "Laser");
+
+    laser.delete().run(db.clone()).await?;
+
+    let mut aliens = load_aliens(db.clone()).await?;
+    resolver
+        .resolve(&mut aliens, |alien| &mut alien.weapon)
+        .await?;
+    assert_eq!(aliens[0].weapon.value().unwrap().name, "Laser");
+
+    let mut aliens = load_aliens(db.clone()).await?;
+    LinkResolver::new(db.clone())
+        .resolve(&mut aliens, |alien| &mut alien.weapon)
+        .await?;
+    assert!(aliens[0].weapon.value().is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_link_resolver_as_data_loader() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let laser = create_weapon(db.clone(), "Laser").await?;
+    let sword = create_weapon(db.clone(), "Sword").await?;
+    let unsaved_weapon_id = Weapon::create_simple_id().to_thing();
+
+    let loader = DataLoader::new(
+        LinkResolver::new(db.clone()).loader::<Weapon>(),
+        tokio::spawn,
+    );
+    let weapons = loader
+        .load_many([
+            laser.id.to_thing(),
+            sword.id.to_thing(),
+            unsaved_weapon_id.clone(),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(weapons.len(), 2);
+    assert_eq!(weapons[&laser.id.to_thing()].name, "Laser");
+    assert_eq!(weapons[&sword.id.to_thing()].name, "Sword");
+    assert!(loader.load_one(unsaved_weapon_id).await.unwrap().is_none());
+
+    Ok(())
+}
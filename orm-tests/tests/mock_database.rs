@@ -0,0 +1,176 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    statements::{delete, insert, select},
+    *,
+};
+use surrealdb::{Connection, Surreal};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = invoice)]
+struct Invoice {
+    id: SurrealId<Self, String>,
+    tenant: String,
+    amount: u32,
+}
+
+fn invoice(id: &str, tenant: &str, amount: u32) -> Invoice {
+    Invoice {
+        id: Invoice::create_id(id.to_string()),
+        tenant: tenant.to_string(),
+        amount,
+    }
+}
+
+fn summary(invoices: &[Invoice]) -> Vec<(String, u32)> {
+    invoices
+        .iter()
+        .map(|invoice| (invoice.id.to_string(), invoice.amount))
+        .collect()
+}
+
+// The code under test, which only knows about the `Surreal` client it is given
+async fn tenant_invoices(
+    db: Surreal<impl Connection>,
+    tenant: &str,
+) -> SurrealOrmResult<Vec<Invoice>> {
+    let invoice::Schema {
+        tenant: tenant_field,
+        ..
+    } = Invoice::schema();
+
+    select(All)
+        .from(Invoice::table())
+        .where_(tenant_field.eq(tenant))
+        .return_many(db)
+        .await
+}
+
+#[tokio::test]
+async fn test_canned_response() -> SurrealOrmResult<()> {
+    let mock = MockDatabase::new().await?;
+    let invoices = vec![invoice("one", "acme", 10)];
+    mock.respond(r"^SELECT \* FROM invoice WHERE", &invoices)?;
+
+    let result = mock.scope(tenant_invoices(mock.db(), "acme")).await?;
+
+    assert_eq!(summary(&result), summary(&invoices));
+    let query = mock.assert_ran_once(r"^SELECT \* FROM invoice WHERE");
+    assert_eq!(query.raw, "SELECT * FROM invoice WHERE tenant = 'acme';");
+    assert!(query.has_binding("acme"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_canned_error() -> SurrealOrmResult<()> {
+    let mock = MockDatabase::new().await?;
+    mock.fail(r"^DELETE invoice", "The database is unavailable");
+
+    let result = mock
+        .scope(delete::<Invoice>(Invoice::table()).run(mock.db()))
+        .await?
+        .take::<Vec<Invoice>>(0);
+
+    let error = result.expect_err("The canned error should be returned");
+    assert!(error.to_string().contains("The database is unavailable"));
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_queries_without_canned_response_run_in_memory() -> SurrealOrmResult<()> {
+    let mock = MockDatabase::new().await?;
+
+    let result = mock
+        .scope(async {
+            insert(vec![
+                invoice("one", "acme", 10),
+                invoice("two", "globex", 20),
+            ])
+            .return_many(mock.db())
+            .await?;
+            tenant_invoices(mock.db(), "globex").await
+        })
+        .await?;
+
+    assert_eq!(summary(&result), vec![("invoice:two".to_string(), 20)]);
+    assert_eq!(mock.queries().len(), 2);
+    assert_eq!(mock.queries_matching("^INSERT INTO invoice").len(), 1);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_statements_outside_scope_are_not_mocked() -> SurrealOrmResult<()> {
+    let mock = MockDatabase::new().await?;
+    mock.respond(
+        r"^SELECT \* FROM invoice WHERE",
+        vec![invoice("one", "acme", 10)],
+    )?;
+
+    let result = tenant_invoices(mock.db(), "acme").await?;
+
+    assert!(result.is_empty());
+    assert!(mock.queries().is_empty());
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_spawned_tasks_use_the_mock_only_when_scoped() -> SurrealOrmResult<()> {
+    let mock = MockDatabase::new().await?;
+    mock.respond(
+        r"^SELECT \* FROM invoice WHERE",
+        vec![invoice("one", "acme", 10)],
+    )?;
+
+    let db = mock.db();
+    let unscoped = mock
+        .scope(async move { tokio::spawn(tenant_invoices(db, "acme")).await })
+        .await
+        .unwrap()?;
+    assert!(unscoped.is_empty());
+    assert!(mock.queries().is_empty());
+
+    let scoped = tokio::spawn(mock.scope(tenant_invoices(mock.db(), "acme")))
+        .await
+        .unwrap()?;
+    assert_eq!(summary(&scoped), vec![("invoice:one".to_string(), 10)]);
+    mock.assert_ran_once(r"^SELECT \* FROM invoice WHERE");
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_record_and_replay() -> SurrealOrmResult<()> {
+    let snapshot = std::env::temp_dir().join("surreal_orm_mock_database_invoices.json");
+
+    let recorder = MockDatabase::record(&snapshot).await?;
+    let recorded = recorder
+        .scope(async {
+            insert(vec![invoice("one", "acme", 10), invoice("two", "acme", 30)])
+                .return_many(recorder.db())
+                .await?;
+            tenant_invoices(recorder.db(), "acme").await
+        })
+        .await?;
+    assert_eq!(recorded.len(), 2);
+
+    // The replayed database has no invoices, so the results come from the snapshot
+    let replayer = MockDatabase::replay(&snapshot).await?;
+    let replayed = replayer
+        .scope(tenant_invoices(replayer.db(), "acme"))
+        .await?;
+    assert_eq!(summary(&replayed), summary(&recorded));
+
+    let unrecorded = replayer
+        .scope(tenant_invoices(replayer.db(), "globex"))
+        .await;
+    assert!(matches!(unrecorded, Err(SurrealOrmError::MockSnapshot(_))));
+
+    std::fs::remove_file(snapshot).unwrap();
+    Ok(())
+}
@@ -0,0 +1,143 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use pretty_assertions::assert_eq;
+use surreal_models::{document, Document};
+use surreal_orm::{
+    statements::{insert, select, VectorDistance},
+    *,
+};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    Surreal,
+};
+
+async fn create_documents(db: Surreal<Db>, with_index: bool) -> SurrealOrmResult<()> {
+    if with_index {
+        for definition in Document::define_fields() {
+            definition.run(db.clone()).await?;
+        }
+    }
+
+    let documents = [
+        ("east", vec![1.0, 0.0, 0.0, 0.0]),
+        ("north", vec![0.0, 1.0, 0.0, 0.0]),
+        ("north_east", vec![1.0, 1.0, 0.0, 0.0]),
+        ("far_east", vec![10.0, 0.5, 0.0, 0.0]),
+    ]
+    .into_iter()
+    .map(|(title, embedding)| Document {
+        id: Document::create_simple_id(),
+        title: title.to_string(),
+        embedding,
+    })
+    .collect::<Vec<_>>();
+    insert(documents).return_many(db.clone()).await?;
+    Ok(())
+}
+
+fn titles(documents: &[(Document, f64)]) -> Vec<&str> {
+    documents
+        .iter()
+        .map(|(document, _)| document.title.as_str())
+        .collect()
+}
+
+#[test]
+fn test_nearest_statement() {
+    let document::Schema {
+        embedding, title, ..
+    } = Document::schema();
+    let statement = Document::nearest(&embedding, vec![1.0, 0.5, 0.0, 0.0], 3)
+        .distance(VectorDistance::Cosine)
+        .where_(title.not_equal("north"));
+
+    assert_eq!(
+        statement.to_raw().build(),
+        "SELECT *, 1 - vector::similarity::cosine(embedding, [1f, 0.5f, 0f, 0f]) AS nearest_distance \
+        FROM document WHERE (embedding <|3|> [1f, 0.5f, 0f, 0f]) AND (title != 'north') \
+        ORDER BY nearest_distance ASC;"
+    );
+}
+
+#[tokio::test]
+async fn test_knn_operator_finds_nearest_records() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    create_documents(db.clone(), false).await?;
+    let document::Schema { embedding, .. } = Document::schema();
+
+    let mut nearest = select(All)
+        .from(Document::table())
+        .where_(embedding.knn(2, vec![1.0, 0.1, 0.0, 0.0]))
+        .return_many::<Document>(db.clone())
+        .await?
+        .into_iter()
+        .map(|document| document.title)
+        .collect::<Vec<_>>();
+    nearest.sort();
+    assert_eq!(nearest, vec!["east", "north_east"]);
+
+    let mut nearest = select(All)
+        .from(Document::table())
+        .where_(embedding.knn_with_distance(1, VectorDistance::Manhattan, vec![0.0, 2.0, 0.0, 0.0]))
+        .return_many::<Document>(db.clone())
+        .await?
+        .into_iter()
+        .map(|document| document.title)
+        .collect::<Vec<_>>();
+    nearest.sort();
+    assert_eq!(nearest, vec!["north"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nearest_returns_records_ordered_by_distance() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    create_documents(db.clone(), false).await?;
+    let document::Schema { embedding, .. } = Document::schema();
+
+    let nearest = Document::nearest(&embedding, vec![1.0, 0.0, 0.0, 0.0], 3)
+        .get(db.clone())
+        .await?;
+
+    assert_eq!(titles(&nearest), vec!["east", "north_east", "north"]);
+    assert_eq!(nearest[0].1, 0.0);
+    assert_eq!(nearest[1].1, 1.0);
+    assert!((nearest[2].1 - 2f64.sqrt()).abs() < 1e-9);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_nearest_with_vector_index_and_filter() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    create_documents(db.clone(), true).await?;
+    let document::Schema {
+        embedding, title, ..
+    } = Document::schema();
+
+    // The far east document points the same way as the vector so is the nearest by cosine
+    let nearest = Document::nearest(&embedding, vec![20.0, 1.0, 0.0, 0.0], 2)
+        .distance(VectorDistance::Cosine)
+        .get(db.clone())
+        .await?;
+    assert_eq!(titles(&nearest), vec!["far_east", "east"]);
+    assert!(nearest[0].1 < 1e-9);
+    assert!(nearest[0].1 < nearest[1].1);
+
+    let nearest = Document::nearest(&embedding, vec![20.0, 1.0, 0.0, 0.0], 2)
+        .distance(VectorDistance::Cosine)
+        .where_(title.not_equal("far_east"))
+        .get(db.clone())
+        .await?;
+    assert_eq!(titles(&nearest), vec!["east"]);
+
+    Ok(())
+}
@@ -6,8 +6,8 @@
 
 use pretty_assertions::assert_eq;
 use surreal_models::{
-    Alien, AlienWithExplicitAttributes, StudentWithDefineAttr, StudentWithDefineFnAttr,
-    StudentWithGranularAttributes,
+    migrations::Library, Alien, AlienWithExplicitAttributes, Document, Passage,
+    StudentWithDefineAttr, StudentWithDefineFnAttr, StudentWithGranularAttributes,
 };
 use surreal_orm::*;
 use surrealdb::{engine::local::Mem, Surreal};
@@ -122,3 +122,92 @@ async fn test_node_attributes_with_define_fn_attribute() -> SurrealOrmResult<()>
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_node_mtree_vector_index_attribute() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let definitions = Document::define_fields()
+        .iter()
+        .map(|x| x.to_raw().build())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        definitions.join("\n"),
+        "DEFINE FIELD id ON TABLE document TYPE record<document>;
+DEFINE FIELD title ON TABLE document TYPE string;
+DEFINE FIELD embedding ON TABLE document TYPE array<float>;
+DEFINE INDEX embedding_vector_index ON TABLE document FIELDS embedding MTREE DIMENSION 4 DIST COSINE TYPE F32;"
+    );
+
+    // The generated definitions must be accepted by the database.
+    db.query(definitions.join("\n"))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    Ok(())
+}
+
+#[test]
+fn test_node_hnsw_vector_index_attribute() {
+    let definitions = Passage::define_fields()
+        .iter()
+        .map(|x| x.to_raw().build())
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        definitions.join("\n"),
+        "DEFINE FIELD id ON TABLE passage TYPE record<passage>;
+DEFINE FIELD text ON TABLE passage TYPE string;
+DEFINE FIELD embedding ON TABLE passage TYPE array<float>;
+DEFINE INDEX embedding_vector_index ON TABLE passage FIELDS embedding HNSW DIMENSION 4 TYPE F32 DIST EUCLIDEAN EFC 150 M 12;"
+    );
+}
+
+#[tokio::test]
+async fn test_node_index_and_event_attributes() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let indexes = <Library as TableResources>::indexes_definitions()
+        .iter()
+        .map(|x| x.to_raw().build())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        indexes.join("\n"),
+        "DEFINE INDEX code_index ON TABLE library FIELDS code UNIQUE;
+DEFINE INDEX isOpen_index ON TABLE library FIELDS isOpen;
+DEFINE INDEX name_city_index ON TABLE library FIELDS name, city UNIQUE;"
+    );
+
+    let events = <Library as TableResources>::events_definitions()
+        .iter()
+        .map(|x| x.to_raw().build())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        events.join("\n"),
+        "DEFINE EVENT library_opened ON TABLE library WHEN isOpen = true THEN SELECT * FROM crop;"
+    );
+
+    // The generated definitions must be accepted by the database.
+    let definitions = [
+        vec![Library::define_table().build()],
+        Library::define_fields()
+            .iter()
+            .map(|x| x.to_raw().build())
+            .collect(),
+        indexes,
+        events,
+    ]
+    .concat();
+    db.query(definitions.join("\n"))
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::sync::Once;
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    statements::{delete, insert, live, select, show_changes_for_table},
+    *,
+};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    sql, Surreal,
+};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = invoice)]
+struct Invoice {
+    id: SurrealSimpleId<Self>,
+    tenant: String,
+    amount: u32,
+}
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = audit_log)]
+struct AuditLog {
+    id: SurrealSimpleId<Self>,
+    action: String,
+}
+
+const TENANT: &str = "acme";
+
+// The interceptors apply to all the queries of the process, hence they are registered
+// once and only act on the tables of these tests.
+fn register_interceptors() {
+    static REGISTER: Once = Once::new();
+
+    REGISTER.call_once(|| {
+        // Scopes the invoices read to the current tenant
+        register_query_interceptor(|query: &mut InterceptedQuery| {
+            if query.kind() == "SELECT" && query.table().as_deref() == Some("invoice") {
+                let tenant = Binding::new(TENANT);
+                query.query = format!(
+                    "SELECT * FROM ({}) WHERE tenant = {};",
+                    query.query.trim_end_matches(';'),
+                    tenant.get_param_dollarised()
+                );
+                query.bindings.push(tenant);
+            }
+            Ok(())
+        });
+
+        // Blocks deleting all the audit logs at once
+        register_query_interceptor(|query: &mut InterceptedQuery| {
+            let unbounded_delete = query.statements()?.iter().any(|statement| {
+                matches!(statement, sql::Statement::Delete(delete) if delete.cond.is_none())
+            });
+
+            if unbounded_delete && query.table().as_deref() == Some("audit_log") {
+                return Err(SurrealOrmError::QueryRejected(
+                    "DELETE statements must have a WHERE clause".to_string(),
+                ));
+            }
+            Ok(())
+        });
+
+        // Keeps the audit logs from being watched or read from a changefeed
+        register_query_interceptor(|query: &mut InterceptedQuery| {
+            let kind = query.kind();
+            if (kind == "LIVE" || kind == "SHOW") && query.query.contains("audit_log") {
+                return Err(SurrealOrmError::QueryRejected(format!(
+                    "{kind} statements are not allowed on audit_log"
+                )));
+            }
+            Ok(())
+        });
+    });
+}
+
+async fn setup() -> Surreal<Db> {
+    register_interceptors();
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    db
+}
+
+#[tokio::test]
+async fn test_interceptor_rewrites_query() -> SurrealOrmResult<()> {
+    let db = setup().await;
+    let invoices = [("acme", 10), ("globex", 20), ("acme", 30)]
+        .into_iter()
+        .map(|(tenant, amount)| Invoice {
+            id: Invoice::create_simple_id(),
+            tenant: tenant.to_string(),
+            amount,
+        })
+        .collect::<Vec<_>>();
+    insert(invoices).return_many(db.clone()).await?;
+
+    let mut amounts = select(All)
+        .from(Invoice::table())
+        .return_many::<Invoice>(db.clone())
+        .await?
+        .into_iter()
+        .map(|invoice| invoice.amount)
+        .collect::<Vec<_>>();
+    amounts.sort();
+
+    assert_eq!(amounts, vec![10, 30]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_interceptor_rejects_query() -> SurrealOrmResult<()> {
+    let db = setup().await;
+    let audit_log::Schema { action, .. } = AuditLog::schema();
+    let logs = ["login", "logout"]
+        .into_iter()
+        .map(|action| AuditLog {
+            id: AuditLog::create_simple_id(),
+            action: action.to_string(),
+        })
+        .collect::<Vec<_>>();
+    insert(logs).return_many(db.clone()).await?;
+
+    let result = delete::<AuditLog>(AuditLog::table()).run(db.clone()).await;
+    assert!(matches!(result, Err(SurrealOrmError::QueryRejected(_))));
+
+    delete::<AuditLog>(AuditLog::table())
+        .where_(action.eq("login"))
+        .run(db.clone())
+        .await?;
+
+    let actions = select(All)
+        .from(AuditLog::table())
+        .return_many::<AuditLog>(db.clone())
+        .await?
+        .into_iter()
+        .map(|log| log.action)
+        .collect::<Vec<_>>();
+    assert_eq!(actions, vec!["logout"]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_interceptor_applies_to_live_and_show_changes() -> SurrealOrmResult<()> {
+    let db = setup().await;
+
+    let live_result = live(select(All).from(AuditLog::table()))
+        .stream::<AuditLog>(db.clone())
+        .await;
+    assert!(matches!(
+        live_result,
+        Err(SurrealOrmError::QueryRejected(_))
+    ));
+
+    let changes_result = show_changes_for_table(AuditLog::table())
+        .get_changes::<AuditLog>(db.clone())
+        .await;
+    assert!(matches!(
+        changes_result,
+        Err(SurrealOrmError::QueryRejected(_))
+    ));
+    Ok(())
+}
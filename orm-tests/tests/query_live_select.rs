@@ -0,0 +1,100 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use futures::StreamExt;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+use surreal_models::Weapon;
+use surreal_orm::{
+    statements::{live, select, LiveNotification},
+    *,
+};
+
+use surrealdb::{engine::local::Mem, sql, Surreal};
+
+#[tokio::test]
+async fn test_live_select_stream_typed_notifications() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let mut stream = live(select(All).from(Weapon::table()))
+        .stream::<Weapon>(db.clone())
+        .await?;
+    assert!(stream.query_id().is_some());
+
+    let weapon = Weapon {
+        name: "Laser".to_string(),
+        strength: 10.0,
+        ..Default::default()
+    };
+    let weapon_id = weapon.get_id_as_thing();
+
+    weapon.clone().create().return_one(db.clone()).await?;
+    match stream.next().await.expect("notification")? {
+        LiveNotification::Create(created) => assert_eq!(created.name, "Laser"),
+        other => panic!("expected create notification, got {other:?}"),
+    }
+
+    Weapon {
+        strength: 20.0,
+        ..weapon.clone()
+    }
+    .save()
+    .return_one(db.clone())
+    .await?;
+    match stream.next().await.expect("notification")? {
+        LiveNotification::Update(updated) => assert_eq!(updated.strength, 20.0),
+        other => panic!("expected update notification, got {other:?}"),
+    }
+
+    weapon.delete().run(db.clone()).await?;
+    match stream.next().await.expect("notification")? {
+        LiveNotification::Delete(id) => assert_eq!(id, weapon_id),
+        other => panic!("expected delete notification, got {other:?}"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_dropping_live_stream_kills_live_query() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let live_queries = || async {
+        let info: sql::Value = db
+            .query("INFO FOR TABLE weapon")
+            .await
+            .unwrap()
+            .take(0)
+            .unwrap();
+        match info.pick(&[sql::Part::from("lives")]) {
+            sql::Value::Object(lives) => lives.0.into_keys().collect::<Vec<_>>(),
+            _ => vec![],
+        }
+    };
+
+    let stream = live(select(All).from(Weapon::table()))
+        .stream::<Weapon>(db.clone())
+        .await?;
+    let query_id = stream.query_id().expect("live query id").to_string();
+    assert_eq!(live_queries().await, vec![query_id]);
+
+    drop(stream);
+
+    // The live query is killed by a task spawned when the stream is dropped.
+    let mut remaining = live_queries().await;
+    for _ in 0..50 {
+        if remaining.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        remaining = live_queries().await;
+    }
+    assert!(remaining.is_empty());
+
+    Ok(())
+}
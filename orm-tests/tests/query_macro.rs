@@ -1,3 +1,4 @@
+use surreal_models::Weapon;
 use surreal_orm::{query, query_raw};
 use surrealdb::{engine::local::Mem, Surreal};
 
@@ -41,6 +42,27 @@ async fn test_complex_multiple_queries() {
     .await;
 }
 
+#[tokio::test]
+async fn test_query_macro_checked_against_models() {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let mut response = query!(
+        db,
+        models = [Weapon],
+        [
+            "CREATE weapon:laser SET name = 'Laser', strength = 10",
+            "SELECT name, strength FROM weapon WHERE strength > $min ORDER BY strength"
+        ],
+        { min: 5 }
+    )
+    .await
+    .unwrap();
+
+    let names: Vec<String> = response.take((1, "name")).unwrap();
+    assert_eq!(names, vec!["Laser".to_string()]);
+}
+
 #[test]
 fn test_query_macro() {
     let query = query_raw!("SELECT name, age, * FROM users");
@@ -0,0 +1,222 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use chrono::Utc;
+use geo::{line_string, point, polygon};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+use surreal_models::{space_ship, Alien, SpaceShip, Weapon};
+use surreal_orm::{
+    statements::{create, eager, select},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+fn new_alien(
+    name: &str,
+    ally: LinkSelf<Alien>,
+    weapon: LinkOne<Weapon>,
+    space_ships: LinkMany<SpaceShip>,
+) -> Alien {
+    Alien {
+        id: Alien::create_simple_id(),
+        name: name.to_string(),
+        age: 20,
+        created: Utc::now(),
+        life_expectancy: Duration::from_secs(100),
+        line_string: line_string![(x: 40.02, y: 116.34), (x: 40.03, y: 116.35)],
+        territory_area: polygon![(x: 40.02, y: 116.34), (x: 40.02, y: 116.35), (x: 40.03, y: 116.35), (x: 40.02, y: 116.34)],
+        home: point! { x: 40.02f64, y: 116.34 },
+        tags: vec![],
+        ally,
+        weapon,
+        space_ships,
+        planets_to_visit: Relate::null(),
+    }
+}
+
+fn space_ship(id: &str, name: &str) -> SpaceShip {
+    SpaceShip {
+        id: SpaceShip::create_id(id.to_string()),
+        name: name.to_string(),
+        created: Utc::now(),
+    }
+}
+
+async fn create_aliens(db: Surreal<surrealdb::engine::local::Db>) -> SurrealOrmResult<Alien> {
+    let weapon = create()
+        .content(Weapon {
+            name: "Laser".to_string(),
+            ..Default::default()
+        })
+        .get_one(db.clone())
+        .await?;
+    let falcon = create()
+        .content(space_ship("falcon", "Falcon"))
+        .get_one(db.clone())
+        .await?;
+    let apollo = create()
+        .content(space_ship("apollo", "Apollo"))
+        .get_one(db.clone())
+        .await?;
+
+    let ally = create()
+        .content(new_alien(
+            "Ally",
+            LinkSelf::null(),
+            LinkOne::from(weapon.clone()),
+            LinkMany::from(vec![falcon.clone(), apollo]),
+        ))
+        .get_one(db.clone())
+        .await?;
+
+    create()
+        .content(new_alien(
+            "Oyelowo",
+            LinkSelf::from(ally),
+            LinkOne::from(weapon),
+            LinkMany::from(vec![falcon]),
+        ))
+        .get_one(db.clone())
+        .await
+}
+
+#[tokio::test]
+async fn test_select_with_loads_links_multiple_levels_deep() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let oyelowo = create_aliens(db.clone()).await?;
+    let alien = Alien::schema();
+    let space_ship::Schema { name, .. } = SpaceShip::schema();
+
+    let loaded = select(All)
+        .from(oyelowo.id.clone())
+        .with(alien.weapon())
+        .with(eager(alien.ally().spaceShips(E)).order_by(name.asc()))
+        .return_one::<Alien>(db.clone())
+        .await?
+        .expect("The alien should exist");
+
+    assert_eq!(loaded.weapon.value().unwrap().name, "Laser");
+    assert!(loaded.space_ships.values_truthy().is_empty());
+
+    let ally = loaded.ally.value().unwrap();
+    assert_eq!(ally.name, "Ally");
+    // The links of the ally not in the path are left as ids
+    assert!(ally.weapon.get_id().is_some());
+    let ally_space_ships = ally
+        .space_ships
+        .values_truthy()
+        .into_iter()
+        .map(|ship| ship.name.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ally_space_ships, vec!["Apollo", "Falcon"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_select_with_filters_and_limits_loaded_links() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let oyelowo = create_aliens(db.clone()).await?;
+    let alien = Alien::schema();
+    let space_ship::Schema { name, .. } = SpaceShip::schema();
+
+    let loaded = select(All)
+        .from(oyelowo.id.clone())
+        .with(alien.ally().spaceShips(cond(name.equal("Apollo"))))
+        .return_one::<Alien>(db.clone())
+        .await?
+        .expect("The alien should exist");
+    let ally = loaded.ally.value().unwrap();
+    assert_eq!(ally.space_ships.values_truthy().len(), 1);
+    assert_eq!(ally.space_ships.values_truthy()[0].name, "Apollo");
+
+    let loaded = select(All)
+        .from(oyelowo.id.clone())
+        .with(
+            eager(alien.ally().spaceShips(E))
+                .order_by(name.desc())
+                .limit(1),
+        )
+        .return_one::<Alien>(db.clone())
+        .await?
+        .expect("The alien should exist");
+    let ally = loaded.ally.value().unwrap();
+    assert_eq!(ally.space_ships.values_truthy().len(), 1);
+    assert_eq!(ally.space_ships.values_truthy()[0].name, "Falcon");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_select_with_dangling_link_errors() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let oyelowo = create_aliens(db.clone()).await?;
+    let alien = Alien::schema();
+
+    let unsaved_ship = space_ship("unsaved", "Unsaved");
+    let stranger = create()
+        .content(new_alien(
+            "Stranger",
+            LinkSelf::from(oyelowo),
+            LinkOne::null(),
+            LinkMany::from(vec![unsaved_ship.clone()]),
+        ))
+        .get_one(db.clone())
+        .await?;
+
+    let loaded = select(All)
+        .from(stranger.id.clone())
+        .with(alien.ally().spaceShips(E))
+        .with(alien.weapon())
+        .return_one::<Alien>(db.clone())
+        .await?
+        .expect("The alien should exist");
+    assert!(loaded.weapon.value().is_none());
+
+    let loaded = select(All)
+        .from(stranger.id.clone())
+        .with(alien.spaceShips(E))
+        .return_one::<Alien>(db.clone())
+        .await;
+
+    match loaded {
+        Err(SurrealOrmError::DanglingLinks { field, ids }) => {
+            assert_eq!(field, "spaceShips");
+            assert_eq!(ids, vec![unsaved_ship.id.to_thing()]);
+        }
+        _ => panic!("Expected the unsaved space ship to be a dangling link"),
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_select_with_paginate_counts_without_loading_links() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    create_aliens(db.clone()).await?;
+    let alien = Alien::schema();
+
+    let paginated = select(All)
+        .from(Alien::table())
+        .with(alien.weapon())
+        .order_by(alien.name.asc())
+        .paginate::<Alien>(1, 1);
+
+    insta::assert_snapshot!(paginated.items_statement().to_raw().build());
+    insta::assert_snapshot!(paginated.count_statement().to_raw().build());
+
+    let page = paginated.get(db.clone()).await?;
+    assert_eq!(page.total, 2);
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].weapon.value().unwrap().name, "Laser");
+
+    Ok(())
+}
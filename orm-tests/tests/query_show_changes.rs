@@ -0,0 +1,102 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    statements::{show_changes_for_table, TableChange},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = reading, schemafull, changefeed = "1h")]
+struct Reading {
+    id: SurrealSimpleId<Self>,
+    temperature: f64,
+}
+
+#[test]
+fn test_changefeed_table_attribute() {
+    assert_eq!(
+        Reading::define_table().build(),
+        "DEFINE TABLE reading SCHEMAFULL CHANGEFEED 1h;"
+    );
+}
+
+#[tokio::test]
+async fn test_show_changes_typed_reader_with_resume() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    db.query(Reading::define_table().build())
+        .query(
+            Reading::define_fields()
+                .iter()
+                .map(|field| field.build())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .await
+        .unwrap()
+        .check()
+        .unwrap();
+
+    let reading = Reading {
+        temperature: 20.5,
+        ..Default::default()
+    };
+    let reading_id = reading.get_id_as_thing();
+    reading.clone().create().return_one(db.clone()).await?;
+    Reading {
+        temperature: 21.0,
+        ..reading.clone()
+    }
+    .save()
+    .return_one(db.clone())
+    .await?;
+    reading.delete().run(db.clone()).await?;
+
+    let change_sets = show_changes_for_table(Reading::table())
+        .get_changes::<Reading>(db.clone())
+        .await?;
+
+    let changes = change_sets
+        .iter()
+        .flat_map(|change_set| change_set.changes.clone())
+        .collect::<Vec<_>>();
+    let temperatures = changes
+        .iter()
+        .filter_map(|change| match change {
+            TableChange::Create(reading) | TableChange::Update(reading) => {
+                Some(reading.temperature)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(temperatures, vec![20.5, 21.0]);
+    assert!(matches!(
+        changes.last(),
+        Some(TableChange::Delete(id)) if *id == reading_id
+    ));
+
+    let delete_change_set = change_sets.last().expect("delete change set");
+    let resumed = show_changes_for_table(Reading::table())
+        .since_versionstamp(delete_change_set.versionstamp)
+        .get_changes::<Reading>(db.clone())
+        .await?;
+    assert_eq!(resumed.len(), 1);
+    assert_eq!(resumed[0].versionstamp, delete_change_set.versionstamp);
+
+    let resumed_after_last = show_changes_for_table(Reading::table())
+        .since_versionstamp(delete_change_set.next_versionstamp())
+        .get_changes::<Reading>(db.clone())
+        .await?;
+    assert!(resumed_after_last.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    statements::{create, insert, select},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    Event, Metadata, Subscriber,
+};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = product)]
+struct Product {
+    id: SurrealSimpleId<Self>,
+    name: String,
+    #[orm(assert = cond(value().lt(1000)))]
+    price: u32,
+}
+
+fn product(name: &str, price: u32) -> Product {
+    Product {
+        id: Product::create_simple_id(),
+        name: name.to_string(),
+        price,
+    }
+}
+
+/// Records the fields of the query spans of the ORM.
+#[derive(Clone, Default)]
+struct QuerySpans(Arc<Mutex<Vec<HashMap<String, String>>>>);
+
+impl QuerySpans {
+    fn take(&self) -> Vec<HashMap<String, String>> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+struct FieldsVisitor<'a>(&'a mut HashMap<String, String>);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{value:?}"));
+    }
+}
+
+impl Subscriber for QuerySpans {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.target() == "surreal_orm"
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = HashMap::new();
+        span.record(&mut FieldsVisitor(&mut fields));
+        let mut spans = self.0.lock().unwrap();
+        spans.push(fields);
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &Id, values: &Record<'_>) {
+        let mut spans = self.0.lock().unwrap();
+        values.record(&mut FieldsVisitor(&mut spans[span.into_u64() as usize - 1]));
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[tokio::test]
+async fn test_statements_are_traced() -> SurrealOrmResult<()> {
+    let spans = QuerySpans::default();
+    let _subscriber = tracing::subscriber::set_default(spans.clone());
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    for definition in Product::define_fields() {
+        definition.run(db.clone()).await?;
+    }
+    let product::Schema { price, .. } = Product::schema();
+    spans.take();
+
+    insert(vec![product("Pen", 3), product("Book", 20)])
+        .return_many(db.clone())
+        .await?;
+    let span = &spans.take()[0];
+    assert_eq!(span["kind"], "INSERT");
+    assert_eq!(span["table"], "product");
+    assert_eq!(span["rows"], "2");
+    assert!(span.contains_key("duration_ms"));
+    assert!(!span.contains_key("error"));
+
+    let query = select(All).from(Product::table()).where_(price.gt(5));
+    query.return_many::<Product>(db.clone()).await?;
+    let span = &spans.take()[0];
+    assert_eq!(span["kind"], "SELECT");
+    assert_eq!(span["table"], "product");
+    assert_eq!(span["query"], query.build());
+    assert_eq!(span["bindings"], query.get_bindings().len().to_string());
+    assert_eq!(span["rows"], "1");
+    // The binding values are redacted by default
+    assert!(!span.contains_key("binding_values"));
+
+    let result = create()
+        .content(product("Car", 5000))
+        .get_one(db.clone())
+        .await;
+    assert!(result.is_err());
+    let span = &spans.take()[0];
+    assert_eq!(span["kind"], "CREATE");
+    assert_eq!(span["error"], "assertion_failed");
+    assert!(!span.contains_key("rows"));
+
+    set_binding_redaction(BindingRedaction::None);
+    query.return_many::<Product>(db.clone()).await?;
+    set_binding_redaction(BindingRedaction::Custom(|_| "***".to_string()));
+    query.return_many::<Product>(db.clone()).await?;
+    set_binding_redaction(BindingRedaction::All);
+
+    let traced = spans.take();
+    let binding = &query.get_bindings()[0];
+    assert_eq!(
+        traced[0]["binding_values"],
+        format!("{} = 5", binding.get_param_dollarised())
+    );
+    assert_eq!(
+        traced[1]["binding_values"],
+        format!("{} = ***", binding.get_param_dollarised())
+    );
+
+    Ok(())
+}
@@ -7,7 +7,7 @@
 use pretty_assertions::assert_eq;
 use surreal_models::{Account, Balance};
 use surreal_orm::{
-    statements::{begin_transaction, create_only, select, update},
+    statements::{self, begin_transaction, create_only, select, update, TransactionCompletion},
     *,
 };
 use surrealdb::{engine::local::Mem, Surreal};
@@ -220,3 +220,115 @@ async fn test_transaction_cancellation_increment_and_decrement_update() -> Surre
     assert_eq!(accounts.len(), 0);
     Ok(())
 }
+
+fn transfer_transaction(
+    id1: &SurrealId<Account, String>,
+    id2: &SurrealId<Account, String>,
+) -> TransactionCompletion {
+    let acc = Account::schema();
+    let amount_to_transfer = 300.00;
+
+    begin_transaction()
+        .query(query_turbo!(
+            let balance = create_only().content(Balance {
+                id: Balance::create_id("balance1".into()),
+                amount: amount_to_transfer,
+            });
+
+            create_only().content(Account {
+                id: id1.clone(),
+                balance: 135_605.16,
+            });
+
+            create_only().content(Account {
+                id: id2.clone(),
+                balance: 91_031.31,
+            });
+
+            update::<Account>(id1).set(acc.balance.increment_by(balance.with_path::<Balance>(E).amount));
+            update::<Account>(id2).set(acc.balance.decrement_by(amount_to_transfer));
+        ))
+        .commit_transaction()
+}
+
+#[tokio::test]
+async fn test_transaction_run_typed() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let id1 = &Account::create_id("one".into());
+    let id2 = &Account::create_id("two".into());
+
+    let (created1, created2, updated1, updated2) = transfer_transaction(id1, id2)
+        .run_typed::<(Option<Account>, Option<Account>, Vec<Account>, Vec<Account>)>(db.clone())
+        .await?;
+
+    assert_eq!(created1.unwrap().balance, 135_605.16);
+    assert_eq!(created2.unwrap().balance, 91_031.31);
+    assert_eq!(updated1.len(), 1);
+    assert_eq!(updated1[0].balance, 135_905.16);
+    assert_eq!(updated2[0].balance, 90_731.31);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_chain_run_typed_ignores_unit_results() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let id1 = &Account::create_id("one".into());
+
+    let (created, (), accounts) = chain(create_only().content(Account {
+        id: id1.clone(),
+        balance: 135_605.16,
+    }))
+    .chain(statements::let_("limit").equal_to(5))
+    .chain(update::<Account>(id1).set(Account::schema().balance.increment_by(300.00)))
+    .chain(select(All).from(Account::table()))
+    .run_typed::<(Option<Account>, (), Vec<Account>)>(db.clone())
+    .await?;
+
+    assert_eq!(created.unwrap().id.to_string(), "account:one");
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(accounts[0].balance, 135_905.16);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_run_typed_reports_failing_statement() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let id1 = &Account::create_id("one".into());
+    let id2 = &Account::create_id("two".into());
+
+    let result = transfer_transaction(id1, id2)
+        .run_typed::<(Option<Account>, Option<Balance>, Vec<Account>, Vec<Account>)>(db.clone())
+        .await;
+
+    match result {
+        Err(SurrealOrmError::StatementResult {
+            index, statement, ..
+        }) => {
+            assert_eq!(index, 2);
+            assert!(statement.starts_with("CREATE ONLY account CONTENT"));
+        }
+        _ => panic!("Expected the second account creation to fail to deserialize"),
+    }
+
+    let result = transfer_transaction(id1, id2)
+        .run_typed::<(Option<Account>, Option<Account>)>(db.clone())
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(SurrealOrmError::StatementResultsCount {
+            expected: 2,
+            found: 4
+        })
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,201 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+use chrono::Utc;
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_models::{weapon, Weapon};
+use surreal_orm::{
+    statements::{select, select_value, upsert},
+    *,
+};
+use surrealdb::{engine::local::Mem, Surreal};
+
+async fn all_weapons(db: Surreal<surrealdb::engine::local::Db>) -> Vec<Weapon> {
+    select(All)
+        .from(Weapon::table())
+        .return_many(db)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_upsert_single_id_content() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let weapon = Weapon {
+        name: "Laser".to_string(),
+        strength: 5.0,
+        created: Utc::now(),
+        ..Default::default()
+    };
+    let statement = upsert::<Weapon>(weapon.id.clone()).content(weapon.clone());
+    assert_eq!(
+        statement.fine_tune_params(),
+        "UPDATE $_param_00000001 CONTENT $_param_00000002;"
+    );
+
+    let created = statement.get_one(db.clone()).await?;
+    assert_eq!(created.id.to_thing(), weapon.id.to_thing());
+    assert_eq!(created.strength, 5.0);
+
+    let updated = upsert::<Weapon>(weapon.id.clone())
+        .content(Weapon {
+            strength: 10.0,
+            ..weapon.clone()
+        })
+        .get_one(db.clone())
+        .await?;
+    assert_eq!(updated.strength, 10.0);
+
+    let weapons = all_weapons(db.clone()).await;
+    assert_eq!(weapons.len(), 1);
+    assert_eq!(weapons[0].strength, 10.0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_single_id_merge() -> SurrealOrmResult<()> {
+    #[derive(Serialize, Deserialize)]
+    struct WeaponStrength {
+        strength: f64,
+    }
+
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let weapon = Weapon {
+        name: "Laser".to_string(),
+        strength: 5.0,
+        created: Utc::now(),
+        ..Default::default()
+    };
+    weapon.clone().save_or_create().run(db.clone()).await?;
+
+    let merged = upsert::<Weapon>(weapon.id.clone())
+        .merge(WeaponStrength { strength: 20.0 })
+        .get_one(db.clone())
+        .await?;
+
+    assert_eq!(merged.name, "Laser");
+    assert_eq!(merged.strength, 20.0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_table_with_filter_creates_then_updates() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let weapon::Schema { name, strength, .. } = &Weapon::schema();
+
+    let statement = || {
+        upsert::<Weapon>(Weapon::table())
+            .set([name.equal_to("Laser"), strength.increment_by(5f64)])
+            .where_(name.equal("Laser"))
+            .return_type(ReturnType::After)
+    };
+    assert_eq!(
+        statement().fine_tune_params(),
+        "IF (SELECT VALUE id FROM weapon WHERE name = $_param_00000001 LIMIT 1) \
+        THEN (UPDATE weapon SET name = $_param_00000002, strength += $_param_00000003 \
+        WHERE name = $_param_00000004 RETURN AFTER) \
+        ELSE (CREATE weapon SET name = $_param_00000005, strength += $_param_00000006 RETURN AFTER) END;"
+    );
+
+    let strengths = select_value(strength).from(Weapon::table());
+
+    statement().run(db.clone()).await?;
+    assert_eq!(strengths.return_many::<f64>(db.clone()).await?, vec![5.0]);
+
+    statement().run(db.clone()).await?;
+    assert_eq!(strengths.return_many::<f64>(db.clone()).await?, vec![10.0]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_table_merge_creates_with_content() -> SurrealOrmResult<()> {
+    #[derive(Serialize, Deserialize)]
+    struct WeaponPartial {
+        name: String,
+        strength: f64,
+    }
+
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let weapon::Schema { name, .. } = &Weapon::schema();
+
+    let statement = || {
+        upsert::<Weapon>(Weapon::table())
+            .merge(WeaponPartial {
+                name: "Sword".to_string(),
+                strength: 3.0,
+            })
+            .where_(name.equal("Sword"))
+    };
+    assert_eq!(
+        statement().fine_tune_params(),
+        "IF (SELECT VALUE id FROM weapon WHERE name = $_param_00000001 LIMIT 1) \
+        THEN (UPDATE weapon MERGE $_param_00000002 WHERE name = $_param_00000003) \
+        ELSE (CREATE weapon CONTENT $_param_00000004) END;"
+    );
+
+    statement().run(db.clone()).await?;
+    statement().run(db.clone()).await?;
+
+    let names = select_value(name)
+        .from(Weapon::table())
+        .return_many::<String>(db.clone())
+        .await?;
+    assert_eq!(names, vec!["Sword".to_string()]);
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_upsert_table_patch_errors() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let weapon::Schema { name, .. } = &Weapon::schema();
+
+    let result = upsert::<Weapon>(Weapon::table())
+        .patch(name.patch_replace("Laser"))
+        .run(db.clone())
+        .await;
+
+    assert!(matches!(result, Err(SurrealOrmError::QueryBuilder(_))));
+    Ok(())
+}
+
+#[test]
+fn test_upsert_table_of_another_model_errors() {
+    let statement = upsert::<Weapon>(Table::new("space_ship")).content(Weapon::default());
+
+    assert_eq!(
+        statement.get_errors(),
+        vec!["table name -space_ship does not match the surreal model struct type which belongs to weapon table".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn test_save_or_create_is_idempotent() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+
+    let weapon = Weapon {
+        name: "Laser".to_string(),
+        strength: 5.0,
+        created: Utc::now(),
+        ..Default::default()
+    };
+
+    let first = weapon.clone().save_or_create().get_one(db.clone()).await?;
+    let second = weapon.clone().save_or_create().get_one(db.clone()).await?;
+
+    assert_eq!(first.id.to_thing(), second.id.to_thing());
+    assert_eq!(first.name, second.name);
+    assert_eq!(all_weapons(db.clone()).await.len(), 1);
+    Ok(())
+}
@@ -0,0 +1,172 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{statements::update, *};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    sql, Surreal,
+};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = document)]
+struct Document {
+    id: SurrealSimpleId<Self>,
+    title: String,
+    #[orm(created_at)]
+    created_at: Option<sql::Datetime>,
+    #[orm(updated_at)]
+    updated_at: Option<sql::Datetime>,
+    #[orm(version)]
+    version: u64,
+}
+
+async fn setup() -> Surreal<Db> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    for definition in Document::define_fields() {
+        definition.run(db.clone()).await.unwrap();
+    }
+    db
+}
+
+fn new_document(title: &str) -> Document {
+    Document {
+        id: Document::create_simple_id(),
+        title: title.to_string(),
+        created_at: None,
+        updated_at: None,
+        version: 0,
+    }
+}
+
+#[test]
+fn test_managed_fields_definitions() {
+    let definitions = Document::define_fields()
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        definitions[2],
+        "DEFINE FIELD createdAt ON TABLE document TYPE option<datetime> VALUE $before OR time::now();"
+    );
+    assert_eq!(
+        definitions[3],
+        "DEFINE FIELD updatedAt ON TABLE document TYPE option<datetime> VALUE time::now();"
+    );
+    assert_eq!(
+        definitions[4],
+        "DEFINE FIELD version ON TABLE document TYPE int VALUE ($before OR 0) + 1;"
+    );
+    assert_eq!(Document::version_field().unwrap().build(), "version");
+}
+
+#[tokio::test]
+async fn test_save_sets_timestamps_and_increments_version() -> SurrealOrmResult<()> {
+    let db = setup().await;
+
+    let saved = new_document("Draft").save().get_one(db.clone()).await?;
+    assert_eq!(saved.version, 1);
+    let created_at = saved.created_at.clone().expect("created_at should be set");
+    let updated_at = saved.updated_at.clone().expect("updated_at should be set");
+
+    let resaved = Document {
+        title: "Final".to_string(),
+        created_at: None,
+        ..saved
+    }
+    .save()
+    .get_one(db.clone())
+    .await?;
+
+    assert_eq!(resaved.title, "Final");
+    assert_eq!(resaved.version, 2);
+    assert_eq!(resaved.created_at, Some(created_at));
+    assert!(resaved.updated_at.unwrap() > updated_at);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_saving_stale_record_fails() -> SurrealOrmResult<()> {
+    let db = setup().await;
+    let saved = new_document("Draft").save().get_one(db.clone()).await?;
+    let mut first_reader = saved.clone();
+    let mut second_reader = saved.clone();
+
+    first_reader.title = "First".to_string();
+    first_reader.save().return_none(db.clone()).await?;
+
+    second_reader.title = "Second".to_string();
+    let result = second_reader.clone().save().get_one(db.clone()).await;
+    match result {
+        Err(SurrealOrmError::StaleWrite(detail)) => {
+            assert!(detail.contains(&saved.id.to_string()));
+            assert!(detail.contains("version 1"));
+        }
+        _ => panic!("Expected a stale write but got {result:?}"),
+    }
+
+    let result = second_reader.save().return_none(db.clone()).await;
+    assert!(matches!(result, Err(SurrealOrmError::StaleWrite(_))));
+
+    let stored = Document::find_by_id(saved.id.clone())
+        .return_one(db.clone())
+        .await?
+        .expect("The document should exist");
+    assert_eq!(stored.title, "First");
+    assert_eq!(stored.version, 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_content_guards_version() -> SurrealOrmResult<()> {
+    let db = setup().await;
+    let document::Schema { title, .. } = Document::schema();
+    let saved = new_document("Draft").save().get_one(db.clone()).await?;
+
+    let statement = update::<Document>(saved.id.clone())
+        .content(saved.clone())
+        .where_(title.eq("Draft"));
+    assert_eq!(
+        statement.fine_tune_params(),
+        "UPDATE $_param_00000001 CONTENT  $_param_00000002 WHERE title = $_param_00000003 \
+            AND (IF version IS NONE OR version = $_param_00000004 THEN true \
+            ELSE { THROW $_param_00000005 } END);"
+    );
+
+    // A record filtered out is neither written nor reported as stale
+    let updated = update::<Document>(saved.id.clone())
+        .content(Document {
+            title: "Final".to_string(),
+            ..saved.clone()
+        })
+        .where_(title.eq("Published"))
+        .return_many(db.clone())
+        .await?;
+    assert!(updated.is_empty());
+
+    let updated = update::<Document>(saved.id.clone())
+        .content(Document {
+            title: "Final".to_string(),
+            ..saved.clone()
+        })
+        .get_one(db.clone())
+        .await?;
+    assert_eq!(updated.version, 2);
+
+    let result = update::<Document>(saved.id.clone())
+        .content(saved)
+        .return_one(db.clone())
+        .await;
+    assert!(matches!(result, Err(SurrealOrmError::StaleWrite(_))));
+
+    Ok(())
+}
@@ -0,0 +1,203 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use chrono::{DateTime, Utc};
+use pretty_assertions::assert_eq;
+use serde::{Deserialize, Serialize};
+use surreal_orm::{
+    statements::{create, select},
+    *,
+};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    Surreal,
+};
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+#[orm(table = invoice, soft_delete = "deletedAt")]
+struct Invoice {
+    id: SurrealSimpleId<Self>,
+    number: u32,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Node, Serialize, Deserialize, Debug, Clone)]
+#[orm(table = receipt)]
+struct Receipt {
+    id: SurrealSimpleId<Self>,
+    number: u32,
+}
+
+async fn create_invoices(db: Surreal<Db>) -> SurrealOrmResult<Vec<Invoice>> {
+    let mut invoices = vec![];
+    for number in 1..=4 {
+        let invoice = create()
+            .content(Invoice {
+                id: Invoice::create_simple_id(),
+                number,
+                deleted_at: None,
+            })
+            .get_one(db.clone())
+            .await?;
+        invoices.push(invoice);
+    }
+    Ok(invoices)
+}
+
+fn numbers(invoices: &[Invoice]) -> Vec<u32> {
+    let mut numbers = invoices.iter().map(|i| i.number).collect::<Vec<_>>();
+    numbers.sort();
+    numbers
+}
+
+#[tokio::test]
+async fn test_soft_deleted_records_are_kept_and_excluded() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let invoices = create_invoices(db.clone()).await?;
+    let invoice::Schema { number, .. } = Invoice::schema();
+
+    invoices[0].delete().run(db.clone()).await?;
+    Invoice::delete_by_id(invoices[1].id.clone())
+        .run(db.clone())
+        .await?;
+
+    let remaining = Invoice::find_where(number.gt(0))
+        .return_many(db.clone())
+        .await?;
+    assert_eq!(numbers(&remaining), vec![3, 4]);
+    assert_eq!(Invoice::count_all().get(db.clone()).await?, 2);
+    assert_eq!(Invoice::count_where(number.gt(1)).get(db.clone()).await?, 2);
+    assert!(Invoice::find_by_id(invoices[0].id.clone())
+        .return_one(db.clone())
+        .await?
+        .is_none());
+
+    // The records are still in the table
+    let all = select(All)
+        .from(Invoice::table())
+        .return_many::<Invoice>(db.clone())
+        .await?;
+    assert_eq!(all.len(), 4);
+
+    let deleted = Invoice::find_where(number.gt(0))
+        .only_deleted()
+        .return_many(db.clone())
+        .await?;
+    assert_eq!(numbers(&deleted), vec![1, 2]);
+    assert!(deleted.iter().all(|invoice| invoice.deleted_at.is_some()));
+
+    let with_deleted = Invoice::find_where(number.gt(0))
+        .with_deleted()
+        .return_many(db.clone())
+        .await?;
+    assert_eq!(numbers(&with_deleted), vec![1, 2, 3, 4]);
+
+    let found = Invoice::find_by_id(invoices[0].id.clone())
+        .with_deleted()
+        .return_one(db.clone())
+        .await?;
+    assert_eq!(found.unwrap().number, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_soft_delete_where_and_restore() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let invoices = create_invoices(db.clone()).await?;
+    let invoice::Schema { number, .. } = Invoice::schema();
+
+    Invoice::delete_where(number.gte(3)).run(db.clone()).await?;
+    assert_eq!(Invoice::count_all().get(db.clone()).await?, 2);
+
+    let deleted_at = Invoice::find_by_id(invoices[3].id.clone())
+        .only_deleted()
+        .return_one(db.clone())
+        .await?
+        .unwrap()
+        .deleted_at;
+
+    // Deleting again keeps the time it was first deleted
+    invoices[3].delete().run(db.clone()).await?;
+    let deleted_again_at = Invoice::find_by_id(invoices[3].id.clone())
+        .only_deleted()
+        .return_one(db.clone())
+        .await?
+        .unwrap()
+        .deleted_at;
+    assert_eq!(deleted_at, deleted_again_at);
+
+    let restored = invoices[3].restore().return_one(db.clone()).await?.unwrap();
+    assert!(restored.deleted_at.is_none());
+    assert_eq!(Invoice::count_all().get(db.clone()).await?, 3);
+
+    let remaining = Invoice::find_where(number.gt(0))
+        .return_many(db.clone())
+        .await?;
+    assert_eq!(numbers(&remaining), vec![1, 2, 4]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_models_without_soft_delete_are_deleted() -> SurrealOrmResult<()> {
+    let db = Surreal::new::<Mem>(()).await.unwrap();
+    db.use_ns("test").use_db("test").await.unwrap();
+    let receipt = create()
+        .content(Receipt {
+            id: Receipt::create_simple_id(),
+            number: 1,
+        })
+        .get_one(db.clone())
+        .await?;
+
+    assert!(receipt.delete().to_raw().build().starts_with("DELETE"));
+    assert!(matches!(
+        receipt.restore().run(db.clone()).await,
+        Err(SurrealOrmError::QueryBuilder(_))
+    ));
+    assert!(matches!(
+        Receipt::find_by_id(receipt.id.clone())
+            .only_deleted()
+            .run(db.clone())
+            .await,
+        Err(SurrealOrmError::QueryBuilder(_))
+    ));
+
+    receipt.delete().run(db.clone()).await?;
+    assert_eq!(Receipt::count_all().get(db.clone()).await?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn test_soft_delete_statements() {
+    let invoice::Schema { number, .. } = Invoice::schema();
+    let invoice_id = Invoice::create_id("one".to_string());
+
+    assert_eq!(
+        Invoice::delete_by_id(invoice_id.clone()).to_raw().build(),
+        "UPDATE invoice:one SET deletedAt = time::now() WHERE deletedAt IS NONE RETURN NONE ;"
+    );
+    assert_eq!(
+        Invoice::find_where(number.gt(2)).to_raw().build(),
+        "SELECT * FROM invoice WHERE (number > 2) AND (deletedAt IS NONE);"
+    );
+    assert_eq!(
+        Invoice::find_by_id(invoice_id.clone())
+            .only_deleted()
+            .to_raw()
+            .build(),
+        "SELECT * FROM invoice:one WHERE deletedAt IS NOT NONE;"
+    );
+    assert_eq!(
+        Invoice::restore_by_id(invoice_id).to_raw().build(),
+        "UPDATE invoice:one SET deletedAt = NONE;"
+    );
+}
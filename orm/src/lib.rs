@@ -199,6 +199,8 @@ pub use surreal_derive::Node;
 #[doc = include_str!("docs/object_field_attributes.md")]
 pub use surreal_derive::Object;
 
+pub use surreal_derive::Aggregate;
+
 pub use surreal_derive::pick;
 pub use surreal_derive::Pickable;
 
@@ -49,8 +49,13 @@ pub fn query_raw(raw_input: TokenStream) -> TokenStream {
 /// It also allows interpolations of variables and can
 /// be run.
 ///
+/// Every placeholder in the queries must have a binding and every binding must be
+/// used by a placeholder, except for predefined params e.g `$auth` and params
+/// declared within the queries with `LET` or `FOR`.
+///
 /// # Arguments
 /// * `db` - The database connection to be used.
+/// * `models` - Optional list of models to check the tables and fields of the queries against.
 /// * `quer(ies)` - A single or list of queries to be checked at compile time.
 /// * `params` - The parameters to be used for the query.
 ///
@@ -98,6 +103,28 @@ pub fn query_raw(raw_input: TokenStream) -> TokenStream {
 /// )
 /// .await;
 /// ```
+///
+/// When models are provided, the tables and fields referenced in the queries must
+/// exist on the models. Fields are only checked for statements on a single table.
+/// ```rust, ignore
+/// # use query_builder_macros as surreal_orm;
+/// use surreal_orm::{query};
+/// use surrealdb::{engine::local::Mem, Surreal};
+///
+/// let db = Surreal::new::<Mem>(()).await.unwrap();
+/// db.use_ns("test").use_db("test").await.unwrap();
+///
+/// let _query = query!(
+///     db,
+///     models = [Weapon],
+///     "SELECT name, strength FROM weapon WHERE strength > $min",
+///     { min: 5 }
+/// )
+/// .await;
+///
+/// // Fails to compile as `strenght` is not a field of `Weapon`
+/// let _query = query!(db, models = [Weapon], "SELECT strenght FROM weapon").await;
+/// ```
 #[proc_macro]
 pub fn query(raw_input: TokenStream) -> TokenStream {
     query::query(raw_input.into()).into()
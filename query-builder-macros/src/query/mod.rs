@@ -5,13 +5,24 @@
  */
 
 use proc_macro2::{Ident, Span, TokenStream, TokenTree};
-use quote::ToTokens;
+use proc_macros_helpers::get_crate_name;
+use quote::{quote, quote_spanned, ToTokens};
 use surreal_query_builder::sql;
 use syn::parse::{Parse, ParseStream};
 use syn::parse2;
 use syn::punctuated::Punctuated;
 use syn::token::{Brace, Comma};
-use syn::{Expr, LitStr, Result, Token};
+use syn::{Expr, LitStr, Path, Result, Token};
+
+mod schema_check;
+
+use schema_check::{SchemaReference, SchemaReferences};
+
+/// Parameters predefined by SurrealDB which do not need a binding
+const PREDEFINED_PARAMS: [&str; 11] = [
+    "auth", "token", "scope", "session", "before", "after", "value", "input", "parent", "event",
+    "this",
+];
 
 struct Binding {
     key: Ident,
@@ -55,9 +66,34 @@ impl Parse for Queries {
     }
 }
 
-fn parse_args(input: TokenStream) -> Result<(Expr, Vec<String>, Vec<Binding>)> {
+/// The models the tables and fields of the queries are checked against
+/// e.g `models = [User, Weapon]`
+struct Models {
+    paths: Vec<Path>,
+}
+
+impl Parse for Models {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        let _bracket_token: syn::token::Bracket = syn::bracketed!(content in input);
+        let paths = Punctuated::<Path, Comma>::parse_terminated(&content)?
+            .into_iter()
+            .collect();
+        Ok(Models { paths })
+    }
+}
+
+struct QueryArgs {
+    db_connection: Expr,
+    models: Vec<Path>,
+    queries: Vec<LitStr>,
+    parsed_queries: Vec<sql::Query>,
+    bindings: Vec<Binding>,
+}
+
+fn parse_args(input: TokenStream) -> Result<QueryArgs> {
     let input2: TokenStream = input.clone();
-    let mut iter = input2.into_iter();
+    let mut iter = input2.into_iter().peekable();
 
     let database_connection = iter
         .next()
@@ -74,17 +110,52 @@ fn parse_args(input: TokenStream) -> Result<(Expr, Vec<String>, Vec<Binding>)> {
         }
     }
 
+    let mut models = vec![];
+    if matches!(iter.peek(), Some(TokenTree::Ident(ident)) if ident == "models") {
+        let models_ident = iter.next();
+
+        match iter.next() {
+            Some(TokenTree::Punct(ref punct)) if punct.as_char() == '=' => {}
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    models_ident,
+                    "Expected `=` after models e.g models = [User, Weapon]",
+                ))
+            }
+        }
+
+        models = match iter.next() {
+            Some(TokenTree::Group(group))
+                if group.delimiter() == proc_macro2::Delimiter::Bracket =>
+            {
+                parse2::<Models>(group.into_token_stream())?.paths
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    models_ident,
+                    "Expected a list of models e.g models = [User, Weapon]",
+                ))
+            }
+        };
+
+        match iter.next() {
+            Some(TokenTree::Punct(ref punct)) if punct.as_char() == ',' => {}
+            _ => {
+                return Err(syn::Error::new(
+                    Span::call_site(),
+                    "Expected a comma after the models",
+                ))
+            }
+        }
+    }
+
     let queries = match iter.next() {
         Some(proc_macro2::TokenTree::Literal(lit)) => {
-            vec![syn::parse2::<LitStr>(lit.to_token_stream())?.value()]
+            vec![syn::parse2::<LitStr>(lit.to_token_stream())?]
         }
         Some(TokenTree::Group(group)) if group.delimiter() == proc_macro2::Delimiter::Bracket => {
             let queries_tokens = group.into_token_stream();
-            parse2::<Queries>(queries_tokens)?
-                .query_strings
-                .into_iter()
-                .map(|lit_str| lit_str.value())
-                .collect()
+            parse2::<Queries>(queries_tokens)?.query_strings
         }
         _ => {
             return Err(syn::Error::new(
@@ -94,17 +165,18 @@ fn parse_args(input: TokenStream) -> Result<(Expr, Vec<String>, Vec<Binding>)> {
         }
     };
 
+    let mut parsed_queries = vec![];
     for query in &queries {
-        let sql = sql::parse(query.trim());
+        let sql = sql::parse(query.value().trim());
         match sql {
-            Ok(value) => value,
+            Ok(value) => parsed_queries.push(value),
             Err(value) => {
-                return Err(syn::Error::new_spanned(input.clone(), value));
+                return Err(syn::Error::new(query.span(), value));
             }
         };
     }
 
-    let has_placeholders = queries.join(";").contains('$');
+    let has_placeholders = queries.iter().any(|query| query.value().contains('$'));
     let mut bindings = Vec::new();
 
     if has_placeholders {
@@ -174,15 +246,59 @@ fn parse_args(input: TokenStream) -> Result<(Expr, Vec<String>, Vec<Binding>)> {
             }
         }
     }
-    Ok((database_connection, queries, bindings))
+    Ok(QueryArgs {
+        db_connection: database_connection,
+        models,
+        queries,
+        parsed_queries,
+        bindings,
+    })
+}
+
+/// Returns the names of the `$params` used in a query and whether each is declared
+/// within the query itself e.g with `LET $name = ...` or `FOR $name IN ...`.
+fn query_params(query: &str) -> Vec<(&str, bool)> {
+    query
+        .match_indices('$')
+        .map(|(start, _)| {
+            let end = query[start + 1..]
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .map_or(query.len(), |i| start + i + 1);
+            let preceding_keyword = query[..start].split_whitespace().next_back();
+            let is_declared = preceding_keyword.is_some_and(|keyword| {
+                keyword.eq_ignore_ascii_case("LET") || keyword.eq_ignore_ascii_case("FOR")
+            });
+
+            (&query[start + 1..end], is_declared)
+        })
+        .collect()
 }
 
-fn validate_and_parse_sql_query(query: &str, bindings: &[Binding]) -> syn::Result<String> {
+fn validate_params(queries: &[LitStr], bindings: &[Binding]) -> syn::Result<()> {
+    let queries_values = queries.iter().map(LitStr::value).collect::<Vec<_>>();
+    let params = queries_values
+        .iter()
+        .map(|query| query_params(query))
+        .collect::<Vec<_>>();
+
+    let declared_params = params
+        .iter()
+        .flatten()
+        .filter(|(_, is_declared)| *is_declared)
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>();
+
+    let mut errors: Option<syn::Error> = None;
+    let mut push_error = |error: syn::Error| match errors.as_mut() {
+        Some(errors) => errors.combine(error),
+        None => errors = Some(error),
+    };
+
     for binding in bindings {
-        let placeholder = format!("${}", binding.key);
-        if !query.contains(&placeholder) {
-            return Err(syn::Error::new(
-                Span::call_site(),
+        let is_used = params.iter().flatten().any(|(name, _)| binding.key == name);
+        if !is_used {
+            push_error(syn::Error::new(
+                binding.key.span(),
                 format!(
                     "No placeholder found in SQL query for binding '{}'",
                     binding.key
@@ -191,34 +307,110 @@ fn validate_and_parse_sql_query(query: &str, bindings: &[Binding]) -> syn::Resul
         }
     }
 
-    let placeholders = query.match_indices('$').peekable();
+    for (query, query_params) in queries.iter().zip(params.iter()) {
+        for (name, _) in query_params {
+            let is_bound = bindings.iter().any(|b| b.key == name)
+                || declared_params.contains(name)
+                || PREDEFINED_PARAMS.contains(name);
 
-    for (start, _) in placeholders {
-        let end = query[start + 1..]
-            .find(|c: char| !c.is_alphanumeric() && c != '_')
-            .map_or(query.len(), |i| start + i + 1);
+            if !is_bound {
+                push_error(syn::Error::new(
+                    query.span(),
+                    format!("No binding found for placeholder '${}'", name),
+                ));
+            }
+        }
+    }
 
-        let placeholder_name = &query[start + 1..end];
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
 
-        if !bindings.iter().any(|b| b.key == placeholder_name) {
-            return Err(syn::Error::new(
-                Span::call_site(),
-                format!("No binding found for placeholder '${}'", placeholder_name),
-            ));
-        }
+/// Generates compile time assertions that the tables and fields referenced in
+/// the queries exist on the models.
+fn schema_assertions(models: &[Path], queries: &[LitStr], parsed: &[sql::Query]) -> TokenStream {
+    if models.is_empty() {
+        return quote!();
     }
 
-    Ok(query.to_owned())
+    let crate_name = get_crate_name(false);
+    let models_names = models
+        .iter()
+        .map(|model| model.to_token_stream().to_string().replace(' ', ""))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let assertions = queries.iter().zip(parsed).flat_map(|(query, parsed)| {
+        let span = query.span();
+        let models_names = &models_names;
+        let crate_name = &crate_name;
+        SchemaReferences::from_query(parsed)
+            .into_inner()
+            .into_iter()
+            .map(move |reference| match reference {
+                SchemaReference::Table(table) => {
+                    let message = format!(
+                        "Table `{table}` is not the table of any of the models: {models_names}"
+                    );
+                    quote_spanned!(span=>
+                        const _: () = ::std::assert!(
+                            #crate_name::validators::models_have_table(__QUERY_MODELS, #table),
+                            #message
+                        );
+                    )
+                }
+                SchemaReference::Field { table, field } => {
+                    let message = format!(
+                        "Field `{field}` does not exist on table `{table}` of the models: {models_names}"
+                    );
+                    quote_spanned!(span=>
+                        const _: () = ::std::assert!(
+                            // An unknown table is already reported by its own assertion
+                            !#crate_name::validators::models_have_table(__QUERY_MODELS, #table)
+                                || #crate_name::validators::models_have_field(__QUERY_MODELS, #table, #field),
+                            #message
+                        );
+                    )
+                }
+            })
+    });
+
+    quote!(
+        const __QUERY_MODELS: &[(&str, &[&str])] = &[
+            #( (
+                <#models as #crate_name::Model>::TABLE_NAME,
+                <#models as #crate_name::Model>::SERIALIZABLE_FIELD_NAMES,
+            ) ),*
+        ];
+        #( #assertions )*
+    )
 }
 
 pub fn query(args: TokenStream) -> TokenStream {
-    let (db_con, query_strs, bindings) = parse_args(args).expect("Failed to parse arguments");
-    let mut output = TokenStream::new();
+    let QueryArgs {
+        db_connection: db_con,
+        models,
+        queries,
+        parsed_queries,
+        bindings,
+    } = match parse_args(args) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error(),
+    };
 
-    let _sql_queries = validate_and_parse_sql_query(&query_strs.join(";"), &bindings)
-        .expect("Failed to validate SQL query");
+    if let Err(err) = validate_params(&queries, &bindings) {
+        // Several errors expand to several `compile_error!` calls which are only
+        // valid in expression position within a block
+        let errors = err.to_compile_error();
+        return quote!({ #errors });
+    }
+
+    let schema_assertions = schema_assertions(&models, &queries, &parsed_queries);
+    let mut output = TokenStream::new();
 
-    for (i, query_str) in query_strs.iter().enumerate() {
+    for (i, query_str) in queries.iter().map(LitStr::value).enumerate() {
         let is_first = i == 0;
         if is_first {
             output.extend(quote::quote! {
@@ -238,13 +430,15 @@ pub fn query(args: TokenStream) -> TokenStream {
         });
     }
 
-    output
+    quote!({
+        #schema_assertions
+        #output
+    })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use quote::quote;
     use syn::parse_quote;
 
     #[test]
@@ -257,7 +451,13 @@ mod tests {
         let expected_query = "SELECT * FROM users".to_string();
         let expected_bindings: Vec<Binding> = vec![];
 
-        let (db_conn, query, bindings) = parse_args(input).unwrap();
+        let QueryArgs {
+            db_connection: db_conn,
+            queries,
+            bindings,
+            ..
+        } = parse_args(input).unwrap();
+        let query = queries.iter().map(LitStr::value).collect::<Vec<_>>();
 
         assert_eq!(
             db_conn.to_token_stream().to_string(),
@@ -282,7 +482,13 @@ mod tests {
             value: parse_quote! { 1 },
         }];
 
-        let (db_conn, query, bindings) = parse_args(input).unwrap();
+        let QueryArgs {
+            db_connection: db_conn,
+            queries,
+            bindings,
+            ..
+        } = parse_args(input).unwrap();
+        let query = queries.iter().map(LitStr::value).collect::<Vec<_>>();
 
         assert_eq!(
             db_conn.to_token_stream().to_string(),
@@ -328,7 +534,13 @@ mod tests {
             value: parse2(quote! { 1 }).unwrap(),
         }];
 
-        let (db_conn, queries, bindings) = parse_args(input).expect("Failed to parse arguments");
+        let QueryArgs {
+            db_connection: db_conn,
+            queries,
+            bindings,
+            ..
+        } = parse_args(input).expect("Failed to parse arguments");
+        let queries = queries.iter().map(LitStr::value).collect::<Vec<_>>();
 
         assert_eq!(
             quote! { #db_conn }.to_string(),
@@ -360,4 +572,84 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_parse_args_with_models() {
+        let input = quote! {
+            my_db, models = [User, crate::Weapon], "SELECT name FROM user"
+        };
+
+        let QueryArgs {
+            models, queries, ..
+        } = parse_args(input).unwrap();
+
+        assert_eq!(
+            models
+                .iter()
+                .map(|model| model.to_token_stream().to_string())
+                .collect::<Vec<_>>(),
+            vec!["User", "crate :: Weapon"]
+        );
+        assert_eq!(queries[0].value(), "SELECT name FROM user");
+    }
+
+    #[test]
+    fn test_parse_args_with_invalid_models() {
+        let input = quote! {
+            my_db, models = User, "SELECT name FROM user"
+        };
+
+        assert!(parse_args(input).is_err());
+    }
+
+    #[test]
+    fn test_validate_params() {
+        let queries: Vec<LitStr> = vec![parse_quote!(
+            "LET $adult = 18; SELECT * FROM user WHERE id = $id AND age > $adult AND owner = $auth.id"
+        )];
+        let bindings = vec![Binding {
+            key: parse_quote! { id },
+            value: parse_quote! { 1 },
+        }];
+
+        assert!(validate_params(&queries, &bindings).is_ok());
+    }
+
+    #[test]
+    fn test_validate_params_missing_binding() {
+        let queries: Vec<LitStr> = vec![parse_quote!(
+            "SELECT * FROM user WHERE id = $id AND name = $name"
+        )];
+        let bindings = vec![Binding {
+            key: parse_quote! { id },
+            value: parse_quote! { 1 },
+        }];
+
+        let error = validate_params(&queries, &bindings).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "No binding found for placeholder '$name'"
+        );
+    }
+
+    #[test]
+    fn test_validate_params_unused_binding() {
+        let queries: Vec<LitStr> = vec![parse_quote!("SELECT * FROM user WHERE id = $id")];
+        let bindings = vec![
+            Binding {
+                key: parse_quote! { id },
+                value: parse_quote! { 1 },
+            },
+            Binding {
+                key: parse_quote! { name },
+                value: parse_quote! { "Oyelowo" },
+            },
+        ];
+
+        let error = validate_params(&queries, &bindings).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "No placeholder found in SQL query for binding 'name'"
+        );
+    }
 }
@@ -0,0 +1,324 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::collections::BTreeSet;
+
+use surreal_query_builder::sql::{
+    self,
+    statements::{CreateStatement, DeleteStatement, SelectStatement, UpdateStatement},
+    Data, Field, Idiom, Part, Statement, Subquery, Value,
+};
+
+/// A table or a field of a table referenced in a query.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SchemaReference {
+    Table(String),
+    Field { table: String, field: String },
+}
+
+/// Collects the tables and fields referenced in the statements of a query so that
+/// they can be checked against the schemas of the models.
+///
+/// Fields are only collected for statements targeting a single table as that is
+/// the only case where the model a field belongs to is unambiguous.
+#[derive(Debug, Default)]
+pub struct SchemaReferences(BTreeSet<SchemaReference>);
+
+impl SchemaReferences {
+    pub fn from_query(query: &sql::Query) -> Self {
+        let mut references = Self::default();
+        for statement in query.0 .0.iter() {
+            references.visit_statement(statement);
+        }
+        references
+    }
+
+    pub fn into_inner(self) -> BTreeSet<SchemaReference> {
+        self.0
+    }
+
+    fn visit_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Select(select) => self.visit_select(select),
+            Statement::Create(create) => self.visit_create(create),
+            Statement::Update(update) => self.visit_update(update),
+            Statement::Delete(delete) => self.visit_delete(delete),
+            Statement::Value(value) => self.visit_value(None, value),
+            Statement::Set(set) => self.visit_value(None, &set.what),
+            Statement::Output(output) => self.visit_value(None, &output.what),
+            _ => {}
+        }
+    }
+
+    fn visit_subquery(&mut self, subquery: &Subquery) {
+        match subquery {
+            Subquery::Select(select) => self.visit_select(select),
+            Subquery::Create(create) => self.visit_create(create),
+            Subquery::Update(update) => self.visit_update(update),
+            Subquery::Delete(delete) => self.visit_delete(delete),
+            Subquery::Value(value) => self.visit_value(None, value),
+            _ => {}
+        }
+    }
+
+    fn visit_create(&mut self, create: &CreateStatement) {
+        let table = self.visit_targets(&create.what);
+        self.visit_data(table.as_deref(), create.data.as_ref());
+    }
+
+    fn visit_update(&mut self, update: &UpdateStatement) {
+        let table = self.visit_targets(&update.what);
+        self.visit_data(table.as_deref(), update.data.as_ref());
+        if let Some(cond) = &update.cond {
+            self.visit_value(table.as_deref(), &cond.0);
+        }
+    }
+
+    fn visit_delete(&mut self, delete: &DeleteStatement) {
+        let table = self.visit_targets(&delete.what);
+        if let Some(cond) = &delete.cond {
+            self.visit_value(table.as_deref(), &cond.0);
+        }
+    }
+
+    fn visit_select(&mut self, select: &SelectStatement) {
+        let table = self.visit_targets(&select.what);
+
+        let mut aliases = BTreeSet::new();
+        for field in select.expr.0.iter() {
+            if let Field::Single { expr, alias } = field {
+                self.visit_value(table.as_deref(), expr);
+                if let Some(alias) = alias.as_ref().and_then(root_field) {
+                    aliases.insert(alias);
+                }
+            }
+        }
+
+        if let Some(cond) = &select.cond {
+            self.visit_value(table.as_deref(), &cond.0);
+        }
+
+        let idioms = select
+            .order
+            .iter()
+            .flat_map(|orders| orders.0.iter().map(|order| &order.order))
+            .chain(
+                select
+                    .group
+                    .iter()
+                    .flat_map(|groups| groups.0.iter().map(|group| &group.0)),
+            )
+            .chain(
+                select
+                    .split
+                    .iter()
+                    .flat_map(|splits| splits.0.iter().map(|split| &split.0)),
+            )
+            .chain(select.omit.iter().flat_map(|omit| omit.0.iter()));
+
+        for idiom in idioms {
+            // Ordering, grouping and splitting can be by the alias of a projection
+            if root_field(idiom).is_some_and(|field| aliases.contains(&field)) {
+                continue;
+            }
+            self.visit_idiom(table.as_deref(), idiom);
+        }
+    }
+
+    /// Collects the targeted tables and returns the table if the statement targets
+    /// exactly one table.
+    fn visit_targets(&mut self, what: &sql::Values) -> Option<String> {
+        let mut tables = BTreeSet::new();
+        let mut has_other_targets = false;
+
+        for target in what.0.iter() {
+            match target {
+                Value::Table(table) => {
+                    tables.insert(table.0.clone());
+                }
+                Value::Thing(thing) => {
+                    tables.insert(thing.tb.clone());
+                }
+                Value::Subquery(subquery) => {
+                    has_other_targets = true;
+                    self.visit_subquery(subquery);
+                }
+                _ => has_other_targets = true,
+            }
+        }
+
+        for table in tables.iter() {
+            self.0.insert(SchemaReference::Table(table.clone()));
+        }
+
+        match (tables.len(), has_other_targets) {
+            (1, false) => tables.into_iter().next(),
+            _ => None,
+        }
+    }
+
+    fn visit_data(&mut self, table: Option<&str>, data: Option<&Data>) {
+        match data {
+            Some(Data::SetExpression(sets)) | Some(Data::UpdateExpression(sets)) => {
+                for (idiom, _, value) in sets {
+                    self.visit_idiom(table, idiom);
+                    self.visit_value(table, value);
+                }
+            }
+            Some(Data::UnsetExpression(idioms)) => {
+                for idiom in idioms {
+                    self.visit_idiom(table, idiom);
+                }
+            }
+            Some(Data::ContentExpression(Value::Object(object)))
+            | Some(Data::MergeExpression(Value::Object(object)))
+            | Some(Data::ReplaceExpression(Value::Object(object))) => {
+                for (field, value) in object.0.iter() {
+                    self.insert_field(table, field);
+                    self.visit_value(table, value);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_value(&mut self, table: Option<&str>, value: &Value) {
+        match value {
+            Value::Idiom(idiom) => self.visit_idiom(table, idiom),
+            Value::Array(array) => array.0.iter().for_each(|v| self.visit_value(table, v)),
+            Value::Object(object) => object.0.values().for_each(|v| self.visit_value(table, v)),
+            Value::Expression(expression) => match expression.as_ref() {
+                sql::Expression::Unary { v, .. } => self.visit_value(table, v),
+                sql::Expression::Binary { l, r, .. } => {
+                    self.visit_value(table, l);
+                    self.visit_value(table, r);
+                }
+            },
+            Value::Function(function) => match function.as_ref() {
+                sql::Function::Normal(_, args)
+                | sql::Function::Custom(_, args)
+                | sql::Function::Script(_, args) => {
+                    args.iter().for_each(|v| self.visit_value(table, v))
+                }
+            },
+            Value::Subquery(subquery) => self.visit_subquery(subquery),
+            _ => {}
+        }
+    }
+
+    fn visit_idiom(&mut self, table: Option<&str>, idiom: &Idiom) {
+        if let Some(field) = root_field(idiom) {
+            self.insert_field(table, &field);
+        }
+    }
+
+    fn insert_field(&mut self, table: Option<&str>, field: &str) {
+        if let Some(table) = table {
+            self.0.insert(SchemaReference::Field {
+                table: table.to_string(),
+                field: field.to_string(),
+            });
+        }
+    }
+}
+
+/// The top level field an idiom starts with e.g `address` in `address.city`.
+/// Graph traversals, params and other value starting idioms have none.
+fn root_field(idiom: &Idiom) -> Option<String> {
+    match idiom.0.first() {
+        Some(Part::Field(ident)) => Some(ident.0.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn references(query: &str) -> Vec<SchemaReference> {
+        let query = sql::parse(query).unwrap();
+        SchemaReferences::from_query(&query)
+            .into_inner()
+            .into_iter()
+            .collect()
+    }
+
+    fn table(name: &str) -> SchemaReference {
+        SchemaReference::Table(name.to_string())
+    }
+
+    fn field(table: &str, field: &str) -> SchemaReference {
+        SchemaReference::Field {
+            table: table.to_string(),
+            field: field.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_select_references() {
+        assert_eq!(
+            references(
+                "SELECT name, count() AS total, address.city FROM user \
+                WHERE age > 18 AND string::len(nick) > 3 GROUP BY name, address.city, total ORDER BY total"
+            ),
+            vec![
+                table("user"),
+                field("user", "address"),
+                field("user", "age"),
+                field("user", "name"),
+                field("user", "nick"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_multiple_tables_only_reference_tables() {
+        assert_eq!(
+            references("SELECT name FROM user, weapon"),
+            vec![table("user"), table("weapon")]
+        );
+    }
+
+    #[test]
+    fn test_mutation_references() {
+        assert_eq!(
+            references(
+                "CREATE weapon:laser SET strength = 5; \
+                UPDATE user CONTENT { name: 'Oyelowo' } ; \
+                DELETE planet WHERE population < $min"
+            ),
+            vec![
+                table("planet"),
+                table("user"),
+                table("weapon"),
+                field("planet", "population"),
+                field("user", "name"),
+                field("weapon", "strength"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_subquery_references_use_their_own_table() {
+        assert_eq!(
+            references("SELECT name, (SELECT strength FROM weapon) AS weapons FROM user"),
+            vec![
+                table("user"),
+                table("weapon"),
+                field("user", "name"),
+                field("weapon", "strength"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_graph_and_param_idioms_are_skipped() {
+        assert_eq!(
+            references("SELECT ->writes->book AS books, $auth.id FROM user"),
+            vec![table("user")]
+        );
+    }
+}
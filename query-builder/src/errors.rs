@@ -59,6 +59,201 @@ pub enum SurrealOrmError {
 
     #[error("Invalid subquery. {0}")]
     InvalidSubquery(String),
+
+    #[error("Unable to authenticate. Check that the scope is defined and the credentials are correct. {0}")]
+    Authentication(#[source] surrealdb::Error),
+
+    #[error("Unable to get the result of statement {index}: `{statement}`. {source}")]
+    StatementResult {
+        index: usize,
+        statement: String,
+        #[source]
+        source: surrealdb::Error,
+    },
+
+    #[error(
+        "The link `{field}` references records that do not exist: {}",
+        .ids.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+    )]
+    DanglingLinks {
+        field: String,
+        ids: Vec<surrealdb::sql::Thing>,
+    },
+
+    #[error("Expected {expected} statement results, but the query has {found} statements returning results. Note that LET, BEGIN, COMMIT and CANCEL statements are skipped.")]
+    StatementResultsCount { expected: usize, found: usize },
+
+    #[error("Stale write. {0}. Fetch the record again and retry the change.")]
+    StaleWrite(String),
+
+    #[error("The unique index `{index}` on table `{table}` already contains {value}.")]
+    UniqueViolation {
+        index: String,
+        table: String,
+        value: String,
+    },
+
+    #[error("Found {value} for field `{field}`, but the field assertion does not allow it.")]
+    AssertionFailed { field: String, value: String },
+
+    #[error("Permission denied. {0}")]
+    PermissionDenied(#[source] surrealdb::Error),
+
+    #[error("The transaction conflicted with another transaction. It can be retried. {0}")]
+    TransactionConflict(#[source] surrealdb::Error),
+
+    #[error(
+        "The value of field `{field}` does not match its defined type. Expected a {expected}."
+    )]
+    SchemaTypeMismatch { field: String, expected: String },
+
+    #[error("The query was rejected by a query interceptor. {0}")]
+    QueryRejected(String),
+
+    #[error("Received a live query notification with the unsupported action `{0}`.")]
+    UnsupportedLiveAction(String),
+
+    #[cfg(feature = "mock")]
+    #[error("Problem with the snapshot of the mock database. {0}")]
+    MockSnapshot(String),
+}
+
+/// Prefix of the error thrown by the version guard of an update when the record
+/// was modified since it was read.
+pub(crate) const STALE_WRITE: &str = "surreal_orm::stale_write";
+
+impl SurrealOrmError {
+    /// The class of the error e.g `unique_violation`, recorded on the span of a failed query.
+    pub fn class(&self) -> &'static str {
+        match self {
+            Self::QueryBuilder(_) => "query_builder",
+            Self::TooManyItemsReturned(_) => "too_many_items_returned",
+            Self::QueryRun(_) => "query_run",
+            Self::Deserialization(_) => "deserialization",
+            Self::InvalidId(_) => "invalid_id",
+            Self::IdBelongsToAnotherTable(..) => "id_belongs_to_another_table",
+            Self::FieldsUnfetchableNotARecordLink(_) => "fields_unfetchable_not_a_record_link",
+            Self::RecordNotFound(_) => "record_not_found",
+            Self::InvalidSubquery(_) => "invalid_subquery",
+            Self::Authentication(_) => "authentication",
+            Self::StatementResult { .. } => "statement_result",
+            Self::DanglingLinks { .. } => "dangling_links",
+            Self::StatementResultsCount { .. } => "statement_results_count",
+            Self::StaleWrite(_) => "stale_write",
+            Self::UniqueViolation { .. } => "unique_violation",
+            Self::AssertionFailed { .. } => "assertion_failed",
+            Self::PermissionDenied(_) => "permission_denied",
+            Self::TransactionConflict(_) => "transaction_conflict",
+            Self::SchemaTypeMismatch { .. } => "schema_type_mismatch",
+            Self::QueryRejected(_) => "query_rejected",
+            Self::UnsupportedLiveAction(_) => "unsupported_live_action",
+            #[cfg(feature = "mock")]
+            Self::MockSnapshot(_) => "mock_snapshot",
+        }
+    }
+
+    /// Maps the error of a statement result, recognising the stale write thrown
+    /// by the version guard of an update and the database errors callers commonly handle.
+    pub(crate) fn from_statement_error(
+        error: surrealdb::Error,
+        fallback: impl FnOnce(surrealdb::Error) -> Self,
+    ) -> Self {
+        match error.to_string().split_once(STALE_WRITE) {
+            Some((_, detail)) => Self::StaleWrite(
+                detail
+                    .trim_start_matches(": ")
+                    .trim_end_matches(['"', '\''])
+                    .to_string(),
+            ),
+            None => Self::from_db_error(error, fallback),
+        }
+    }
+
+    fn from_db_error(
+        error: surrealdb::Error,
+        fallback: impl FnOnce(surrealdb::Error) -> Self,
+    ) -> Self {
+        use surrealdb::error::Db;
+
+        let surrealdb::Error::Db(db_error) = &error else {
+            return fallback(error);
+        };
+
+        #[allow(deprecated)]
+        match db_error {
+            Db::IndexExists {
+                thing,
+                index,
+                value,
+            } => Self::UniqueViolation {
+                index: index.clone(),
+                table: thing.tb.clone(),
+                value: value.clone(),
+            },
+            Db::FieldValue { field, value, .. } => Self::AssertionFailed {
+                field: field.to_string(),
+                value: value.clone(),
+            },
+            Db::FieldCheck { field, check, .. } => Self::SchemaTypeMismatch {
+                field: field.to_string(),
+                expected: check.clone(),
+            },
+            Db::TablePermissions { .. }
+            | Db::ParamPermissions { .. }
+            | Db::FunctionPermissions { .. }
+            | Db::NsNotAllowed { .. }
+            | Db::DbNotAllowed { .. }
+            | Db::IamError(surrealdb::iam::Error::NotAllowed { .. }) => {
+                Self::PermissionDenied(error)
+            }
+            Db::TxConditionNotMet | Db::TxKeyAlreadyExists | Db::TxKeyAlreadyExistsCategory(_) => {
+                Self::TransactionConflict(error)
+            }
+            // The key-value stores other than the in-memory one report conflicts as messages
+            Db::Tx(message) if is_conflict_message(message) => Self::TransactionConflict(error),
+            _ => fallback(error),
+        }
+    }
+}
+
+fn is_conflict_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("conflict") || message.contains("resource busy")
+}
+
+/// Classifies the errors of statement results taken from the response of `Runnable::run`
+/// e.g `response.take::<Vec<User>>(0).map_err(SurrealOrmError::from)`
+impl From<surrealdb::Error> for SurrealOrmError {
+    fn from(error: surrealdb::Error) -> Self {
+        Self::from_statement_error(error, Self::QueryRun)
+    }
 }
 
 pub type SurrealOrmResult<T> = std::result::Result<T, SurrealOrmError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use surrealdb::error::Db;
+
+    #[test]
+    fn test_transaction_conflicts_are_classified() {
+        let conflicts = [
+            Db::TxConditionNotMet,
+            Db::Tx("Transaction write conflict".to_string()),
+            Db::Tx("Resource busy: ".to_string()),
+        ];
+
+        for conflict in conflicts {
+            let error = SurrealOrmError::from(surrealdb::Error::Db(conflict));
+            assert!(matches!(error, SurrealOrmError::TransactionConflict(_)));
+        }
+    }
+
+    #[test]
+    fn test_unclassified_errors_fall_back() {
+        let error =
+            SurrealOrmError::from(surrealdb::Error::Db(Db::Tx("Key too large".to_string())));
+        assert!(matches!(error, SurrealOrmError::QueryRun(_)));
+    }
+}
@@ -0,0 +1,567 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// DEFINE ACCESS statement
+// Access methods supersede scopes and tokens. A record access method lets records of a table
+// sign up and sign in as users, while a JWT access method verifies tokens issued by a
+// third-party provider.
+//
+// Statement syntax
+// DEFINE ACCESS [ OVERWRITE | IF NOT EXISTS ] @name
+//   ON [ NAMESPACE | DATABASE ]
+//   TYPE [
+//     JWT [ ALGORITHM @algorithm KEY @key | URL @url ]
+//     | RECORD
+//       [ SIGNUP @expression ]
+//       [ SIGNIN @expression ]
+//       [ WITH JWT [ ALGORITHM @algorithm KEY @key | URL @url ] ]
+//   ]
+//   [ AUTHENTICATE @expression ]
+//   [ DURATION [ FOR TOKEN @duration ] [, FOR SESSION @duration ] ]
+//
+// -- Allow users of the user table to sign up and sign in
+// DEFINE ACCESS account ON DATABASE TYPE RECORD
+// 	SIGNUP ( CREATE user SET email = $email, pass = crypto::argon2::generate($pass) )
+// 	SIGNIN ( SELECT * FROM user WHERE email = $email AND crypto::argon2::compare(pass, $pass) )
+// 	DURATION FOR SESSION 24h
+// ;
+use std::fmt::{self, Display};
+
+use surrealdb::sql;
+
+use super::{define_keyword, DefineMode, Subquery};
+use crate::{
+    traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    types::{Access, DurationLike, TokenType},
+};
+
+/// The level an access method is defined on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccessTarget {
+    /// Access method for the current namespace
+    Namespace,
+    /// Access method for the current database
+    Database,
+}
+
+impl Display for AccessTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AccessTarget::Namespace => write!(f, "NAMESPACE"),
+            AccessTarget::Database => write!(f, "DATABASE"),
+        }
+    }
+}
+
+/// JWT configuration of an access method.
+/// Use with `define_access(..).type_(jwt_access()...)` or
+/// `record_access().with_jwt(jwt_access()...)`.
+#[derive(Debug, Clone)]
+pub struct JwtAccess {
+    algorithm: Option<TokenType>,
+    key: Option<String>,
+    url: Option<String>,
+    bindings: BindingsList,
+}
+
+/// Create the JWT configuration of an access method.
+///
+/// Examples:
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::{define_access, jwt_access}};
+///
+/// let statement = define_access("oauth")
+///     .on_database()
+///     .type_(jwt_access().algorithm(TokenType::HS512).key("sNSYneezcr8kqphfOC6NwwraUHJCVAt0X"));
+/// assert_eq!(
+///     statement.to_raw().build(),
+///     "DEFINE ACCESS oauth ON DATABASE TYPE JWT ALGORITHM HS512 KEY 'sNSYneezcr8kqphfOC6NwwraUHJCVAt0X';"
+/// );
+/// ```
+pub fn jwt_access() -> JwtAccess {
+    JwtAccess {
+        algorithm: None,
+        key: None,
+        url: None,
+        bindings: vec![],
+    }
+}
+
+impl JwtAccess {
+    /// Specify the cryptographic signature algorithm used to sign and verify the token
+    pub fn algorithm(mut self, algorithm: TokenType) -> Self {
+        self.algorithm = Some(algorithm);
+        self
+    }
+
+    /// Specify the key used to verify the authenticity of the token
+    pub fn key(mut self, key: impl Into<sql::Strand>) -> Self {
+        let binding = Binding::new(key.into());
+        self.key = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self
+    }
+
+    /// Specify the JWKS url the keys for verifying the token are fetched from.
+    /// Takes precedence over `algorithm` and `key`.
+    pub fn url(mut self, url: impl Into<sql::Strand>) -> Self {
+        let binding = Binding::new(url.into());
+        self.url = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self
+    }
+}
+
+impl Buildable for JwtAccess {
+    fn build(&self) -> String {
+        let mut query = "JWT".to_string();
+
+        if let Some(url) = &self.url {
+            return format!("{query} URL {url}");
+        }
+
+        if let Some(algorithm) = &self.algorithm {
+            query = format!("{query} ALGORITHM {algorithm}");
+        }
+
+        if let Some(key) = &self.key {
+            query = format!("{query} KEY {key}");
+        }
+
+        query
+    }
+}
+
+impl Parametric for JwtAccess {
+    fn get_bindings(&self) -> BindingsList {
+        self.bindings.to_vec()
+    }
+}
+
+/// Record configuration of an access method.
+/// Records of a table sign up and sign in through the provided expressions.
+#[derive(Debug, Clone)]
+pub struct RecordAccess {
+    signup_expression: Option<String>,
+    signin_expression: Option<String>,
+    jwt: Option<JwtAccess>,
+    bindings: BindingsList,
+}
+
+/// Create the record configuration of an access method.
+///
+/// Examples:
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::{define_access, record_access}};
+///
+/// let statement = define_access("account")
+///     .on_database()
+///     .type_(
+///         record_access()
+///             .signup(Raw::new("CREATE user SET email = $email"))
+///             .signin(Raw::new("SELECT * FROM user WHERE email = $email")),
+///     );
+/// assert_eq!(
+///     statement.to_raw().build(),
+///     "DEFINE ACCESS account ON DATABASE TYPE RECORD \
+///     \n\tSIGNUP (CREATE user SET email = $email) \
+///     \n\tSIGNIN (SELECT * FROM user WHERE email = $email);"
+/// );
+/// ```
+pub fn record_access() -> RecordAccess {
+    RecordAccess {
+        signup_expression: None,
+        signin_expression: None,
+        jwt: None,
+        bindings: vec![],
+    }
+}
+
+impl RecordAccess {
+    /// Set the signup expression
+    pub fn signup(mut self, subquery: impl Into<Subquery>) -> Self {
+        let subquery: Subquery = subquery.into();
+        self.bindings.extend(subquery.get_bindings());
+        self.signup_expression = Some(subquery.build());
+        self
+    }
+
+    /// Set the signin expression
+    pub fn signin(mut self, subquery: impl Into<Subquery>) -> Self {
+        let subquery: Subquery = subquery.into();
+        self.bindings.extend(subquery.get_bindings());
+        self.signin_expression = Some(subquery.build());
+        self
+    }
+
+    /// Set how the tokens issued to the records are signed and verified
+    pub fn with_jwt(mut self, jwt: JwtAccess) -> Self {
+        self.jwt = Some(jwt);
+        self
+    }
+}
+
+impl Buildable for RecordAccess {
+    fn build(&self) -> String {
+        let mut query = "RECORD".to_string();
+
+        if let Some(signup) = &self.signup_expression {
+            query = format!("{query} \n\tSIGNUP {signup}");
+        }
+
+        if let Some(signin) = &self.signin_expression {
+            query = format!("{query} \n\tSIGNIN {signin}");
+        }
+
+        if let Some(jwt) = &self.jwt {
+            query = format!("{query} \n\tWITH {}", jwt.build());
+        }
+
+        query
+    }
+}
+
+impl Parametric for RecordAccess {
+    fn get_bindings(&self) -> BindingsList {
+        let jwt_bindings = self
+            .jwt
+            .as_ref()
+            .map(Parametric::get_bindings)
+            .unwrap_or_default();
+        [self.bindings.to_vec(), jwt_bindings].concat()
+    }
+}
+
+/// The type of an access method
+#[derive(Debug, Clone)]
+pub enum AccessType {
+    /// Verifies tokens issued by a third-party provider
+    Jwt(JwtAccess),
+    /// Lets records of a table sign up and sign in
+    Record(RecordAccess),
+}
+
+impl From<JwtAccess> for AccessType {
+    fn from(value: JwtAccess) -> Self {
+        Self::Jwt(value)
+    }
+}
+
+impl From<RecordAccess> for AccessType {
+    fn from(value: RecordAccess) -> Self {
+        Self::Record(value)
+    }
+}
+
+impl Buildable for AccessType {
+    fn build(&self) -> String {
+        match self {
+            AccessType::Jwt(jwt) => jwt.build(),
+            AccessType::Record(record) => record.build(),
+        }
+    }
+}
+
+impl Parametric for AccessType {
+    fn get_bindings(&self) -> BindingsList {
+        match self {
+            AccessType::Jwt(jwt) => jwt.get_bindings(),
+            AccessType::Record(record) => record.get_bindings(),
+        }
+    }
+}
+
+/// Define the API for the Access builder
+pub struct DefineAccessStatement {
+    name: String,
+    target: Option<AccessTarget>,
+    access_type: Option<AccessType>,
+    authenticate: Option<String>,
+    token_duration: Option<String>,
+    session_duration: Option<String>,
+    bindings: BindingsList,
+    define_mode: Option<DefineMode>,
+}
+
+/// Define a new access method.
+///
+/// Access methods are the successor of scopes and tokens.
+/// A `RECORD` access method lets the records of a table sign up and sign in as users,
+/// and a `JWT` access method verifies tokens issued by a third-party provider.
+/// Requires SurrealDB 2.0 or later. On SurrealDB 1.x, use `define_scope` and `define_token`.
+///
+/// Requirements
+/// To DEFINE ACCESS ... ON NAMESPACE ... you must have root or namespace level access.
+/// To DEFINE ACCESS ... ON DATABASE ... you must have root, namespace, or database level access.
+///
+/// Examples:
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, functions::crypto, statements::{define_access, record_access, select}};
+/// use std::time::Duration;
+///
+/// # let user = Table::new("user");
+/// # let email = Field::new("email");
+/// # let pass = Field::new("pass");
+/// # let pass_param = Param::new("pass");
+/// let statement = define_access("account")
+///     .on_database()
+///     .type_(
+///         record_access()
+///             .signup(Raw::new(
+///                 "CREATE user SET email = $email, pass = crypto::argon2::generate($pass)",
+///             ))
+///             .signin(
+///                 select(All).from(user).where_(
+///                     cond(email.equal(Param::new("email")))
+///                         .and(crypto::argon2::compare!(pass, pass_param)),
+///                 ),
+///             ),
+///     )
+///     .duration_for_token(Duration::from_secs(60 * 60))
+///     .duration_for_session(Duration::from_secs(60 * 60 * 24));
+///
+/// assert!(!statement.build().is_empty());
+/// ```
+pub fn define_access(access_name: impl Into<Access>) -> DefineAccessStatement {
+    let access_name: Access = access_name.into();
+
+    DefineAccessStatement {
+        name: access_name.to_string(),
+        target: None,
+        access_type: None,
+        authenticate: None,
+        token_duration: None,
+        session_duration: None,
+        bindings: vec![],
+        define_mode: None,
+    }
+}
+
+impl DefineAccessStatement {
+    /// Define the access method for the current namespace
+    pub fn on_namespace(mut self) -> Self {
+        self.target = Some(AccessTarget::Namespace);
+        self
+    }
+
+    /// Define the access method for the current database
+    pub fn on_database(mut self) -> Self {
+        self.target = Some(AccessTarget::Database);
+        self
+    }
+
+    /// Set the type of the access method i.e `record_access()` or `jwt_access()`
+    pub fn type_(mut self, access_type: impl Into<AccessType>) -> Self {
+        let access_type: AccessType = access_type.into();
+        self.bindings.extend(access_type.get_bindings());
+        self.access_type = Some(access_type);
+        self
+    }
+
+    /// Set the expression run after the user is authenticated.
+    /// It can be used to reject the authentication by throwing an error.
+    pub fn authenticate(mut self, subquery: impl Into<Subquery>) -> Self {
+        let subquery: Subquery = subquery.into();
+        self.bindings.extend(subquery.get_bindings());
+        self.authenticate = Some(subquery.build());
+        self
+    }
+
+    /// Set how long the issued tokens are valid for
+    pub fn duration_for_token(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.bindings.extend(duration.get_bindings());
+        self.token_duration = Some(duration.build());
+        self
+    }
+
+    /// Set how long the sessions are valid for
+    pub fn duration_for_session(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.bindings.extend(duration.get_bindings());
+        self.session_duration = Some(duration.build());
+        self
+    }
+
+    /// Skips the statement if the access method is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the access method if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
+}
+
+impl Buildable for DefineAccessStatement {
+    fn build(&self) -> String {
+        let mut query = format!(
+            "{} {}",
+            define_keyword("ACCESS", self.define_mode),
+            self.name
+        );
+
+        if let Some(target) = &self.target {
+            query = format!("{query} ON {target}");
+        }
+
+        if let Some(access_type) = &self.access_type {
+            query = format!("{query} TYPE {}", access_type.build());
+        }
+
+        if let Some(authenticate) = &self.authenticate {
+            query = format!("{query} \n\tAUTHENTICATE {authenticate}");
+        }
+
+        let durations = [
+            self.token_duration
+                .as_ref()
+                .map(|duration| format!("FOR TOKEN {duration}")),
+            self.session_duration
+                .as_ref()
+                .map(|duration| format!("FOR SESSION {duration}")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        if !durations.is_empty() {
+            query = format!("{query} \n\tDURATION {}", durations.join(", "));
+        }
+
+        query += ";";
+        query
+    }
+}
+
+impl Display for DefineAccessStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl Parametric for DefineAccessStatement {
+    fn get_bindings(&self) -> BindingsList {
+        self.bindings.to_vec()
+    }
+}
+
+impl Queryable for DefineAccessStatement {}
+
+impl Erroneous for DefineAccessStatement {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        functions::crypto,
+        statements::{define_access, jwt_access, record_access, select},
+        *,
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn test_define_record_access_on_database() {
+        let user = Table::new("user");
+        let email = Field::new("email");
+        let pass = Field::new("pass");
+        let pass_param = Param::new("pass");
+
+        let statement = define_access("account")
+            .on_database()
+            .type_(
+                record_access()
+                    .signup(Raw::new(
+                        "CREATE user SET email = $email, pass = crypto::argon2::generate($pass)",
+                    ))
+                    .signin(
+                        select(All).from(user).where_(
+                            cond(email.equal(Param::new("email")))
+                                .and(crypto::argon2::compare!(pass, pass_param)),
+                        ),
+                    ),
+            )
+            .duration_for_token(Duration::from_secs(60 * 60))
+            .duration_for_session(Duration::from_secs(60 * 60 * 24));
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE ACCESS account ON DATABASE TYPE RECORD \
+                \n\tSIGNUP $_param_00000001 \
+                \n\tSIGNIN $_param_00000002 \
+                \n\tDURATION FOR TOKEN $_param_00000003, FOR SESSION $_param_00000004;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS account ON DATABASE TYPE RECORD \
+                \n\tSIGNUP (CREATE user SET email = $email, pass = crypto::argon2::generate($pass)) \
+                \n\tSIGNIN (SELECT * FROM user WHERE (email = $email) AND (crypto::argon2::compare(pass, $pass))) \
+                \n\tDURATION FOR TOKEN 1h, FOR SESSION 1d;"
+        );
+        assert_eq!(statement.get_bindings().len(), 4);
+    }
+
+    #[test]
+    fn test_define_record_access_with_jwt_and_authenticate() {
+        let statement = define_access("account")
+            .on_namespace()
+            .type_(
+                record_access()
+                    .signin(Raw::new("SELECT * FROM user WHERE email = $email"))
+                    .with_jwt(jwt_access().algorithm(TokenType::HS256).key("secret")),
+            )
+            .authenticate(Raw::new("SELECT * FROM $auth WHERE enabled = true"))
+            .overwrite();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS OVERWRITE account ON NAMESPACE TYPE RECORD \
+                \n\tSIGNIN (SELECT * FROM user WHERE email = $email) \
+                \n\tWITH JWT ALGORITHM HS256 KEY 'secret' \
+                \n\tAUTHENTICATE (SELECT * FROM $auth WHERE enabled = true);"
+        );
+        assert_eq!(statement.get_bindings().len(), 3);
+    }
+
+    #[test]
+    fn test_define_jwt_access() {
+        let statement = define_access("oauth")
+            .on_database()
+            .type_(jwt_access().key("secret").algorithm(TokenType::RS256))
+            .if_not_exists();
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE ACCESS IF NOT EXISTS oauth ON DATABASE TYPE JWT ALGORITHM RS256 KEY $_param_00000001;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS IF NOT EXISTS oauth ON DATABASE TYPE JWT ALGORITHM RS256 KEY 'secret';"
+        );
+    }
+
+    #[test]
+    fn test_define_jwt_access_with_url() {
+        let statement = define_access("oauth")
+            .on_database()
+            .type_(jwt_access().url("https://example.com/.well-known/jwks.json"))
+            .duration_for_session(Duration::from_secs(60 * 60 * 2));
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE ACCESS oauth ON DATABASE TYPE JWT URL 'https://example.com/.well-known/jwks.json' \
+                \n\tDURATION FOR SESSION 2h;"
+        );
+    }
+}
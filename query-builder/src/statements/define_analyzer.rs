@@ -6,6 +6,7 @@
 
 use std::fmt::{self, Display};
 
+use super::{define_keyword, DefineMode};
 use crate::{BindingsList, Buildable, Erroneous, ErrorList, Parametric, Queryable};
 
 #[allow(missing_docs)]
@@ -104,6 +105,7 @@ pub struct DefineAnalyzerStatement {
     filters: Vec<AnalyzerFilter>,
     bindings: BindingsList,
     errors: ErrorList,
+    define_mode: Option<DefineMode>,
 }
 
 /// Create a new `DefineAnalyzerStatement`
@@ -125,6 +127,7 @@ pub fn define_analyzer(name: &str) -> DefineAnalyzerStatement {
         filters: vec![],
         bindings: vec![],
         errors: vec![],
+        define_mode: None,
     }
 }
 
@@ -147,6 +150,18 @@ impl DefineAnalyzerStatement {
         self.filters.extend(filters.into_iter().collect::<Vec<_>>());
         self
     }
+
+    /// Skips the statement if the analyzer is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the analyzer if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Queryable for DefineAnalyzerStatement {}
@@ -164,7 +179,11 @@ impl Parametric for DefineAnalyzerStatement {
 
 impl Buildable for DefineAnalyzerStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE ANALYZER {}", self.name);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("ANALYZER", self.define_mode),
+            self.name
+        );
 
         if !self.tokenizers.is_empty() {
             let tokenizers_str = self
@@ -218,4 +237,16 @@ mod tests {
             "DEFINE ANALYZER ascii TOKENIZERS Class FILTERS lowercase,ascii,edgengram(2, 15),snowball(english);"
         );
     }
+
+    #[test]
+    fn test_define_analyzer_if_not_exists() {
+        let analyzer = define_analyzer("ascii")
+            .tokenizers([Tokenizer::Class])
+            .if_not_exists();
+
+        assert_eq!(
+            analyzer.build(),
+            "DEFINE ANALYZER IF NOT EXISTS ascii TOKENIZERS Class;"
+        );
+    }
 }
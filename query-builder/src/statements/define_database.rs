@@ -6,6 +6,7 @@
 
 use std::fmt::Display;
 
+use super::{define_keyword, DefineMode};
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::Database,
@@ -41,6 +42,7 @@ pub fn define_database(database: impl Into<Database>) -> DefineDatabaseStatement
     DefineDatabaseStatement {
         database: database.into().into(),
         bindings: vec![],
+        define_mode: None,
     }
 }
 
@@ -48,11 +50,30 @@ pub fn define_database(database: impl Into<Database>) -> DefineDatabaseStatement
 pub struct DefineDatabaseStatement {
     database: String,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
+}
+
+impl DefineDatabaseStatement {
+    /// Skips the statement if the database is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the database if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Buildable for DefineDatabaseStatement {
     fn build(&self) -> String {
-        format!("DEFINE DATABASE {};", self.database)
+        format!(
+            "{} {};",
+            define_keyword("DATABASE", self.define_mode),
+            self.database
+        )
     }
 }
 
@@ -82,4 +103,16 @@ mod tests {
             "DEFINE DATABASE oyelowo;"
         );
     }
+
+    #[test]
+    fn test_define_statement_if_not_exists_and_overwrite() {
+        assert_eq!(
+            define_database("oyelowo").if_not_exists().build(),
+            "DEFINE DATABASE IF NOT EXISTS oyelowo;"
+        );
+        assert_eq!(
+            define_database("oyelowo").overwrite().build(),
+            "DEFINE DATABASE OVERWRITE oyelowo;"
+        );
+    }
 }
@@ -6,6 +6,7 @@
 
 use std::fmt::{self, Display};
 
+use super::{define_keyword, DefineMode};
 use crate::{
     BindingsList, Buildable, Conditional, Erroneous, Event, Filter, Parametric, Queryable, Table,
 };
@@ -32,6 +33,7 @@ pub struct EventBuilder {
     when: Option<String>,
     then_string: Option<String>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 /**
@@ -95,6 +97,7 @@ impl EventBuilder {
             when: None,
             then_string: None,
             bindings: vec![],
+            define_mode: None,
         }
     }
 
@@ -111,6 +114,18 @@ impl EventBuilder {
         self.bindings.extend(cond.get_bindings());
         Then(self)
     }
+
+    /// Skips the statement if the event is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the event if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 pub struct Then(EventBuilder);
@@ -126,10 +141,28 @@ impl Then {
 /// A statement for defining an event.
 pub struct DefineEventStatement(EventBuilder);
 
+impl DefineEventStatement {
+    /// Skips the statement if the event is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.0.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the event if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.0.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
+}
+
 // DEFINE EVENT @name ON [ TABLE ] @table WHEN @expression THEN @expression
 impl Buildable for DefineEventStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE EVENT {}", &self.0.event);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("EVENT", self.0.define_mode),
+            &self.0.event
+        );
         if let Some(table) = &self.0.on_table {
             query = format!("{query} ON TABLE {table}");
         }
@@ -207,4 +240,30 @@ mod tests {
                 (city IS 'NewFoundland') OR (city ~ 'Toronto') LIMIT 153 START AT 10 PARALLEL;",
         );
     }
+
+    #[test]
+    fn test_define_event_if_not_exists_and_overwrite() {
+        let age = Field::new("age");
+        let user_table = Table::new("user");
+
+        let query = define_event(Event::new("adult"))
+            .if_not_exists()
+            .on_table(user_table.clone())
+            .when(cond(age.greater_than(18)))
+            .then(select(All).from(user_table.clone()));
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE EVENT IF NOT EXISTS adult ON TABLE user WHEN age > 18 THEN SELECT * FROM user;"
+        );
+
+        let query = define_event(Event::new("adult"))
+            .on_table(user_table.clone())
+            .when(cond(age.greater_than(18)))
+            .then(select(All).from(user_table))
+            .overwrite();
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE EVENT OVERWRITE adult ON TABLE user WHEN age > 18 THEN SELECT * FROM user;"
+        );
+    }
 }
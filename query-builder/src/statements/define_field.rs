@@ -11,7 +11,7 @@ use crate::{
     Queryable, Table, ValueLike,
 };
 
-use super::for_permission::Permissions;
+use super::{define_keyword, for_permission::Permissions, DefineMode};
 
 // DEFINE FIELD statement
 // The DEFINE FIELD statement allows you to instantiate a named field on a table, enabling you to set the field's data type, set a default value, apply assertions to protect data consistency, and set permissions specifying what operations can be performed on the field.
@@ -43,6 +43,7 @@ pub struct DefineFieldStatement {
     permissions_full: Option<bool>,
     permissions_for: Vec<String>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 /// Define a new field.
@@ -89,6 +90,7 @@ pub fn define_field(fieldable: impl Into<Field>) -> DefineFieldStatement {
         permissions_full: None,
         permissions_for: vec![],
         bindings: vec![],
+        define_mode: None,
     }
 }
 
@@ -111,11 +113,21 @@ impl DefineFieldStatement {
     pub fn value(mut self, default_value: impl Into<ValueLike>) -> Self {
         // pub fn value<T, U>(mut self, default_value: U) where T: Deref<Target = U>, T: Into<U>, U: Into<T>, T: Into<ValueLike>, T: Into<sql::Value> -> Self {
         let value: ValueLike = default_value.into();
-        self.value = Some(value.build());
+        self.value = Some(format!("$value OR {}", value.build()));
         self.bindings.extend(value.get_bindings());
         self
     }
 
+    /// Set the value of the field to an expression computed on every write.
+    /// Unlike `value`, the value written is replaced by the expression
+    /// e.g `$before OR time::now()` keeps the time the record was created.
+    pub fn value_expression(mut self, expression: impl Into<ValueLike>) -> Self {
+        let expression: ValueLike = expression.into();
+        self.value = Some(expression.build());
+        self.bindings.extend(expression.get_bindings());
+        self
+    }
+
     /// assert constraint on the field.
     ///  
     ///  Examples:
@@ -221,6 +233,18 @@ impl DefineFieldStatement {
         }
         self
     }
+
+    /// Skips the statement if the field is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the field if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Queryable for DefineFieldStatement {}
@@ -234,7 +258,11 @@ impl Parametric for DefineFieldStatement {
 
 impl Buildable for DefineFieldStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE FIELD {}", &self.field_name);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("FIELD", self.define_mode),
+            &self.field_name
+        );
 
         if let Some(table) = &self.table {
             query = format!("{query} ON TABLE {table}");
@@ -245,7 +273,7 @@ impl Buildable for DefineFieldStatement {
         }
 
         if let Some(value) = &self.value {
-            query = format!("{query} VALUE $value OR {value}");
+            query = format!("{query} VALUE {value}");
         }
 
         if let Some(assertion) = &self.assert {
@@ -337,4 +365,28 @@ mod tests {
         insta::assert_snapshot!(statement.fine_tune_params());
         assert_eq!(statement.get_bindings().len(), 0);
     }
+
+    #[test]
+    fn test_define_field_if_not_exists_and_overwrite() {
+        let email = Field::new("email");
+        let user_table = Table::from("user");
+
+        let statement = define_field(email.clone())
+            .on_table(user_table.clone())
+            .type_(FieldType::String)
+            .if_not_exists();
+        assert_eq!(
+            statement.build(),
+            "DEFINE FIELD IF NOT EXISTS email ON TABLE user TYPE string;"
+        );
+
+        let statement = define_field(email)
+            .on_table(user_table)
+            .type_(FieldType::String)
+            .overwrite();
+        assert_eq!(
+            statement.build(),
+            "DEFINE FIELD OVERWRITE email ON TABLE user TYPE string;"
+        );
+    }
 }
@@ -4,6 +4,7 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
+use super::{define_keyword, DefineMode};
 use crate::{
     BindingsList, Block, Buildable, Erroneous, ErrorList, FieldType, Param, Parametric, Queryable,
 };
@@ -25,6 +26,7 @@ pub struct DefineFunctionStatement {
     body: Option<Block>,
     bindings: BindingsList,
     errors: ErrorList,
+    define_mode: Option<DefineMode>,
 }
 
 impl DefineFunctionStatement {
@@ -39,6 +41,7 @@ impl DefineFunctionStatement {
             body: None,
             bindings: vec![],
             errors: vec![],
+            define_mode: None,
         }
     }
 
@@ -56,6 +59,18 @@ impl DefineFunctionStatement {
         self.body = Some(body);
         self
     }
+
+    /// Skips the statement if the function is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the function if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 /// Create a new function definition statement
@@ -66,6 +81,7 @@ pub fn define_function(name: impl Into<String>) -> DefineFunctionStatement {
         body: None,
         bindings: vec![],
         errors: vec![],
+        define_mode: None,
     }
 }
 
@@ -85,7 +101,11 @@ impl Queryable for DefineFunctionStatement {}
 
 impl Buildable for DefineFunctionStatement {
     fn build(&self) -> String {
-        let mut build = format!("DEFINE FUNCTION fn::{}(", self.name);
+        let mut build = format!(
+            "{} fn::{}(",
+            define_keyword("FUNCTION", self.define_mode),
+            self.name
+        );
         build.push_str(
             &self
                 .args
@@ -103,4 +123,20 @@ impl Buildable for DefineFunctionStatement {
 }
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::*;
+
+    use crate::{block, chain, statements::return_, ToRaw};
+
+    #[test]
+    fn test_define_function_if_not_exists() {
+        let statement = define_function("greet")
+            .body(block(chain(return_("Hello"))))
+            .if_not_exists();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE FUNCTION IF NOT EXISTS fn::greet() {\nRETURN 'Hello';\n};"
+        );
+    }
+}
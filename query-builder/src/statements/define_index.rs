@@ -13,7 +13,9 @@
 // You must select your namespace and database before you can use the DEFINE INDEX statement.
 // Statement syntax
 // DEFINE INDEX @name ON [ TABLE ] @table [ FIELDS | COLUMNS ] @fields
-// 	[ UNIQUE | SEARCH ANALYZER @analyzer [ BM25 [(@k1, @b)] ] [ HIGHLIGHTS ] ]
+// 	[ UNIQUE | SEARCH ANALYZER @analyzer [ BM25 [(@k1, @b)] ] [ HIGHLIGHTS ]
+// 	| MTREE DIMENSION @dimension [ DIST @distance ] [ TYPE @type ] [ CAPACITY @capacity ]
+// 	| HNSW DIMENSION @dimension [ TYPE @type ] [ DIST @distance ] [ EFC @efc ] [ M @m ] ]
 // Example usage
 // How to create a unique index for the email address field on a user table.
 //
@@ -27,9 +29,17 @@
 //
 // -- Allow full-text search queries on the name of the user
 // DEFINE INDEX userNameIndex ON TABLE user COLUMNS name SEARCH ANALYZER ascii BM25 HIGHLIGHTS;
+// How to create a vector index for an embedding field on a document table.
+//
+// -- Allow nearest neighbour queries on the embedding of the document
+// DEFINE INDEX documentEmbeddingIndex ON TABLE document FIELDS embedding MTREE DIMENSION 4 DIST COSINE;
 
-use std::fmt::{self, Display};
+use std::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
+use super::{define_keyword, DefineMode};
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::{Field, TableIndex},
@@ -76,8 +86,10 @@ pub fn define_index(index_name: impl Into<TableIndex>) -> DefineIndexStatement {
         columns: vec![],
         unique: None,
         search_analyzer: None,
+        vector_index: None,
         bindings: vec![],
         errors: vec![],
+        define_mode: None,
     }
 }
 
@@ -162,8 +174,10 @@ pub struct DefineIndexStatement {
     columns: Vec<Field>,
     unique: Option<bool>,
     search_analyzer: Option<SearchAnalyzer>,
+    vector_index: Option<VectorIndex>,
     bindings: BindingsList,
     errors: ErrorList,
+    define_mode: Option<DefineMode>,
 }
 
 impl DefineIndexStatement {
@@ -228,11 +242,73 @@ impl DefineIndexStatement {
         // self.errors.extend(search_analyzer.get_errors());
         self
     }
+
+    /// Set an M-Tree vector index on the fields
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::{define_index, mtree, VectorDistance, VectorType}};
+    ///
+    /// let query = define_index("document_embedding")
+    ///     .on_table("document")
+    ///     .fields(Field::new("embedding"))
+    ///     .mtree(
+    ///         mtree(4)
+    ///             .dist(VectorDistance::Cosine)
+    ///             .vector_type(VectorType::F32)
+    ///             .capacity(40),
+    ///     );
+    ///
+    /// assert_eq!(
+    ///     query.to_raw().build(),
+    ///     "DEFINE INDEX document_embedding ON TABLE document FIELDS embedding MTREE DIMENSION 4 DIST COSINE TYPE F32 CAPACITY 40;"
+    /// );
+    /// ```
+    pub fn mtree(mut self, mtree: MTreeIndex) -> Self {
+        self.vector_index = Some(VectorIndex::MTree(mtree));
+        self
+    }
+
+    /// Set a Hierarchical Navigable Small World (HNSW) vector index on the fields.
+    /// Requires a SurrealDB server version that supports HNSW indexes.
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::{define_index, hnsw, VectorDistance}};
+    ///
+    /// let query = define_index("document_embedding")
+    ///     .on_table("document")
+    ///     .fields(Field::new("embedding"))
+    ///     .hnsw(hnsw(4).dist(VectorDistance::Euclidean).efc(150).m(12));
+    ///
+    /// assert_eq!(
+    ///     query.to_raw().build(),
+    ///     "DEFINE INDEX document_embedding ON TABLE document FIELDS embedding HNSW DIMENSION 4 DIST EUCLIDEAN EFC 150 M 12;"
+    /// );
+    /// ```
+    pub fn hnsw(mut self, hnsw: HnswIndex) -> Self {
+        self.vector_index = Some(VectorIndex::Hnsw(hnsw));
+        self
+    }
+
+    /// Skips the statement if the index is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the index if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Buildable for DefineIndexStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE INDEX {}", self.index_name);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("INDEX", self.define_mode),
+            self.index_name
+        );
 
         if let Some(table) = &self.table {
             query = format!("{query} ON TABLE {table}");
@@ -263,6 +339,9 @@ impl Buildable for DefineIndexStatement {
         } else if let Some(search_analyzer) = &self.search_analyzer {
             let search_analyzer = search_analyzer.build();
             query = format!("{query} {search_analyzer}");
+        } else if let Some(vector_index) = &self.vector_index {
+            let vector_index = vector_index.build();
+            query = format!("{query} {vector_index}");
         }
         query += ";";
         query
@@ -290,12 +369,24 @@ impl Parametric for DefineIndexStatement {
             bindings.extend(search_analyzer.get_bindings());
         }
 
+        if let Some(vector_index) = &self.vector_index {
+            bindings.extend(vector_index.get_bindings());
+        }
+
         bindings
     }
 }
 
 impl Queryable for DefineIndexStatement {}
-impl Erroneous for DefineIndexStatement {}
+
+impl Erroneous for DefineIndexStatement {
+    fn get_errors(&self) -> ErrorList {
+        self.vector_index
+            .as_ref()
+            .map(Erroneous::get_errors)
+            .unwrap_or_default()
+    }
+}
 
 /// Scoring for search
 pub enum Scoring {
@@ -511,6 +602,336 @@ pub fn search_analyzer(analyzer_name: impl Into<TableLike>) -> SearchAnalyzer {
     }
 }
 
+/// Distance function used by vector indexes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorDistance {
+    /// Chebyshev distance
+    Chebyshev,
+    /// Cosine distance
+    Cosine,
+    /// Euclidean distance
+    Euclidean,
+    /// Hamming distance
+    Hamming,
+    /// Manhattan distance
+    Manhattan,
+}
+
+impl Display for VectorDistance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let distance = match self {
+            VectorDistance::Chebyshev => "CHEBYSHEV",
+            VectorDistance::Cosine => "COSINE",
+            VectorDistance::Euclidean => "EUCLIDEAN",
+            VectorDistance::Hamming => "HAMMING",
+            VectorDistance::Manhattan => "MANHATTAN",
+        };
+        write!(f, "{distance}")
+    }
+}
+
+impl FromStr for VectorDistance {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "chebyshev" => Ok(Self::Chebyshev),
+            "cosine" => Ok(Self::Cosine),
+            "euclidean" => Ok(Self::Euclidean),
+            "hamming" => Ok(Self::Hamming),
+            "manhattan" => Ok(Self::Manhattan),
+            _ => Err(format!(
+                "Invalid vector distance: {s}. Must be one of: chebyshev, cosine, euclidean, hamming, manhattan"
+            )),
+        }
+    }
+}
+
+/// Type of the vector elements stored in a vector index
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VectorType {
+    /// 64-bit float
+    F64,
+    /// 32-bit float
+    F32,
+    /// 64-bit integer
+    I64,
+    /// 32-bit integer
+    I32,
+    /// 16-bit integer
+    I16,
+}
+
+impl Display for VectorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let vector_type = match self {
+            VectorType::F64 => "F64",
+            VectorType::F32 => "F32",
+            VectorType::I64 => "I64",
+            VectorType::I32 => "I32",
+            VectorType::I16 => "I16",
+        };
+        write!(f, "{vector_type}")
+    }
+}
+
+impl FromStr for VectorType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "f64" => Ok(Self::F64),
+            "f32" => Ok(Self::F32),
+            "i64" => Ok(Self::I64),
+            "i32" => Ok(Self::I32),
+            "i16" => Ok(Self::I16),
+            _ => Err(format!(
+                "Invalid vector type: {s}. Must be one of: f64, f32, i64, i32, i16"
+            )),
+        }
+    }
+}
+
+/// Vector index kinds
+pub enum VectorIndex {
+    /// M-Tree index
+    MTree(MTreeIndex),
+    /// Hierarchical Navigable Small World index
+    Hnsw(HnswIndex),
+}
+
+impl Buildable for VectorIndex {
+    fn build(&self) -> String {
+        match self {
+            VectorIndex::MTree(mtree) => mtree.build(),
+            VectorIndex::Hnsw(hnsw) => hnsw.build(),
+        }
+    }
+}
+
+impl Parametric for VectorIndex {
+    fn get_bindings(&self) -> BindingsList {
+        match self {
+            VectorIndex::MTree(mtree) => mtree.get_bindings(),
+            VectorIndex::Hnsw(hnsw) => hnsw.get_bindings(),
+        }
+    }
+}
+
+impl Erroneous for VectorIndex {
+    fn get_errors(&self) -> ErrorList {
+        match self {
+            VectorIndex::MTree(mtree) => mtree.get_errors(),
+            VectorIndex::Hnsw(hnsw) => hnsw.get_errors(),
+        }
+    }
+}
+
+/// Builder for M-Tree vector index params
+pub struct MTreeIndex {
+    dimension: NumberLike,
+    vector_type: Option<VectorType>,
+    distance: Option<VectorDistance>,
+    capacity: Option<NumberLike>,
+}
+
+/// Function to start building an M-Tree vector index with the dimension of the vectors
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{statements::{mtree, VectorDistance, VectorType}};
+///
+/// mtree(4)
+///    .dist(VectorDistance::Manhattan)
+///    .vector_type(VectorType::F64)
+///    .capacity(40);
+/// ```
+pub fn mtree(dimension: impl Into<NumberLike>) -> MTreeIndex {
+    MTreeIndex {
+        dimension: dimension.into(),
+        vector_type: None,
+        distance: None,
+        capacity: None,
+    }
+}
+
+impl MTreeIndex {
+    /// Set the distance function of the index
+    pub fn dist(mut self, distance: VectorDistance) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Set the type of the vector elements
+    pub fn vector_type(mut self, vector_type: VectorType) -> Self {
+        self.vector_type = Some(vector_type);
+        self
+    }
+
+    /// Set the capacity of the nodes of the tree
+    pub fn capacity(mut self, capacity: impl Into<NumberLike>) -> Self {
+        self.capacity = Some(capacity.into());
+        self
+    }
+}
+
+impl Buildable for MTreeIndex {
+    fn build(&self) -> String {
+        let mut query = format!("MTREE DIMENSION {}", self.dimension.build());
+
+        if let Some(distance) = &self.distance {
+            query = format!("{query} DIST {distance}");
+        }
+
+        if let Some(vector_type) = &self.vector_type {
+            query = format!("{query} TYPE {vector_type}");
+        }
+
+        if let Some(capacity) = &self.capacity {
+            query = format!("{query} CAPACITY {}", capacity.build());
+        }
+
+        query
+    }
+}
+
+impl Parametric for MTreeIndex {
+    fn get_bindings(&self) -> BindingsList {
+        let mut bindings = self.dimension.get_bindings();
+
+        if let Some(capacity) = &self.capacity {
+            bindings.extend(capacity.get_bindings());
+        }
+
+        bindings
+    }
+}
+
+impl Erroneous for MTreeIndex {
+    fn get_errors(&self) -> ErrorList {
+        let mut errors = self.dimension.get_errors();
+
+        if let Some(capacity) = &self.capacity {
+            errors.extend(capacity.get_errors());
+        }
+
+        errors
+    }
+}
+
+/// Builder for HNSW vector index params
+pub struct HnswIndex {
+    dimension: NumberLike,
+    vector_type: Option<VectorType>,
+    distance: Option<VectorDistance>,
+    efc: Option<NumberLike>,
+    m: Option<NumberLike>,
+}
+
+/// Function to start building an HNSW vector index with the dimension of the vectors
+///
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{statements::{hnsw, VectorDistance, VectorType}};
+///
+/// hnsw(4)
+///    .dist(VectorDistance::Cosine)
+///    .vector_type(VectorType::F32)
+///    .efc(150)
+///    .m(12);
+/// ```
+pub fn hnsw(dimension: impl Into<NumberLike>) -> HnswIndex {
+    HnswIndex {
+        dimension: dimension.into(),
+        vector_type: None,
+        distance: None,
+        efc: None,
+        m: None,
+    }
+}
+
+impl HnswIndex {
+    /// Set the distance function of the index
+    pub fn dist(mut self, distance: VectorDistance) -> Self {
+        self.distance = Some(distance);
+        self
+    }
+
+    /// Set the type of the vector elements
+    pub fn vector_type(mut self, vector_type: VectorType) -> Self {
+        self.vector_type = Some(vector_type);
+        self
+    }
+
+    /// Set the size of the dynamic candidate list used while building the graph
+    pub fn efc(mut self, efc: impl Into<NumberLike>) -> Self {
+        self.efc = Some(efc.into());
+        self
+    }
+
+    /// Set the maximum number of connections per node in the graph
+    pub fn m(mut self, m: impl Into<NumberLike>) -> Self {
+        self.m = Some(m.into());
+        self
+    }
+}
+
+impl Buildable for HnswIndex {
+    fn build(&self) -> String {
+        let mut query = format!("HNSW DIMENSION {}", self.dimension.build());
+
+        if let Some(vector_type) = &self.vector_type {
+            query = format!("{query} TYPE {vector_type}");
+        }
+
+        if let Some(distance) = &self.distance {
+            query = format!("{query} DIST {distance}");
+        }
+
+        if let Some(efc) = &self.efc {
+            query = format!("{query} EFC {}", efc.build());
+        }
+
+        if let Some(m) = &self.m {
+            query = format!("{query} M {}", m.build());
+        }
+
+        query
+    }
+}
+
+impl Parametric for HnswIndex {
+    fn get_bindings(&self) -> BindingsList {
+        let mut bindings = self.dimension.get_bindings();
+
+        if let Some(efc) = &self.efc {
+            bindings.extend(efc.get_bindings());
+        }
+
+        if let Some(m) = &self.m {
+            bindings.extend(m.get_bindings());
+        }
+
+        bindings
+    }
+}
+
+impl Erroneous for HnswIndex {
+    fn get_errors(&self) -> ErrorList {
+        let mut errors = self.dimension.get_errors();
+
+        if let Some(efc) = &self.efc {
+            errors.extend(efc.get_errors());
+        }
+
+        if let Some(m) = &self.m {
+            errors.extend(m.get_errors());
+        }
+
+        errors
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{arr, ToRaw};
@@ -613,4 +1034,70 @@ mod tests {
         );
         assert_eq!(query.get_bindings().len(), 1);
     }
+
+    #[test]
+    fn test_define_index_statement_mtree() {
+        let embedding = Field::new("embedding");
+
+        let query = define_index("documentEmbeddingIndex")
+            .on_table("document")
+            .fields(embedding)
+            .mtree(
+                mtree(4)
+                    .vector_type(VectorType::F64)
+                    .dist(VectorDistance::Cosine)
+                    .capacity(40),
+            );
+
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX documentEmbeddingIndex ON TABLE document FIELDS embedding MTREE DIMENSION 4 DIST COSINE TYPE F64 CAPACITY 40;"
+        );
+        assert_eq!(
+            query.fine_tune_params(),
+            "DEFINE INDEX documentEmbeddingIndex ON TABLE $_param_00000001 FIELDS embedding MTREE DIMENSION $_param_00000002 DIST COSINE TYPE F64 CAPACITY $_param_00000003;"
+        );
+        assert_eq!(query.get_bindings().len(), 3);
+    }
+
+    #[test]
+    fn test_define_index_statement_hnsw() {
+        let embedding = Field::new("embedding");
+
+        let query = define_index("documentEmbeddingIndex")
+            .on_table("document")
+            .fields(embedding)
+            .hnsw(hnsw(4).dist(VectorDistance::Manhattan).efc(150).m(12));
+
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX documentEmbeddingIndex ON TABLE document FIELDS embedding HNSW DIMENSION 4 DIST MANHATTAN EFC 150 M 12;"
+        );
+        assert_eq!(query.get_bindings().len(), 4);
+    }
+
+    #[test]
+    fn define_index_if_not_exists_and_overwrite() {
+        let email = Field::new("email");
+
+        let query = define_index("userEmailIndex")
+            .on_table("user")
+            .fields([email.clone()])
+            .unique()
+            .if_not_exists();
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX IF NOT EXISTS userEmailIndex ON TABLE user FIELDS email UNIQUE;"
+        );
+
+        let query = define_index("userEmailIndex")
+            .on_table("user")
+            .fields([email])
+            .unique()
+            .overwrite();
+        assert_eq!(
+            query.to_raw().build(),
+            "DEFINE INDEX OVERWRITE userEmailIndex ON TABLE user FIELDS email UNIQUE;"
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use super::{define_keyword, DefineMode};
 use crate::{
     statements::Permissions, BindingsList, Buildable, Erroneous, LiteralLike, Parametric,
     Queryable, StrandLike, TableLike,
@@ -23,6 +24,7 @@ pub struct DefineModelStatement {
     permissions_full: Option<bool>,
     permissions_for: Vec<String>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 /// A model name.
@@ -63,6 +65,7 @@ pub fn define_model(name: impl Into<ModelName>) -> DefineModelStatement {
         permissions_full: None,
         permissions_for: vec![],
         bindings: name.get_bindings(),
+        define_mode: None,
     }
 }
 
@@ -156,6 +159,18 @@ impl DefineModelStatement {
         }
         self
     }
+
+    /// Skips the statement if the model is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the model if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Queryable for DefineModelStatement {}
@@ -170,7 +185,11 @@ impl Parametric for DefineModelStatement {
 
 impl Buildable for DefineModelStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE MODEL ml::{}", &self.model_name);
+        let mut query = format!(
+            "{} ml::{}",
+            define_keyword("MODEL", self.define_mode),
+            &self.model_name
+        );
 
         if !self.version.is_empty() {
             query = format!("{query}<{version}>", version = self.version);
@@ -272,4 +291,14 @@ mod tests {
         insta::assert_snapshot!(statement.fine_tune_params());
         assert_eq!(statement.get_bindings().len(), 2);
     }
+
+    #[test]
+    fn test_define_model_overwrite() {
+        let statement = define_model("recommendation").version("1.2.3").overwrite();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE MODEL OVERWRITE ml::recommendation<1.2.3>;"
+        );
+    }
 }
@@ -6,6 +6,7 @@
 
 use std::fmt::Display;
 
+use super::{define_keyword, DefineMode};
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::Namespace,
@@ -37,6 +38,7 @@ pub fn define_namespace(namespace: impl Into<Namespace>) -> DefineNamespaceState
     DefineNamespaceStatement {
         namespace: namespace.into().into(),
         bindings: vec![],
+        define_mode: None,
     }
 }
 
@@ -44,11 +46,30 @@ pub fn define_namespace(namespace: impl Into<Namespace>) -> DefineNamespaceState
 pub struct DefineNamespaceStatement {
     namespace: String,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
+}
+
+impl DefineNamespaceStatement {
+    /// Skips the statement if the namespace is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the namespace if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Buildable for DefineNamespaceStatement {
     fn build(&self) -> String {
-        format!("DEFINE NAMESPACE {};", self.namespace)
+        format!(
+            "{} {};",
+            define_keyword("NAMESPACE", self.define_mode),
+            self.namespace
+        )
     }
 }
 
@@ -78,4 +99,16 @@ mod tests {
             "DEFINE NAMESPACE oyelowo;"
         );
     }
+
+    #[test]
+    fn test_define_namespace_if_not_exists_and_overwrite() {
+        assert_eq!(
+            define_namespace("oyelowo").if_not_exists().build(),
+            "DEFINE NAMESPACE IF NOT EXISTS oyelowo;"
+        );
+        assert_eq!(
+            define_namespace("oyelowo").overwrite().build(),
+            "DEFINE NAMESPACE OVERWRITE oyelowo;"
+        );
+    }
 }
@@ -6,6 +6,7 @@
 
 use std::{fmt::Display, ops::Deref};
 
+use super::{define_keyword, DefineMode};
 use crate::{
     BindingsList, Buildable, Erroneous, ErrorList, Param, Parametric, Queryable, ValueLike,
 };
@@ -59,6 +60,7 @@ pub fn define_param(param_name: impl Deref<Target = Param>) -> DefineParamStatem
         value: None,
         bindings: vec![],
         errors: vec![],
+        define_mode: None,
     };
     DefineParamStatementBuilder(define_param_statement)
 }
@@ -71,6 +73,21 @@ pub struct DefineParamStatement {
     value: Option<String>,
     bindings: BindingsList,
     errors: ErrorList,
+    define_mode: Option<DefineMode>,
+}
+
+impl DefineParamStatement {
+    /// Skips the statement if the param is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the param if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl DefineParamStatementBuilder {
@@ -86,7 +103,11 @@ impl DefineParamStatementBuilder {
 
 impl Buildable for DefineParamStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE PARAM {}", self.name);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("PARAM", self.define_mode),
+            self.name
+        );
 
         if let Some(value) = &self.value {
             query = format!("{query} VALUE {value}");
@@ -160,4 +181,15 @@ mod tests {
             "DEFINE PARAM $endpoint_base_with_doc VALUE $_param_00000001;"
         );
     }
+
+    #[test]
+    fn test_define_param_statement_if_not_exists() {
+        let statement = define_param(endpoint_base_without_doc())
+            .value("https://codebreather.com")
+            .if_not_exists();
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE PARAM IF NOT EXISTS $endpoint_base_without_doc VALUE 'https://codebreather.com';"
+        );
+    }
 }
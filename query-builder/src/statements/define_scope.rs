@@ -21,7 +21,7 @@ use crate::{
     types::{DurationLike, Scope},
 };
 
-use super::Subquery;
+use super::{define_keyword, DefineMode, Subquery};
 
 /// Define a new scope.
 /// Setting scope access allows SurrealDB to operate as a web database.
@@ -66,6 +66,7 @@ pub fn define_scope(scope_name: impl Into<Scope>) -> DefineScopeStatement {
         signup_expression: None,
         signin_expression: None,
         bindings: vec![binding_scope_name],
+        define_mode: None,
     }
 }
 
@@ -76,6 +77,7 @@ pub struct DefineScopeStatement {
     signup_expression: Option<String>,
     signin_expression: Option<String>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 impl DefineScopeStatement {
@@ -102,11 +104,27 @@ impl DefineScopeStatement {
         self.signin_expression = Some(subquery.build());
         self
     }
+
+    /// Skips the statement if the scope is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the scope if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Buildable for DefineScopeStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE SCOPE {}", self.name);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("SCOPE", self.define_mode),
+            self.name
+        );
 
         if let Some(session_duration) = &self.duration {
             query = format!("{query} SESSION {session_duration}");
@@ -184,4 +202,16 @@ mod tests {
 
         assert_eq!(token_def.get_bindings().len(), 4);
     }
+
+    #[test]
+    fn test_define_scope_statement_if_not_exists() {
+        let statement = define_scope("oyelowo_scope")
+            .session(Duration::from_secs(45))
+            .if_not_exists();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE SCOPE IF NOT EXISTS oyelowo_scope SESSION 45s;"
+        );
+    }
 }
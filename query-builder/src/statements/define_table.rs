@@ -6,9 +6,10 @@
 
 use std::fmt::{self, Display};
 
+use super::{define_keyword, DefineMode};
 use crate::{
     statements::{for_permission::Permissions, select::SelectStatement},
-    BindingsList, Buildable, Erroneous, Parametric, Queryable, Table,
+    BindingsList, Buildable, DurationLike, Erroneous, Parametric, Queryable, Table,
 };
 
 // DEFINE TABLE statement
@@ -26,6 +27,7 @@ use crate::{
 // 		[ WHERE @condition ]
 // 		[ GROUP [ BY ] @groups ]
 // 	]
+// 	[ CHANGEFEED @duration ]
 // 	[ PERMISSIONS [ NONE | FULL
 // 		| FOR select @expression
 // 		| FOR create @expression
@@ -39,11 +41,13 @@ pub struct DefineTableStatement {
     drop: Option<bool>,
     flexible: Option<bool>,
     schema_type: Option<SchemaType>,
+    changefeed: Option<String>,
     as_: Option<String>,
     permissions_none: Option<bool>,
     permissions_full: Option<bool>,
     permissions_for: Vec<String>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 /// Define a new table.
@@ -96,11 +100,13 @@ pub fn define_table(table: impl Into<Table>) -> DefineTableStatement {
         drop: None,
         flexible: None,
         schema_type: None,
+        changefeed: None,
         as_: None,
         permissions_none: None,
         permissions_full: None,
         permissions_for: vec![],
         bindings: vec![],
+        define_mode: None,
     }
 }
 
@@ -129,6 +135,32 @@ impl DefineTableStatement {
         self
     }
 
+    /// Record changes to the table for the given duration, so they can be read with
+    /// the `SHOW CHANGES` statement.
+    ///
+    /// Examples:
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::define_table};
+    /// use std::time::Duration;
+    ///
+    /// let statement = define_table(Table::from("reading"))
+    ///     .schemafull()
+    ///     .changefeed(Duration::from_secs(60 * 60 * 24));
+    ///
+    /// assert_eq!(
+    ///     statement.to_raw().build(),
+    ///     "DEFINE TABLE reading SCHEMAFULL CHANGEFEED 1d;"
+    /// );
+    /// ```
+    pub fn changefeed(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.bindings.extend(duration.get_bindings());
+        self.changefeed = Some(duration.build());
+        self
+    }
+
     /// Select from existing table.
     ///
     /// Examples:
@@ -231,6 +263,18 @@ impl DefineTableStatement {
         }
         self
     }
+
+    /// Skips the statement if the table is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the table if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 // Statement syntax
@@ -242,6 +286,7 @@ impl DefineTableStatement {
 // 		[ WHERE @condition ]
 // 		[ GROUP [ BY ] @groups ]
 // 	]
+// 	[ CHANGEFEED @duration ]
 // 	[ PERMISSIONS [ NONE | FULL
 // 		| FOR select @expression
 // 		| FOR create @expression
@@ -250,7 +295,11 @@ impl DefineTableStatement {
 // 	] ]
 impl Buildable for DefineTableStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE TABLE {}", &self.table);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("TABLE", self.define_mode),
+            &self.table
+        );
 
         if self.drop.unwrap_or_default() {
             query = format!("{query} DROP");
@@ -274,6 +323,10 @@ impl Buildable for DefineTableStatement {
             query = format!("{query} AS \n\t{}", select_statement.trim_end_matches(';'));
         }
 
+        if let Some(changefeed) = &self.changefeed {
+            query = format!("{query} CHANGEFEED {changefeed}");
+        }
+
         if let Some(true) = self.permissions_none {
             query = format!("{query} PERMISSIONS NONE");
         } else if let Some(true) = self.permissions_full {
@@ -352,6 +405,25 @@ mod tests {
         assert_eq!(statement.get_bindings().len(), 0);
     }
 
+    #[test]
+    fn test_define_statement_changefeed() {
+        let reading = Table::from("reading");
+        let statement = define_table(reading)
+            .schemafull()
+            .changefeed(std::time::Duration::from_secs(3600))
+            .permissions_full();
+
+        assert_eq!(
+            statement.fine_tune_params(),
+            "DEFINE TABLE reading SCHEMAFULL CHANGEFEED $_param_00000001 PERMISSIONS FULL;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE TABLE reading SCHEMAFULL CHANGEFEED 1h PERMISSIONS FULL;"
+        );
+        assert_eq!(statement.get_bindings().len(), 1);
+    }
+
     #[test]
     fn test_define_statement_multiple() {
         let name = Field::new("name");
@@ -398,4 +470,18 @@ mod tests {
         );
         assert_eq!(statement.get_bindings().len(), 8);
     }
+
+    #[test]
+    fn test_define_statement_if_not_exists_and_overwrite() {
+        let user = Table::from("user");
+
+        let statement = define_table(user.clone()).schemafull().if_not_exists();
+        assert_eq!(
+            statement.build(),
+            "DEFINE TABLE IF NOT EXISTS user SCHEMAFULL;"
+        );
+
+        let statement = define_table(user).schemafull().overwrite();
+        assert_eq!(statement.build(), "DEFINE TABLE OVERWRITE user SCHEMAFULL;");
+    }
 }
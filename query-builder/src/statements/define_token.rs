@@ -8,6 +8,7 @@ use std::fmt::{self, Display};
 
 use surrealdb::sql;
 
+use super::{define_keyword, DefineMode};
 use crate::{
     traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::{Idiomx, Scope, TokenTarget, TokenType},
@@ -46,6 +47,7 @@ pub struct DefineTokenStatement {
     value: Option<String>,
     target: Option<TokenTarget>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 /// Define a new token.
@@ -93,6 +95,7 @@ pub fn define_token(token_name: impl Into<Idiomx>) -> DefineTokenStatement {
         value: None,
         target: None,
         bindings: vec![],
+        define_mode: None,
     }
 }
 
@@ -128,11 +131,27 @@ impl DefineTokenStatement {
 
         self
     }
+
+    /// Skips the statement if the token is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the token if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Buildable for DefineTokenStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE TOKEN {}", self.name);
+        let mut query = format!(
+            "{} {}",
+            define_keyword("TOKEN", self.define_mode),
+            self.name
+        );
 
         if let Some(target) = &self.target {
             query = format!("{query} ON {target}");
@@ -232,4 +251,18 @@ mod tests {
 
         assert_eq!(statement.get_bindings().len(), 1);
     }
+
+    #[test]
+    fn test_define_token_statement_overwrite() {
+        let statement = define_token("oyelowo_token")
+            .on_database()
+            .type_(TokenType::HS512)
+            .value("anaksunamun")
+            .overwrite();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE TOKEN OVERWRITE oyelowo_token ON DATABASE TYPE HS512 VALUE 'anaksunamun';"
+        );
+    }
 }
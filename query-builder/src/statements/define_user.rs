@@ -6,6 +6,7 @@
 
 use std::fmt::{self, Display};
 
+use super::{define_keyword, DefineMode};
 use crate::{
     traits::{Binding, BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::Idiomx,
@@ -137,6 +138,7 @@ pub struct DefineUserStatement {
     credential: Option<UserCredential>,
     role: Option<UserRole>,
     bindings: BindingsList,
+    define_mode: Option<DefineMode>,
 }
 
 impl DefineUserStatement {
@@ -149,6 +151,7 @@ impl DefineUserStatement {
             credential: None,
             role: None,
             bindings: vec![binding],
+            define_mode: None,
         }
     }
 
@@ -195,11 +198,23 @@ impl DefineUserStatement {
         self.role = Some(role);
         self
     }
+
+    /// Skips the statement if the user is already defined.
+    pub fn if_not_exists(mut self) -> Self {
+        self.define_mode = Some(DefineMode::IfNotExists);
+        self
+    }
+
+    /// Replaces the user if it is already defined.
+    pub fn overwrite(mut self) -> Self {
+        self.define_mode = Some(DefineMode::Overwrite);
+        self
+    }
 }
 
 impl Buildable for DefineUserStatement {
     fn build(&self) -> String {
-        let mut query = format!("DEFINE USER {}", self.name);
+        let mut query = format!("{} {}", define_keyword("USER", self.define_mode), self.name);
 
         if let Some(user_type) = &self.user_type {
             query.push_str(&format!(" ON {user_type}"));
@@ -325,4 +340,18 @@ mod tests {
 
         assert_eq!(user_with_database_and_viewer.get_bindings().len(), 2);
     }
+
+    #[test]
+    fn test_define_user_statement_if_not_exists() {
+        let statement = define_user(User::new("username"))
+            .on_root()
+            .password("123456")
+            .role(UserRole::Owner)
+            .if_not_exists();
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "DEFINE USER IF NOT EXISTS username ON ROOT PASSWORD '123456' ROLES OWNER;"
+        );
+    }
 }
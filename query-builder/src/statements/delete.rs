@@ -14,7 +14,7 @@ use crate::{
     Binding, Conditional, ErrorList, ReturnableDefault, ReturnableStandard, ToRaw,
 };
 
-use super::update::TargettablesForUpdate;
+use super::update::{TargettablesForUpdate, UpdateStatement};
 
 /*
 Statement syntax
@@ -315,18 +315,37 @@ where
     }
 }
 
-/// Used in model helper methods for deleting records
+/// Used in model helper methods for deleting records.
+/// Sets the soft delete field instead of deleting the records of a soft deleted model.
 #[derive(Debug, Clone)]
-pub struct DeleteStatementMini<T>(DeleteStatement<T>)
+pub struct DeleteStatementMini<T>(DeleteKind<T>)
 where
     T: Serialize + DeserializeOwned + Model;
 
+#[derive(Debug, Clone)]
+enum DeleteKind<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    Delete(DeleteStatement<T>),
+    SoftDelete(UpdateStatement<T>),
+}
+
 impl<T> From<DeleteStatement<T>> for DeleteStatementMini<T>
 where
     T: Serialize + DeserializeOwned + Model,
 {
     fn from(statement: DeleteStatement<T>) -> Self {
-        Self(statement)
+        Self(DeleteKind::Delete(statement))
+    }
+}
+
+impl<T> From<UpdateStatement<T>> for DeleteStatementMini<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn from(statement: UpdateStatement<T>) -> Self {
+        Self(DeleteKind::SoftDelete(statement))
     }
 }
 
@@ -335,7 +354,10 @@ where
     T: Serialize + DeserializeOwned + Model,
 {
     fn get_bindings(&self) -> BindingsList {
-        self.0.get_bindings()
+        match &self.0 {
+            DeleteKind::Delete(statement) => statement.get_bindings(),
+            DeleteKind::SoftDelete(statement) => statement.get_bindings(),
+        }
     }
 }
 
@@ -344,7 +366,10 @@ where
     T: Serialize + DeserializeOwned + Model,
 {
     fn get_errors(&self) -> ErrorList {
-        self.0.get_errors()
+        match &self.0 {
+            DeleteKind::Delete(statement) => statement.get_errors(),
+            DeleteKind::SoftDelete(statement) => statement.get_errors(),
+        }
     }
 }
 
@@ -357,13 +382,18 @@ impl<T> ReturnableStandard<T> for DeleteStatementMini<T>
 where
     T: Serialize + DeserializeOwned + Model + Send + Sync,
 {
-    fn set_return_type(mut self, return_type: ReturnType) -> Self {
-        self.0.return_type = Some(return_type);
-        self
+    fn set_return_type(self, return_type: ReturnType) -> Self {
+        match self.0 {
+            DeleteKind::Delete(statement) => statement.set_return_type(return_type).into(),
+            DeleteKind::SoftDelete(statement) => statement.set_return_type(return_type).into(),
+        }
     }
 
     fn get_return_type(&self) -> ReturnType {
-        self.0.return_type.clone().unwrap_or(ReturnType::None)
+        match &self.0 {
+            DeleteKind::Delete(statement) => statement.get_return_type(),
+            DeleteKind::SoftDelete(statement) => statement.get_return_type(),
+        }
     }
 }
 
@@ -372,7 +402,10 @@ where
     T: Serialize + DeserializeOwned + Model,
 {
     fn build(&self) -> String {
-        self.0.build()
+        match &self.0 {
+            DeleteKind::Delete(statement) => statement.build(),
+            DeleteKind::SoftDelete(statement) => statement.build(),
+        }
     }
 }
 
@@ -4,11 +4,27 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
-use std::fmt;
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures::Stream;
+use serde::de::DeserializeOwned;
+use surrealdb::{
+    method::QueryStream,
+    sql::{self, Thing},
+    Action, Connection, Notification, Surreal,
+};
 
 use crate::{
     statements::SelectStatement,
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
+    traits::{
+        instrumentation::{execute, TracedResponse},
+        BindingsList, Buildable, Erroneous, Parametric, Queryable,
+    },
+    Model, SurrealOrmError, SurrealOrmResult,
 };
 
 /// Creates a LIVE SELECT statement builder.
@@ -31,6 +47,129 @@ pub struct LiveSelectStatement {
     select: SelectStatement,
 }
 
+impl LiveSelectStatement {
+    /// Runs the LIVE SELECT statement and returns a stream of typed notifications.
+    ///
+    /// The live query is killed automatically when the returned stream is dropped.
+    ///
+    /// Examples
+    /// ```rust, ignore
+    /// use futures::StreamExt;
+    /// use surreal_orm::{*, statements::{live, select}};
+    ///
+    /// let mut stream = live(select(All).from(Weapon::table()))
+    ///     .stream::<Weapon>(db.clone())
+    ///     .await?;
+    ///
+    /// while let Some(notification) = stream.next().await {
+    ///     match notification? {
+    ///         LiveNotification::Create(weapon) => println!("created {}", weapon.name),
+    ///         LiveNotification::Update(weapon) => println!("updated {}", weapon.name),
+    ///         LiveNotification::Delete(id) => println!("deleted {id}"),
+    ///     }
+    /// }
+    /// ```
+    pub async fn stream<T>(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<LiveStream<T>>
+    where
+        T: Model + DeserializeOwned + Unpin,
+    {
+        let TracedResponse {
+            mut response, span, ..
+        } = execute(self, db).await?;
+
+        let live_stream = response
+            .stream::<Notification<T>>(0)
+            .map_err(SurrealOrmError::QueryRun)
+            .and_then(|stream| {
+                // The result slot of a LIVE statement holds the id of the live query.
+                let query_id = match response
+                    .take::<sql::Value>(0)
+                    .map_err(SurrealOrmError::Deserialization)?
+                {
+                    sql::Value::Uuid(uuid) => Some(uuid.0),
+                    _ => None,
+                };
+
+                Ok(LiveStream {
+                    query_id,
+                    inner: stream,
+                })
+            });
+
+        span.finish(live_stream)
+    }
+}
+
+/// A typed notification received from a live query.
+#[derive(Debug, Clone)]
+pub enum LiveNotification<T> {
+    /// A record was created.
+    Create(T),
+    /// A record was updated.
+    Update(T),
+    /// A record was deleted. Holds the id of the deleted record.
+    Delete(Thing),
+}
+
+/// A stream of typed notifications from a LIVE SELECT statement.
+/// The live query is killed when the stream is dropped. Notifications of an action
+/// other than create, update or delete are returned as `UnsupportedLiveAction` errors.
+#[must_use = "streams do nothing unless you poll them"]
+pub struct LiveStream<T> {
+    query_id: Option<uuid::Uuid>,
+    inner: QueryStream<Notification<T>>,
+}
+
+impl<T> LiveStream<T> {
+    /// The id of the live query. Can be used to kill the query manually.
+    pub fn query_id(&self) -> Option<uuid::Uuid> {
+        self.query_id
+    }
+}
+
+impl<T> fmt::Debug for LiveStream<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LiveStream")
+            .field("query_id", &self.query_id)
+            .finish()
+    }
+}
+
+impl<T> Stream for LiveStream<T>
+where
+    T: Model + DeserializeOwned + Unpin,
+{
+    type Item = SurrealOrmResult<LiveNotification<T>>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.as_mut().get_mut();
+        match Pin::new(&mut this.inner).poll_next(cx) {
+            Poll::Ready(Some(Ok(notification))) => {
+                if this.query_id.is_none() {
+                    this.query_id = Some(notification.query_id);
+                }
+                let data = notification.data;
+                let notification = match notification.action {
+                    Action::Create => Ok(LiveNotification::Create(data)),
+                    Action::Update => Ok(LiveNotification::Update(data)),
+                    // Delete notifications carry the record as it was before deletion.
+                    Action::Delete => Ok(LiveNotification::Delete(data.get_id_as_thing())),
+                    // `Action` is non-exhaustive.
+                    action => Err(SurrealOrmError::UnsupportedLiveAction(format!(
+                        "{action:?}"
+                    ))),
+                };
+                Poll::Ready(Some(notification))
+            }
+            Poll::Ready(Some(Err(error))) => {
+                Poll::Ready(Some(Err(SurrealOrmError::Deserialization(error))))
+            }
+            Poll::Ready(None) => Poll::Ready(None),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
 impl Queryable for LiveSelectStatement {}
 
 impl Erroneous for LiveSelectStatement {}
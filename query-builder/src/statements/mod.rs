@@ -10,6 +10,7 @@ pub(crate) mod break_;
 pub(crate) mod continue_;
 pub(crate) mod create;
 pub(crate) mod create_v2;
+pub(crate) mod define_access;
 pub(crate) mod define_analyzer;
 pub(crate) mod define_database;
 pub(crate) mod define_event;
@@ -32,6 +33,7 @@ pub(crate) mod insert;
 pub(crate) mod let_;
 pub(crate) mod live_select;
 pub(crate) mod relate;
+pub(crate) mod remove_access;
 pub(crate) mod remove_analyzer;
 pub(crate) mod remove_database;
 pub(crate) mod remove_event;
@@ -48,6 +50,7 @@ pub(crate) mod remove_token;
 pub(crate) mod remove_user;
 pub(crate) mod return_;
 pub(crate) mod select;
+pub(crate) mod select_eager;
 pub(crate) mod select_surreal_id_range;
 pub(crate) mod show;
 pub(crate) mod sleep;
@@ -55,6 +58,7 @@ pub(crate) mod subquery;
 pub(crate) mod throw;
 pub(crate) mod transaction;
 pub(crate) mod update;
+pub(crate) mod upsert;
 pub(crate) mod use_;
 pub(crate) mod utils_block;
 pub(crate) mod utils_chain;
@@ -63,6 +67,10 @@ pub use break_::{break_, BreakStatement};
 pub use continue_::{continue_, ContinueStatement};
 pub use create::{create, create_only, CreateStatement};
 pub use create_v2::{create_v2, CreateStatementV2};
+pub use define_access::{
+    define_access, jwt_access, record_access, AccessTarget, AccessType, DefineAccessStatement,
+    JwtAccess, RecordAccess,
+};
 pub use define_analyzer::{
     define_analyzer, AnalyzerFilter, DefineAnalyzerStatement, SnowballLanguage, Tokenizer,
 };
@@ -70,7 +78,10 @@ pub use define_database::{define_database, DefineDatabaseStatement};
 pub use define_event::{define_event, DefineEventStatement};
 pub use define_field::{define_field, DefineFieldStatement};
 pub use define_function::{define_function, DefineFunctionStatement, FunctionArgument};
-pub use define_index::{define_index, search_analyzer, DefineIndexStatement, Scoring};
+pub use define_index::{
+    define_index, hnsw, mtree, search_analyzer, DefineIndexStatement, HnswIndex, MTreeIndex,
+    Scoring, VectorDistance, VectorIndex, VectorType,
+};
 pub use define_model::{define_model, DefineModelStatement, ModelName, ModelVersion};
 pub use define_namespace::{define_namespace, DefineNamespaceStatement};
 pub use define_param::{define_param, DefineParamStatement};
@@ -85,8 +96,9 @@ pub use ifelse::{if_, IfElseStatement};
 pub use info::{info_for, InfoStatement};
 pub use insert::{insert, InsertStatement, Insertables};
 pub use let_::{let_, LetStatement};
-pub use live_select::{live, LiveSelectStatement};
+pub use live_select::{live, LiveNotification, LiveSelectStatement, LiveStream};
 pub use relate::{relate, relate_only, RelateStatement};
+pub use remove_access::{remove_access, RemoveAccessStatement};
 pub use remove_analyzer::{remove_analyzer, RemoveAnalyzerStatement};
 pub use remove_database::{remove_database, RemoveDatabaseStatement};
 pub use remove_event::{remove_event, RemoveEventStatement};
@@ -103,10 +115,11 @@ pub use remove_token::{remove_token, RemoveTokenStatement};
 pub use remove_user::{remove_user, RemoveUserStatement, UserPermissionScope};
 pub use return_::{return_, ReturnStatement};
 pub use select::{
-    order, select, select_diff, select_value, Order, Orderables, SelectStatement,
-    SelectStatementMini, Selectables, Splittables,
+    order, select, select_diff, select_value, CursorPage, Order, Orderables, Page, SelectStatement,
+    SelectStatementCursor, SelectStatementMini, SelectStatementPaginated, Selectables, Splittables,
 };
-pub use show::{show_changes_for_table, ShowChangesStatement};
+pub use select_eager::{eager, EagerLoad};
+pub use show::{show_changes_for_table, ChangeSet, ShowChangesStatement, TableChange};
 pub use sleep::{sleep, SleepStatement};
 pub use subquery::Subquery;
 pub use throw::{throw, ThrowStatement};
@@ -114,6 +127,7 @@ pub use transaction::{
     begin_transaction, transaction_deprecated, BeginTransactionStatement, TransactionCompletion,
 };
 pub use update::{update, update_only, UpdateStatement};
+pub use upsert::{upsert, UpsertStatement};
 pub use use_::{use_, UseStatement};
 
 /// helpers for statements
@@ -137,3 +151,40 @@ impl Display for NamespaceOrDatabase {
         write!(f, "{}", stringified)
     }
 }
+
+/// What a DEFINE statement does when the resource it defines already exists.
+/// Both modes require SurrealDB 2.0 or later.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DefineMode {
+    /// Keeps the existing definition and skips the statement.
+    IfNotExists,
+    /// Replaces the existing definition.
+    Overwrite,
+}
+
+impl Display for DefineMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let stringified = match self {
+            DefineMode::IfNotExists => "IF NOT EXISTS",
+            DefineMode::Overwrite => "OVERWRITE",
+        };
+        write!(f, "{}", stringified)
+    }
+}
+
+/// Renders the keyword of a DEFINE statement e.g `DEFINE TABLE IF NOT EXISTS`
+pub(crate) fn define_keyword(resource: &str, define_mode: Option<DefineMode>) -> String {
+    match define_mode {
+        Some(define_mode) => format!("DEFINE {resource} {define_mode}"),
+        None => format!("DEFINE {resource}"),
+    }
+}
+
+/// Renders the keyword of a REMOVE statement e.g `REMOVE TABLE IF EXISTS`
+pub(crate) fn remove_keyword(resource: &str, if_exists: bool) -> String {
+    if if_exists {
+        format!("REMOVE {resource} IF EXISTS")
+    } else {
+        format!("REMOVE {resource}")
+    }
+}
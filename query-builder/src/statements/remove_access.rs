@@ -0,0 +1,147 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+/*
+REMOVE ACCESS statement
+
+Statement syntax
+REMOVE ACCESS [ IF EXISTS ] @name ON [ NAMESPACE | DATABASE ]
+ * */
+
+use std::fmt::{self, Display};
+
+use super::{remove_keyword, AccessTarget};
+use crate::{Access, BindingsList, Buildable, Erroneous, Parametric, Queryable};
+
+/// Remove access statement. Requires SurrealDB 2.0 or later.
+///
+/// # Arguments
+///
+/// * `access` - The name of the access method to be removed. Can be a string or an Access type.
+///
+/// # Example
+/// ```rust
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::remove_access};
+/// let access = Access::new("account");
+/// let statement = remove_access(access).on_namespace();
+/// assert_eq!(statement.build(), "REMOVE ACCESS account ON NAMESPACE;");
+///
+/// let statement = remove_access("account").if_exists().on_database();
+/// assert_eq!(statement.build(), "REMOVE ACCESS IF EXISTS account ON DATABASE;");
+/// ```
+pub fn remove_access(access: impl Into<Access>) -> RemoveAccessStatementInit {
+    RemoveAccessStatementInit {
+        access: access.into(),
+        on: None,
+        if_exists: false,
+    }
+}
+
+/// Remove access statement
+pub struct RemoveAccessStatementInit {
+    access: Access,
+    on: Option<AccessTarget>,
+    if_exists: bool,
+}
+
+/// Remove access statement
+pub struct RemoveAccessStatement(RemoveAccessStatementInit);
+
+impl RemoveAccessStatement {
+    /// Skips the statement instead of erroring if the access method does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.0.if_exists = true;
+        self
+    }
+}
+
+impl std::ops::Deref for RemoveAccessStatement {
+    type Target = RemoveAccessStatementInit;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<RemoveAccessStatementInit> for RemoveAccessStatement {
+    fn from(init: RemoveAccessStatementInit) -> Self {
+        Self(init)
+    }
+}
+
+impl RemoveAccessStatementInit {
+    /// Specify to remove the access method from namespace
+    pub fn on_namespace(mut self) -> RemoveAccessStatement {
+        self.on = Some(AccessTarget::Namespace);
+        self.into()
+    }
+
+    /// Specify to remove the access method from database
+    pub fn on_database(mut self) -> RemoveAccessStatement {
+        self.on = Some(AccessTarget::Database);
+        self.into()
+    }
+
+    /// Skips the statement instead of erroring if the access method does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
+}
+
+impl Buildable for RemoveAccessStatement {
+    fn build(&self) -> String {
+        let mut query = format!(
+            "{} {}",
+            remove_keyword("ACCESS", self.if_exists),
+            self.access
+        );
+
+        if let Some(on) = &self.on {
+            query = format!("{} ON {}", query, on);
+        }
+        format!("{};", query)
+    }
+}
+
+impl Display for RemoveAccessStatement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl Parametric for RemoveAccessStatement {
+    fn get_bindings(&self) -> BindingsList {
+        vec![]
+    }
+}
+
+impl Erroneous for RemoveAccessStatement {}
+
+impl Queryable for RemoveAccessStatement {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_access_on_namespace() {
+        let statement = remove_access("account").on_namespace();
+        assert_eq!(statement.build(), "REMOVE ACCESS account ON NAMESPACE;");
+    }
+
+    #[test]
+    fn test_remove_access_on_database_if_exists() {
+        let statement = remove_access(Access::new("account"))
+            .on_database()
+            .if_exists();
+        assert_eq!(
+            statement.build(),
+            "REMOVE ACCESS IF EXISTS account ON DATABASE;"
+        );
+    }
+}
@@ -5,6 +5,7 @@
  */
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     Binding,
@@ -25,6 +26,7 @@ pub fn remove_analyzer(name: impl Display) -> RemoveAnalyzerStatement {
         name: name.get_param_dollarised(),
         bindings: vec![name],
         errors: vec![],
+        if_exists: false,
     }
 }
 
@@ -33,6 +35,15 @@ pub struct RemoveAnalyzerStatement {
     name: String,
     bindings: BindingsList,
     errors: crate::ErrorList,
+    if_exists: bool,
+}
+
+impl RemoveAnalyzerStatement {
+    /// Skips the statement instead of erroring if the analyzer does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Queryable for RemoveAnalyzerStatement {}
@@ -51,7 +62,11 @@ impl Parametric for RemoveAnalyzerStatement {
 
 impl Buildable for RemoveAnalyzerStatement {
     fn build(&self) -> String {
-        format!("REMOVE ANALYZER {};", self.name)
+        format!(
+            "{} {};",
+            remove_keyword("ANALYZER", self.if_exists),
+            self.name
+        )
     }
 }
 
@@ -106,4 +121,13 @@ mod tests {
         );
         assert_eq!(statement.get_bindings().len(), 1);
     }
+
+    #[test]
+    fn test_remove_analyzer_if_exists() {
+        let statement = remove_analyzer("analyzer::standard").if_exists();
+        assert_eq!(
+            statement.to_raw().build(),
+            "REMOVE ANALYZER IF EXISTS analyzer::standard;"
+        );
+    }
 }
@@ -25,6 +25,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::Database,
@@ -64,19 +65,31 @@ pub fn remove_database(database: impl Into<Database>) -> RemoveDatabaseStatement
 /// A statement for removing a database.
 pub struct RemoveDatabaseStatement {
     database: Database,
+    if_exists: bool,
 }
 
 impl RemoveDatabaseStatement {
     fn new(database: impl Into<Database>) -> Self {
         Self {
             database: database.into(),
+            if_exists: false,
         }
     }
+
+    /// Skips the statement instead of erroring if the database does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveDatabaseStatement {
     fn build(&self) -> String {
-        format!("REMOVE DATABASE {};", self.database)
+        format!(
+            "{} {};",
+            remove_keyword("DATABASE", self.if_exists),
+            self.database
+        )
     }
 }
 
@@ -107,4 +120,12 @@ mod tests {
             "REMOVE DATABASE oyelowo;"
         );
     }
+
+    #[test]
+    fn test_remove_statement_if_exists() {
+        assert_eq!(
+            remove_database("oyelowo").if_exists().build(),
+            "REMOVE DATABASE IF EXISTS oyelowo;"
+        );
+    }
 }
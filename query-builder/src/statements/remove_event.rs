@@ -23,6 +23,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::{Event, Table},
@@ -48,6 +49,7 @@ pub fn remove_event(event: impl Into<Event>) -> RemoveEventStatement {
     RemoveEventStatement {
         table: None,
         event: event.into(),
+        if_exists: false,
     }
 }
 
@@ -55,6 +57,7 @@ pub fn remove_event(event: impl Into<Event>) -> RemoveEventStatement {
 pub struct RemoveEventStatement {
     event: Event,
     table: Option<Table>,
+    if_exists: bool,
 }
 
 impl RemoveEventStatement {
@@ -76,11 +79,17 @@ impl RemoveEventStatement {
         self.table = Some(table.into());
         self
     }
+
+    /// Skips the statement instead of erroring if the event does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveEventStatement {
     fn build(&self) -> String {
-        let mut query = format!("REMOVE EVENT {}", self.event);
+        let mut query = format!("{} {}", remove_keyword("EVENT", self.if_exists), self.event);
         if let Some(table) = &self.table {
             query = format!("{query} ON TABLE {}", table);
         }
@@ -115,4 +124,16 @@ mod tests {
         let statement = remove_event(party).on_table(user);
         assert_eq!(statement.build(), "REMOVE EVENT party ON TABLE user;");
     }
+
+    #[test]
+    fn test_remove_event_if_exists() {
+        let user = Table::new("user");
+        let party = Event::new("party");
+
+        let statement = remove_event(party).on_table(user).if_exists();
+        assert_eq!(
+            statement.build(),
+            "REMOVE EVENT IF EXISTS party ON TABLE user;"
+        );
+    }
 }
@@ -25,6 +25,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{BindingsList, Buildable, Erroneous, Field, Parametric, Queryable, Table};
 
 /// Remove field statement
@@ -46,6 +47,7 @@ pub fn remove_field(field: impl Into<Field>) -> RemoveFieldStatement {
     RemoveFieldStatement {
         field: field.into(),
         table: None,
+        if_exists: false,
     }
 }
 
@@ -53,6 +55,7 @@ pub fn remove_field(field: impl Into<Field>) -> RemoveFieldStatement {
 pub struct RemoveFieldStatement {
     field: Field,
     table: Option<Table>,
+    if_exists: bool,
 }
 
 impl RemoveFieldStatement {
@@ -73,11 +76,17 @@ impl RemoveFieldStatement {
         self.table = Some(table.into());
         self
     }
+
+    /// Skips the statement instead of erroring if the field does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveFieldStatement {
     fn build(&self) -> String {
-        let mut query = format!("REMOVE FIELD {}", self.field);
+        let mut query = format!("{} {}", remove_keyword("FIELD", self.if_exists), self.field);
         if let Some(table) = &self.table {
             query = format!("{} ON TABLE {}", query, table);
         }
@@ -114,4 +123,16 @@ mod tests {
         let statement = remove_field(name).on_table(user);
         assert_eq!(statement.build(), "REMOVE FIELD name ON TABLE user;");
     }
+
+    #[test]
+    fn test_remove_field_if_exists() {
+        let user = Table::new("user");
+        let name = Field::new("name");
+
+        let statement = remove_field(name).on_table(user).if_exists();
+        assert_eq!(
+            statement.build(),
+            "REMOVE FIELD IF EXISTS name ON TABLE user;"
+        );
+    }
 }
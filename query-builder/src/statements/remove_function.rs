@@ -6,6 +6,7 @@
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     Binding,
@@ -47,6 +48,7 @@ pub fn remove_function(name: impl Into<FunctionName>) -> RemoveFunctionStatement
         name: name.get_param_dollarised(),
         bindings: vec![name],
         errors: vec![],
+        if_exists: false,
     }
 }
 
@@ -55,6 +57,15 @@ pub struct RemoveFunctionStatement {
     name: String,
     bindings: BindingsList,
     errors: crate::ErrorList,
+    if_exists: bool,
+}
+
+impl RemoveFunctionStatement {
+    /// Skips the statement instead of erroring if the function does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Queryable for RemoveFunctionStatement {}
@@ -73,7 +84,11 @@ impl Parametric for RemoveFunctionStatement {
 
 impl Buildable for RemoveFunctionStatement {
     fn build(&self) -> String {
-        format!("REMOVE FUNCTION {};", self.name)
+        format!(
+            "{} {};",
+            remove_keyword("FUNCTION", self.if_exists),
+            self.name
+        )
     }
 }
 
@@ -115,4 +130,13 @@ mod tests {
         );
         assert_eq!(statement.get_bindings().len(), 1);
     }
+
+    #[test]
+    fn test_remove_function_if_exists() {
+        let statement = remove_function("update_author").if_exists();
+        assert_eq!(
+            statement.to_raw().build(),
+            "REMOVE FUNCTION IF EXISTS fn::update_author;"
+        );
+    }
 }
@@ -25,6 +25,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Table, TableIndex};
 
 /// Remove index statement
@@ -46,6 +47,7 @@ pub fn remove_index(index: impl Into<TableIndex>) -> RemoveIndexStatement {
     RemoveIndexStatement {
         index: index.into(),
         table: None,
+        if_exists: false,
     }
 }
 
@@ -53,6 +55,7 @@ pub fn remove_index(index: impl Into<TableIndex>) -> RemoveIndexStatement {
 pub struct RemoveIndexStatement {
     index: TableIndex,
     table: Option<Table>,
+    if_exists: bool,
 }
 
 impl RemoveIndexStatement {
@@ -72,11 +75,17 @@ impl RemoveIndexStatement {
         self.table = Some(table.into());
         self
     }
+
+    /// Skips the statement instead of erroring if the index does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveIndexStatement {
     fn build(&self) -> String {
-        let mut query = format!("REMOVE INDEX {}", self.index);
+        let mut query = format!("{} {}", remove_keyword("INDEX", self.if_exists), self.index);
         if let Some(table) = &self.table {
             query = format!("{} ON TABLE {}", query, table);
         }
@@ -113,4 +122,16 @@ mod tests {
         let statement = remove_index(party).on_table(user);
         assert_eq!(statement.build(), "REMOVE INDEX party ON TABLE user;");
     }
+
+    #[test]
+    fn test_remove_index_if_exists() {
+        let user = Table::new("user");
+        let party = TableIndex::new("party");
+
+        let statement = remove_index(party).on_table(user).if_exists();
+        assert_eq!(
+            statement.build(),
+            "REMOVE INDEX IF EXISTS party ON TABLE user;"
+        );
+    }
 }
@@ -27,7 +27,7 @@ use std::fmt::{self, Display};
 
 use crate::{BindingsList, Buildable, Erroneous, Login, Parametric, Queryable};
 
-use super::NamespaceOrDatabase;
+use super::{remove_keyword, NamespaceOrDatabase};
 
 /// Remove login statement
 ///
@@ -47,12 +47,14 @@ pub fn remove_login(login: impl Into<Login>) -> RemoveLoginStatementInit {
     RemoveLoginStatementInit {
         login: login.into(),
         on: None,
+        if_exists: false,
     }
 }
 
 pub struct RemoveLoginStatementInit {
     login: Login,
     on: Option<NamespaceOrDatabase>,
+    if_exists: bool,
 }
 
 impl RemoveLoginStatementInit {
@@ -85,11 +87,25 @@ impl RemoveLoginStatementInit {
         self.on = Some(NamespaceOrDatabase::Database);
         self.into()
     }
+
+    /// Skips the statement instead of erroring if the login does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 /// Remove login statement
 pub struct RemoveLoginStatement(RemoveLoginStatementInit);
 
+impl RemoveLoginStatement {
+    /// Skips the statement instead of erroring if the login does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.0.if_exists = true;
+        self
+    }
+}
+
 impl From<RemoveLoginStatementInit> for RemoveLoginStatement {
     fn from(init: RemoveLoginStatementInit) -> Self {
         Self(init)
@@ -106,7 +122,7 @@ impl std::ops::Deref for RemoveLoginStatement {
 
 impl Buildable for RemoveLoginStatement {
     fn build(&self) -> String {
-        let mut query = format!("REMOVE LOGIN {}", self.login);
+        let mut query = format!("{} {}", remove_keyword("LOGIN", self.if_exists), self.login);
 
         if let Some(on) = &self.on {
             query = format!("{} ON {}", query, on);
@@ -149,4 +165,14 @@ mod tests {
         let statement = remove_login(login).on_database();
         assert_eq!(statement.build(), "REMOVE LOGIN login ON DATABASE;");
     }
+
+    #[test]
+    fn remove_login_if_exists() {
+        let login = Login::new("login");
+        let statement = remove_login(login).if_exists().on_namespace();
+        assert_eq!(
+            statement.build(),
+            "REMOVE LOGIN IF EXISTS login ON NAMESPACE;"
+        );
+    }
 }
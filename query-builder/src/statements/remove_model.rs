@@ -7,7 +7,10 @@ use std::fmt;
 
 use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable};
 
-use super::define_model::{ModelName, ModelVersion};
+use super::{
+    define_model::{ModelName, ModelVersion},
+    remove_keyword,
+};
 
 /// Creates a REMOVE MODEL statement builder.
 ///
@@ -26,6 +29,7 @@ pub fn remove_model(name: impl Into<ModelName>) -> RemoveModelStatement {
         version: None,
         bindings: name.get_bindings(),
         errors: vec![],
+        if_exists: false,
     }
 }
 
@@ -35,6 +39,7 @@ pub struct RemoveModelStatement {
     version: Option<String>,
     bindings: BindingsList,
     errors: crate::ErrorList,
+    if_exists: bool,
 }
 
 impl RemoveModelStatement {
@@ -45,6 +50,12 @@ impl RemoveModelStatement {
         self.bindings.extend(version.get_bindings());
         self
     }
+
+    /// Skips the statement instead of erroring if the model does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Queryable for RemoveModelStatement {}
@@ -63,7 +74,11 @@ impl Parametric for RemoveModelStatement {
 
 impl Buildable for RemoveModelStatement {
     fn build(&self) -> String {
-        let query = format!("REMOVE MODEL ml::{}", self.name);
+        let query = format!(
+            "{} ml::{}",
+            remove_keyword("MODEL", self.if_exists),
+            self.name
+        );
 
         let query = if let Some(version) = &self.version {
             format!("{query}<{version}>")
@@ -128,4 +143,13 @@ mod tests {
         );
         assert_eq!(statement.get_bindings().len(), 0);
     }
+
+    #[test]
+    fn test_remove_model_if_exists() {
+        let statement = remove_model("recommendation").version("1.0.0").if_exists();
+        assert_eq!(
+            statement.to_raw().build(),
+            "REMOVE MODEL IF EXISTS ml::recommendation<1.0.0>;"
+        );
+    }
 }
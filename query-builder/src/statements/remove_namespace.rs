@@ -25,6 +25,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::Namespace,
@@ -46,18 +47,33 @@ use crate::{
 /// assert_eq!(statement.build(), "REMOVE NAMESPACE namespace;");
 /// ```
 pub fn remove_namespace(namespace: impl Into<Namespace>) -> RemoveNamespaceStatement {
-    let namespace = namespace.into();
-    RemoveNamespaceStatement { namespace }
+    RemoveNamespaceStatement {
+        namespace: namespace.into(),
+        if_exists: false,
+    }
 }
 
 /// Remove namespace statement
 pub struct RemoveNamespaceStatement {
     namespace: Namespace,
+    if_exists: bool,
+}
+
+impl RemoveNamespaceStatement {
+    /// Skips the statement instead of erroring if the namespace does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveNamespaceStatement {
     fn build(&self) -> String {
-        format!("REMOVE NAMESPACE {};", self.namespace)
+        format!(
+            "{} {};",
+            remove_keyword("NAMESPACE", self.if_exists),
+            self.namespace
+        )
     }
 }
 
@@ -87,4 +103,11 @@ mod tests {
         let statement = remove_namespace(namespace);
         assert_eq!(statement.build(), "REMOVE NAMESPACE namespace;");
     }
+
+    #[test]
+    fn test_remove_namespace_if_exists() {
+        let namespace = Namespace::new("namespace");
+        let statement = remove_namespace(namespace).if_exists();
+        assert_eq!(statement.build(), "REMOVE NAMESPACE IF EXISTS namespace;");
+    }
 }
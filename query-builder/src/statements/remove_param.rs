@@ -5,6 +5,7 @@
  */
 use std::fmt::{self};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     Param,
@@ -25,6 +26,7 @@ pub fn remove_param(name: impl Into<Param>) -> RemoveParamStatement {
         name: name.to_string(),
         bindings: vec![],
         errors: vec![],
+        if_exists: false,
     }
 }
 
@@ -33,6 +35,15 @@ pub struct RemoveParamStatement {
     name: String,
     bindings: BindingsList,
     errors: crate::ErrorList,
+    if_exists: bool,
+}
+
+impl RemoveParamStatement {
+    /// Skips the statement instead of erroring if the param does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Queryable for RemoveParamStatement {}
@@ -51,7 +62,7 @@ impl Parametric for RemoveParamStatement {
 
 impl Buildable for RemoveParamStatement {
     fn build(&self) -> String {
-        format!("REMOVE PARAM {};", self.name)
+        format!("{} {};", remove_keyword("PARAM", self.if_exists), self.name)
     }
 }
 
@@ -85,4 +96,13 @@ mod tests {
         );
         assert_eq!(statement.get_bindings().len(), 0);
     }
+
+    #[test]
+    fn test_remove_param_if_exists() {
+        let statement = remove_param(Param::new("some_param")).if_exists();
+        assert_eq!(
+            statement.to_raw().build(),
+            "REMOVE PARAM IF EXISTS $some_param;"
+        );
+    }
 }
@@ -26,6 +26,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{
     traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
     types::Scope,
@@ -52,14 +53,22 @@ pub fn remove_scope(scope: impl Into<Scope>) -> RemoveScopeStatement {
 /// Remove scope statement
 pub struct RemoveScopeStatement {
     scope: Scope,
+    if_exists: bool,
 }
 
 impl RemoveScopeStatement {
     fn new(scope: impl Into<Scope>) -> Self {
         Self {
             scope: scope.into(),
+            if_exists: false,
         }
     }
+
+    /// Skips the statement instead of erroring if the scope does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Queryable for RemoveScopeStatement {}
@@ -73,7 +82,11 @@ impl Parametric for RemoveScopeStatement {
 
 impl Buildable for RemoveScopeStatement {
     fn build(&self) -> String {
-        format!("REMOVE SCOPE {};", self.scope)
+        format!(
+            "{} {};",
+            remove_keyword("SCOPE", self.if_exists),
+            self.scope
+        )
     }
 }
 
@@ -94,4 +107,11 @@ mod tests {
         let statement = remove_scope(scope);
         assert_eq!(statement.build(), "REMOVE SCOPE scope;");
     }
+
+    #[test]
+    fn test_remove_scope_if_exists() {
+        let scope = Scope::new("scope");
+        let statement = remove_scope(scope).if_exists();
+        assert_eq!(statement.build(), "REMOVE SCOPE IF EXISTS scope;");
+    }
 }
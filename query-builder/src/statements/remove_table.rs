@@ -23,6 +23,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Table};
 
 /// Remove table statement
@@ -42,17 +43,31 @@ use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Table};
 pub fn remove_table(table: impl Into<Table>) -> RemoveTableStatement {
     RemoveTableStatement {
         table: table.into(),
+        if_exists: false,
     }
 }
 
 /// Remove table statement
 pub struct RemoveTableStatement {
     table: Table,
+    if_exists: bool,
+}
+
+impl RemoveTableStatement {
+    /// Skips the statement instead of erroring if the table does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveTableStatement {
     fn build(&self) -> String {
-        format!("REMOVE TABLE {};", self.table)
+        format!(
+            "{} {};",
+            remove_keyword("TABLE", self.if_exists),
+            self.table
+        )
     }
 }
 
@@ -82,4 +97,11 @@ mod tests {
         let statement = remove_table(table);
         assert_eq!(statement.build(), "REMOVE TABLE table;");
     }
+
+    #[test]
+    fn test_remove_table_if_exists() {
+        let table = Table::new("table");
+        let statement = remove_table(table).if_exists();
+        assert_eq!(statement.build(), "REMOVE TABLE IF EXISTS table;");
+    }
 }
@@ -23,6 +23,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, Scope, Token, TokenTarget};
 
 /// Remove token statement
@@ -48,6 +49,7 @@ pub fn remove_token(token: impl Into<Token>) -> RemoveTokenStatementInit {
         token: token.into(),
         on: None,
         // bindings: vec![],
+        if_exists: false,
     }
 }
 
@@ -56,11 +58,20 @@ pub struct RemoveTokenStatementInit {
     token: Token,
     on: Option<TokenTarget>,
     // bindings: BindingsList,
+    if_exists: bool,
 }
 
 /// Remove token statement
 pub struct RemoveTokenStatement(RemoveTokenStatementInit);
 
+impl RemoveTokenStatement {
+    /// Skips the statement instead of erroring if the token does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.0.if_exists = true;
+        self
+    }
+}
+
 impl std::ops::Deref for RemoveTokenStatement {
     type Target = RemoveTokenStatementInit;
 
@@ -96,11 +107,17 @@ impl RemoveTokenStatementInit {
         self.on = Some(TokenTarget::Scope(scope_name.into().into()));
         self.into()
     }
+
+    /// Skips the statement instead of erroring if the token does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveTokenStatement {
     fn build(&self) -> String {
-        let mut query = format!("REMOVE TOKEN {}", self.token);
+        let mut query = format!("{} {}", remove_keyword("TOKEN", self.if_exists), self.token);
 
         if let Some(on) = &self.on {
             query = format!("{} ON {}", query, on);
@@ -141,4 +158,19 @@ mod test {
         let statement = remove_token(login).on_database();
         assert_eq!(statement.build(), "REMOVE TOKEN login ON DATABASE;");
     }
+
+    #[test]
+    fn remove_token_on_scope() {
+        let statement = remove_token("token").on_scope("account");
+        assert_eq!(statement.build(), "REMOVE TOKEN token ON SCOPE account;");
+    }
+
+    #[test]
+    fn remove_token_if_exists() {
+        let statement = remove_token("token").if_exists().on_database();
+        assert_eq!(
+            statement.build(),
+            "REMOVE TOKEN IF EXISTS token ON DATABASE;"
+        );
+    }
 }
@@ -26,6 +26,7 @@ REMOVE [
 
 use std::fmt::{self, Display};
 
+use super::remove_keyword;
 use crate::{BindingsList, Buildable, Erroneous, Parametric, Queryable, User};
 
 /// Remove user statement
@@ -48,6 +49,7 @@ pub fn remove_user(user: impl Into<User>) -> RemoveUserStatementInit {
     RemoveUserStatementInit {
         user: user.into(),
         on: None,
+        if_exists: false,
     }
 }
 
@@ -76,11 +78,20 @@ impl Display for UserPermissionScope {
 pub struct RemoveUserStatementInit {
     user: User,
     on: Option<UserPermissionScope>,
+    if_exists: bool,
 }
 
 /// Remove User statement
 pub struct RemoveUserStatement(RemoveUserStatementInit);
 
+impl RemoveUserStatement {
+    /// Skips the statement instead of erroring if the user does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.0.if_exists = true;
+        self
+    }
+}
+
 impl std::ops::Deref for RemoveUserStatement {
     type Target = RemoveUserStatementInit;
 
@@ -113,11 +124,17 @@ impl RemoveUserStatementInit {
         self.on = Some(UserPermissionScope::Database);
         self.into()
     }
+
+    /// Skips the statement instead of erroring if the user does not exist.
+    pub fn if_exists(mut self) -> Self {
+        self.if_exists = true;
+        self
+    }
 }
 
 impl Buildable for RemoveUserStatement {
     fn build(&self) -> String {
-        let mut query = format!("REMOVE USER {}", self.user);
+        let mut query = format!("{} {}", remove_keyword("USER", self.if_exists), self.user);
 
         if let Some(on) = &self.on {
             query = format!("{} ON {}", query, on);
@@ -158,4 +175,19 @@ mod test {
         let statement = remove_user(login).on_database();
         assert_eq!(statement.build(), "REMOVE USER login ON DATABASE;");
     }
+
+    #[test]
+    fn remove_user_on_root() {
+        let statement = remove_user("oyelowo").on_root();
+        assert_eq!(statement.build(), "REMOVE USER oyelowo ON ROOT;");
+    }
+
+    #[test]
+    fn remove_user_if_exists() {
+        let statement = remove_user("oyelowo").on_database().if_exists();
+        assert_eq!(
+            statement.build(),
+            "REMOVE USER IF EXISTS oyelowo ON DATABASE;"
+        );
+    }
 }
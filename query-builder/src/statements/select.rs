@@ -31,16 +31,20 @@ use std::{
 };
 
 use serde::{de::DeserializeOwned, Serialize};
-use surrealdb::{engine::local::Db, sql, Surreal};
+use surrealdb::{sql, Connection, Surreal};
 
 use crate::{
-    Aliasable, All, Binding, BindingsList, Buildable, Conditional, DurationLike, Erroneous,
-    ErrorList, Field, Filter, Function, IndexName, Model, NumberLike, Parametric, Queryable,
-    ReturnableSelect, ReturnableStandard, SurrealId, SurrealOrmResult, SurrealSimpleId,
-    SurrealUlid, SurrealUuid, Table, ToRaw, ValueLike,
+    cond, functions::vector, Aliasable, All, ArrayLike, Binding, BindingsList, Buildable,
+    Conditional, DurationLike, Erroneous, ErrorList, Field, Filter, Function, IndexName, Model,
+    NumberLike, Operatable, Operation, Parametric, Queryable, ReturnableSelect, ReturnableStandard,
+    Runnable, SurrealId, SurrealOrmError, SurrealOrmResult, SurrealSimpleId, SurrealUlid,
+    SurrealUuid, Table, ToRaw, ValueLike, NONE,
 };
 
-use super::Subquery;
+use super::{
+    select_eager::{EagerLoad, EagerLoads},
+    Subquery, VectorDistance,
+};
 
 /// Creates a new `Order` instance with the specified database field.
 ///
@@ -117,6 +121,27 @@ impl Parametric for Orderables {
     }
 }
 
+impl Erroneous for Order {
+    fn get_errors(&self) -> ErrorList {
+        self.field.get_errors()
+    }
+}
+
+impl Erroneous for Vec<Order> {
+    fn get_errors(&self) -> ErrorList {
+        self.iter().flat_map(|o| o.get_errors()).collect::<Vec<_>>()
+    }
+}
+
+impl Erroneous for Orderables {
+    fn get_errors(&self) -> ErrorList {
+        match self {
+            Orderables::Order(o) => o.get_errors(),
+            Orderables::OrdersList(ol) => ol.get_errors(),
+        }
+    }
+}
+
 /// an order or list of orders
 pub enum Orderables {
     /// Single order
@@ -828,6 +853,7 @@ pub struct SelectStatementInit {
     timeout: Option<String>,
     parallel: bool,
     explain_mode: Option<ExplainMode>,
+    eager_loads: EagerLoads,
     bindings: BindingsList,
     errors: ErrorList,
 }
@@ -968,6 +994,7 @@ pub fn select(selectables: impl Into<Selectables>) -> SelectStatementInit {
         omitted_fields: vec![],
         timeout: None,
         parallel: false,
+        eager_loads: EagerLoads::default(),
         bindings: selectables.get_bindings(),
         errors: selectables.get_errors(),
     }
@@ -1486,6 +1513,45 @@ impl SelectStatement {
         self
     }
 
+    /// Eagerly loads the records of a link path into the links e.g `LinkOne`, `LinkMany`
+    /// and `LinkSelf` fields, decoding them as `Reference::FetchedValue`. Unlike `fetch`,
+    /// the loaded records can be filtered, ordered and limited and the path can be
+    /// multiple levels deep. Returns `SurrealOrmError::DanglingLinks` when running the
+    /// statement if a loaded link references a record that does not exist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::{*, statements::{eager, select}};
+    /// # let alien = Table::new("alien");
+    /// # let weapon = Field::new("weapon");
+    /// # let name = Field::new("name");
+    /// let statement = select(All)
+    ///     .from(alien)
+    ///     .with(weapon)
+    ///     .with(eager(Field::new("ally.spaceShips")).order_by(name.desc()).limit(5));
+    ///
+    /// assert!(statement.to_raw().build().contains("(SELECT * FROM ONLY $parent.weapon) END) AS weapon"));
+    /// ```
+    /// ```rust, ignore
+    /// // Using the link methods of the schema
+    /// select(All)
+    ///     .from(Alien::table())
+    ///     .with(Alien::schema().ally().spaceShips(cond(ship.name.like("Falcon"))))
+    ///     .return_many::<Alien>(db.clone())
+    ///     .await?;
+    /// ```
+    pub fn with(mut self, link_path: impl Into<EagerLoad>) -> Self {
+        let load: EagerLoad = link_path.into();
+        self.0.bindings.extend(load.get_bindings());
+        self.0.errors.extend(load.get_errors());
+        if let Err(error) = self.0.eager_loads.add(load) {
+            self.0.errors.push(error);
+        }
+        self
+    }
+
     /// Sets the timeout duration for the query.
     ///
     /// # Arguments
@@ -1529,6 +1595,174 @@ impl SelectStatement {
         self
     }
 
+    /// Gets the given page of the results along with the total count of records,
+    /// which is useful for offset based pagination. Pages start at 1.
+    /// The total is counted with the same targets and condition, ignoring ordering and limits.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// let user::Schema { age, .. } = User::schema();
+    /// let page: Page<User> = select(All)
+    ///     .from(User::table())
+    ///     .where_(age.gt(18))
+    ///     .order_by(age.asc())
+    ///     .paginate(2, 20)
+    ///     .get(db.clone())
+    ///     .await?;
+    ///
+    /// assert!(page.has_prev());
+    /// ```
+    pub fn paginate<T>(self, page: u64, per_page: u64) -> SelectStatementPaginated<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let page = page.max(1);
+        let mut counted = self.0.clone();
+        counted.selection_type = SelectionType::Select;
+        counted.projections = "count()".to_string();
+        counted.group_by = vec![];
+        counted.group_all = true;
+        counted.order_by = vec![];
+        counted.limit = None;
+        counted.start = None;
+        counted.fetch = vec![];
+        counted.eager_loads = EagerLoads::default();
+        counted.omitted_fields = vec![];
+        counted.explain_mode = None;
+        let count = select_value(Field::new("count"))
+            .from(SelectStatement(counted))
+            .into();
+
+        let items = self
+            .start(page.saturating_sub(1).saturating_mul(per_page))
+            .limit(per_page);
+
+        SelectStatementPaginated {
+            items,
+            count,
+            page,
+            per_page,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Gets the records following the cursor, which is useful for keyset pagination
+    /// as it keeps its place when records are added or removed before it.
+    /// The records are paginated by all the ordering fields with the record id breaking ties,
+    /// so the statement must be ordered by a field. Pass no cursor to get the first page,
+    /// then the `next_cursor` of the returned page to get the next one.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// let user::Schema { created_at, .. } = User::schema();
+    /// let first_page: CursorPage<User> = select(All)
+    ///     .from(User::table())
+    ///     .order_by(created_at.desc())
+    ///     .after(None, 20)
+    ///     .get(db.clone())
+    ///     .await?;
+    ///
+    /// let second_page: CursorPage<User> = select(All)
+    ///     .from(User::table())
+    ///     .order_by(created_at.desc())
+    ///     .after(first_page.next_cursor.as_deref(), 20)
+    ///     .get(db.clone())
+    ///     .await?;
+    /// ```
+    pub fn after<T>(mut self, cursor: Option<&str>, per_page: u64) -> SelectStatementCursor<T>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let id = Field::new("id");
+        let Some(direction) = self.0.order_by.first().map(|order| order.direction) else {
+            self.0
+                .errors
+                .push("Cursor pagination requires ordering by a field".to_string());
+            return SelectStatementCursor {
+                statement: self,
+                order_fields: vec![id],
+                per_page,
+                _phantom: PhantomData,
+            };
+        };
+
+        // The ordering fields up to the id, which is unique, make up the position of a record
+        let id_position = self
+            .0
+            .order_by
+            .iter()
+            .position(|order| order.field.build() == id.build())
+            .unwrap_or_else(|| {
+                self.0.order_by.push(Order {
+                    field: id.clone(),
+                    direction,
+                    option: None,
+                });
+                self.0.order_by.len() - 1
+            });
+        let keys = self.0.order_by[..=id_position].to_vec();
+
+        if let Some(cursor) = cursor {
+            match decode_cursor(cursor).filter(|values| values.len() == keys.len()) {
+                Some(values) => {
+                    let values = values.into_iter().map(Binding::new).collect::<Vec<_>>();
+                    // e.g (a > $a) OR ((a = $a) AND (b < $b)) OR ((a = $a) AND (b = $b) AND (id > $id))
+                    let after_cursor = keys
+                        .iter()
+                        .zip(&values)
+                        .enumerate()
+                        .map(|(i, (key, value))| {
+                            let operator = match key.direction {
+                                Some(OrderDirection::Desc) => "<",
+                                _ => ">",
+                            };
+                            let mut conditions = keys[..i]
+                                .iter()
+                                .zip(&values)
+                                .map(|(previous, value)| {
+                                    format!(
+                                        "({} = {})",
+                                        previous.field.build(),
+                                        value.get_param_dollarised()
+                                    )
+                                })
+                                .collect::<Vec<_>>();
+                            conditions.push(format!(
+                                "({} {operator} {})",
+                                key.field.build(),
+                                value.get_param_dollarised()
+                            ));
+                            match conditions.as_slice() {
+                                [condition] => condition.clone(),
+                                conditions => format!("({})", conditions.join(" AND ")),
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" OR ");
+                    self.0.where_ = Some(match self.0.where_.take() {
+                        Some(condition) => format!("({condition}) AND ({after_cursor})"),
+                        None => after_cursor,
+                    });
+                    self.update_bindings(values);
+                }
+                None => self.0.errors.push(format!("Invalid cursor: {cursor}")),
+            }
+        }
+
+        // Gets one more record to know if there is a next page
+        self.0.start = None;
+        self.0.limit = Some(per_page.saturating_add(1).to_string());
+
+        SelectStatementCursor {
+            statement: self,
+            order_fields: keys.into_iter().map(|key| key.field).collect(),
+            per_page,
+            _phantom: PhantomData,
+        }
+    }
+
     fn update_bindings(&mut self, bindings: BindingsList) -> &mut Self {
         // let mut updated_params = vec![];
         // updated_params.extend(self.________params_accumulator.to_vec());
@@ -1559,10 +1793,20 @@ impl Buildable for SelectStatement {
             "".to_string()
         };
 
+        let projections = if statement.eager_loads.is_empty() {
+            statement.projections.clone()
+        } else {
+            format!(
+                "{}, {}",
+                statement.projections,
+                statement.eager_loads.build()
+            )
+        };
+
         let only = if statement.only { "ONLY " } else { "" };
         let mut query = format!(
             "{select} {} {}FROM {}{}",
-            statement.projections,
+            projections,
             omitted_fields,
             only,
             statement.targets.join(", ")
@@ -1642,8 +1886,18 @@ impl Buildable for SelectStatement {
 }
 
 /// A mini version of the select statement used as Model convenience method for building select statements.
+/// Soft deleted records of the model are excluded unless `with_deleted` or `only_deleted` is used.
 #[derive(Debug, Clone)]
-pub struct SelectStatementMini<T: Model>(SelectStatement, PhantomData<T>);
+pub struct SelectStatementMini<T: Model>(SelectStatement, SoftDeleted, PhantomData<T>);
+
+/// Which soft deleted records of a model are selected
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum SoftDeleted {
+    #[default]
+    Excluded,
+    Included,
+    Only,
+}
 
 impl<T: Model> SelectStatementMini<T> {
     /// Order the results by the given fields
@@ -1677,6 +1931,62 @@ impl<T: Model> SelectStatementMini<T> {
         self.0 .0.parallel = true;
         self
     }
+
+    /// Includes the soft deleted records of the model
+    pub fn with_deleted(mut self) -> Self {
+        self.1 = SoftDeleted::Included;
+        self
+    }
+
+    /// Selects only the soft deleted records of the model
+    pub fn only_deleted(mut self) -> Self {
+        self.1 = SoftDeleted::Only;
+        self
+    }
+
+    /// The select statement filtered by the soft delete field of the model
+    fn statement(&self) -> SelectStatement {
+        let mut statement = self.0.clone();
+
+        let soft_deleted = match (T::soft_delete_field(), self.1) {
+            (Some(deleted_at), SoftDeleted::Excluded) => deleted_at.is(NONE),
+            (Some(deleted_at), SoftDeleted::Only) => deleted_at.is_not(NONE),
+            (None, SoftDeleted::Only) => {
+                statement.0.errors.push(format!(
+                    "Unable to select only the soft deleted records of `{}` which is not soft deleted. \
+                    Use the `soft_delete` attribute on the model.",
+                    T::table()
+                ));
+                return statement;
+            }
+            (_, SoftDeleted::Included) | (None, SoftDeleted::Excluded) => return statement,
+        };
+
+        statement.update_bindings(soft_deleted.get_bindings());
+        let soft_deleted = soft_deleted.build();
+        statement.0.where_ = Some(match statement.0.where_.take() {
+            Some(condition) => format!("({condition}) AND ({soft_deleted})"),
+            None => soft_deleted,
+        });
+        statement
+    }
+}
+
+impl<T> SelectStatementMini<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    /// Gets the given page of the results along with the total count of records.
+    /// See [`SelectStatement::paginate`].
+    pub fn paginate(self, page: u64, per_page: u64) -> SelectStatementPaginated<T> {
+        self.statement().paginate(page, per_page)
+    }
+
+    /// Gets the records following the cursor in the order of the first ordering field.
+    /// See [`SelectStatement::after`].
+    pub fn after(self, cursor: Option<&str>, per_page: u64) -> SelectStatementCursor<T> {
+        self.statement().after(cursor, per_page)
+    }
 }
 
 impl<T> From<SelectStatement> for SelectStatementMini<T>
@@ -1684,18 +1994,25 @@ where
     T: Serialize + DeserializeOwned + Model,
 {
     fn from(value: SelectStatement) -> Self {
-        Self(value, PhantomData)
+        Self(value, SoftDeleted::default(), PhantomData)
     }
 }
 
-impl<T> Erroneous for SelectStatementMini<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> Erroneous for SelectStatementMini<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn get_errors(&self) -> ErrorList {
+        self.statement().get_errors()
+    }
+}
 
 impl<T> Parametric for SelectStatementMini<T>
 where
     T: Serialize + DeserializeOwned + Model,
 {
     fn get_bindings(&self) -> crate::BindingsList {
-        self.0.get_bindings()
+        self.statement().get_bindings()
     }
 }
 impl<T> Buildable for SelectStatementMini<T>
@@ -1703,7 +2020,7 @@ where
     T: Serialize + DeserializeOwned + Model,
 {
     fn build(&self) -> String {
-        self.0.build()
+        self.statement().build()
     }
 }
 
@@ -1725,13 +2042,149 @@ where
     }
 }
 
+/// The alias of the distance of the records to the vector in a nearest neighbour search
+const NEAREST_DISTANCE: &str = "nearest_distance";
+
+/// A nearest neighbour search built with `SurrealCrud::nearest`, getting the records nearest
+/// to a vector along with their distance to it, nearest first.
+/// Soft deleted records of the model are excluded.
+#[derive(Debug, Clone)]
+pub struct SelectStatementNearest<T: Model> {
+    field: Field,
+    vector: ArrayLike,
+    k: u32,
+    distance: VectorDistance,
+    where_: Option<Filter>,
+    _model: PhantomData<T>,
+}
+
+impl<T: Model> SelectStatementNearest<T> {
+    pub(crate) fn new(field: Field, vector: ArrayLike, k: u32) -> Self {
+        Self {
+            field,
+            vector,
+            k,
+            distance: VectorDistance::Euclidean,
+            where_: None,
+            _model: PhantomData,
+        }
+    }
+
+    /// The distance used to compute the distance of the records to the vector.
+    /// Should match the distance of the vector index of the field. Defaults to euclidean.
+    pub fn distance(mut self, distance: VectorDistance) -> Self {
+        self.distance = distance;
+        self
+    }
+
+    /// Filters the nearest records. Note that the filter is applied to the `k` nearest
+    /// records, so fewer than `k` records may be returned.
+    pub fn where_(mut self, condition: impl Conditional) -> Self {
+        self.where_ = Some(Filter::new(condition));
+        self
+    }
+
+    fn statement(&self) -> SelectStatement {
+        let field = self.field.clone();
+        let vector = self.vector.clone();
+        let distance = match self.distance {
+            VectorDistance::Chebyshev => {
+                vector::distance_chebyshev_fn(field, vector).__as__(NEAREST_DISTANCE)
+            }
+            VectorDistance::Euclidean => {
+                vector::distance_euclidean_fn(field, vector).__as__(NEAREST_DISTANCE)
+            }
+            VectorDistance::Hamming => {
+                vector::distance_hamming_fn(field, vector).__as__(NEAREST_DISTANCE)
+            }
+            VectorDistance::Manhattan => {
+                vector::distance_manhattan_fn(field, vector).__as__(NEAREST_DISTANCE)
+            }
+            VectorDistance::Cosine => {
+                let similarity = vector::similarity_cosine_fn(field, vector);
+                Operation {
+                    query_string: format!("1 - {}", similarity.build()),
+                    bindings: similarity.get_bindings(),
+                    errors: similarity.get_errors(),
+                }
+                .__as__(NEAREST_DISTANCE)
+            }
+        };
+
+        let nearest = self.field.knn(self.k, self.vector.clone());
+        let condition = match &self.where_ {
+            Some(filter) => cond(nearest).and(filter.clone()),
+            None => Filter::new(nearest),
+        };
+
+        let statement = select(vec![ValueLike::from(All), ValueLike::from(distance)])
+            .from(T::table())
+            .where_(condition)
+            .order_by(Field::new(NEAREST_DISTANCE).asc());
+        SelectStatementMini::<T>(statement, SoftDeleted::default(), PhantomData).statement()
+    }
+}
+
+impl<T> SelectStatementNearest<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    /// Gets the nearest records along with their distance to the vector, nearest first.
+    pub async fn get(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Vec<(T, f64)>> {
+        let mut response = self.statement().run(db).await?;
+        let records = match response
+            .take::<sql::Value>(0)
+            .map_err(SurrealOrmError::Deserialization)?
+        {
+            sql::Value::Array(records) => records.0,
+            sql::Value::None | sql::Value::Null => vec![],
+            record => vec![record],
+        };
+
+        records
+            .into_iter()
+            .map(|mut record| {
+                let distance = match &mut record {
+                    sql::Value::Object(object) => object.remove(NEAREST_DISTANCE),
+                    _ => None,
+                };
+                let distance = sql::from_value::<f64>(distance.unwrap_or_default())
+                    .map_err(|e| SurrealOrmError::Deserialization(e.into()))?;
+                let record = sql::from_value::<T>(record)
+                    .map_err(|e| SurrealOrmError::Deserialization(e.into()))?;
+                Ok((record, distance))
+            })
+            .collect()
+    }
+}
+
+impl<T: Model> Erroneous for SelectStatementNearest<T> {
+    fn get_errors(&self) -> ErrorList {
+        self.statement().get_errors()
+    }
+}
+
+impl<T: Model> Parametric for SelectStatementNearest<T> {
+    fn get_bindings(&self) -> crate::BindingsList {
+        self.statement().get_bindings()
+    }
+}
+
+impl<T: Model> Buildable for SelectStatementNearest<T> {
+    fn build(&self) -> String {
+        self.statement().build()
+    }
+}
+
+impl<T: Model> Queryable for SelectStatementNearest<T> {}
+
 #[derive(Debug, Clone)]
 pub struct SelectStatementCount(SelectStatement);
 
 impl SelectStatementCount {
     // /// Gets count of the records that would be returned by the select statement.
     // /// Defaults to zero if there is no result or query is invalid.
-    pub async fn get(&self, db: Surreal<Db>) -> SurrealOrmResult<usize> {
+    pub async fn get(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<usize> {
         Ok(self.0.return_one(db).await?.unwrap_or_default())
     }
 }
@@ -1760,11 +2213,256 @@ impl From<SelectStatement> for SelectStatementCount {
     }
 }
 
+/// A page of records returned by offset based pagination.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    /// The records in the page
+    pub items: Vec<T>,
+    /// The total count of records across all pages
+    pub total: usize,
+    /// The page number, starting at 1
+    pub page: u64,
+    /// The maximum number of records in a page
+    pub per_page: u64,
+}
+
+impl<T> Page<T> {
+    /// The number of pages needed for all the records
+    pub fn total_pages(&self) -> u64 {
+        if self.per_page == 0 {
+            return 0;
+        }
+        (self.total as u64).div_ceil(self.per_page)
+    }
+
+    /// Whether there are records after this page
+    pub fn has_next(&self) -> bool {
+        self.page < self.total_pages()
+    }
+
+    /// Whether there are records before this page
+    pub fn has_prev(&self) -> bool {
+        self.page > 1
+    }
+}
+
+/// Select statement for getting a page of records along with their total count.
+#[derive(Debug, Clone)]
+pub struct SelectStatementPaginated<T> {
+    items: SelectStatement,
+    count: SelectStatementCount,
+    page: u64,
+    per_page: u64,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> SelectStatementPaginated<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    /// Gets the page of records and the total count of records.
+    pub async fn get(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Page<T>> {
+        let items = self.items.return_many::<T>(db.clone()).await?;
+        let total = self.count.get(db).await?;
+
+        Ok(Page {
+            items,
+            total,
+            page: self.page,
+            per_page: self.per_page,
+        })
+    }
+
+    /// The statement getting the records in the page
+    pub fn items_statement(&self) -> &SelectStatement {
+        &self.items
+    }
+
+    /// The statement counting all the records
+    pub fn count_statement(&self) -> &SelectStatementCount {
+        &self.count
+    }
+}
+
+/// A page of records returned by cursor based pagination.
+#[derive(Debug, Clone)]
+pub struct CursorPage<T> {
+    /// The records in the page
+    pub items: Vec<T>,
+    /// Opaque cursor to pass to `after` for getting the next page.
+    /// None if this is the last page.
+    pub next_cursor: Option<String>,
+}
+
+impl<T> CursorPage<T> {
+    /// Whether there are records after this page
+    pub fn has_next(&self) -> bool {
+        self.next_cursor.is_some()
+    }
+}
+
+/// Select statement for getting the records following a cursor.
+#[derive(Debug, Clone)]
+pub struct SelectStatementCursor<T> {
+    statement: SelectStatement,
+    order_fields: Vec<Field>,
+    per_page: u64,
+    _phantom: PhantomData<T>,
+}
+
+impl<T> Erroneous for SelectStatementCursor<T> {
+    fn get_errors(&self) -> ErrorList {
+        self.statement.get_errors()
+    }
+}
+
+impl<T> Parametric for SelectStatementCursor<T> {
+    fn get_bindings(&self) -> BindingsList {
+        self.statement.get_bindings()
+    }
+}
+
+impl<T> Buildable for SelectStatementCursor<T> {
+    fn build(&self) -> String {
+        self.statement.build()
+    }
+}
+
+impl<T> SelectStatementCursor<T>
+where
+    T: Serialize + DeserializeOwned,
+{
+    /// Gets the page of records following the cursor and the cursor for the next page.
+    pub async fn get(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<CursorPage<T>> {
+        let mut response = self.statement.run(db).await?;
+        let mut records = match response
+            .take::<sql::Value>(0)
+            .map_err(SurrealOrmError::Deserialization)?
+        {
+            sql::Value::Array(records) => records.0,
+            sql::Value::None | sql::Value::Null => vec![],
+            record => vec![record],
+        };
+
+        let has_next = records.len() as u64 > self.per_page;
+        records.truncate(self.per_page as usize);
+
+        let next_cursor = match records.last() {
+            Some(last) if has_next => {
+                let values = self
+                    .order_fields
+                    .iter()
+                    .map(|field| {
+                        sql::idiom(&field.build())
+                            .map(|field| last.pick(&field))
+                            .map_err(|e| {
+                                SurrealOrmError::QueryBuilder(format!(
+                                    "Invalid ordering field. {e}"
+                                ))
+                            })
+                    })
+                    .collect::<SurrealOrmResult<Vec<_>>>()?;
+                Some(encode_cursor(values))
+            }
+            _ => None,
+        };
+
+        let items = sql::from_value::<Vec<T>>(sql::Value::from(records))
+            .map_err(|e| SurrealOrmError::Deserialization(e.into()))?;
+
+        Ok(CursorPage { items, next_cursor })
+    }
+}
+
+// The cursor is the ordering field values and the id of the last record, hex encoded
+// so it can be passed around e.g in urls. It is not encrypted, so anyone holding it can read it.
+fn encode_cursor(values: Vec<sql::Value>) -> String {
+    sql::Value::from(values)
+        .to_string()
+        .bytes()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+// The cursor comes from the client and its values are bound as parameters which the database
+// computes, so only literal values and a record id are accepted e.g not `[(DELETE user), 1]`.
+fn decode_cursor(cursor: &str) -> Option<Vec<sql::Value>> {
+    if !cursor.len().is_multiple_of(2) {
+        return None;
+    }
+    let bytes = (0..cursor.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(cursor.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let value = sql::value(&String::from_utf8(bytes).ok()?).ok()?;
+
+    match value {
+        sql::Value::Array(values) => match values.0.as_slice() {
+            [.., sql::Value::Thing(_)] if values.iter().all(is_literal) => Some(values.0),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn is_literal(value: &sql::Value) -> bool {
+    match value {
+        sql::Value::None
+        | sql::Value::Null
+        | sql::Value::Bool(_)
+        | sql::Value::Bytes(_)
+        | sql::Value::Uuid(_)
+        | sql::Value::Number(_)
+        | sql::Value::Strand(_)
+        | sql::Value::Duration(_)
+        | sql::Value::Datetime(_)
+        | sql::Value::Geometry(_) => true,
+        sql::Value::Array(values) => values.iter().all(is_literal),
+        sql::Value::Object(object) => object.values().all(is_literal),
+        sql::Value::Thing(thing) => match &thing.id {
+            sql::Id::Number(_) | sql::Id::String(_) => true,
+            sql::Id::Array(values) => values.iter().all(is_literal),
+            sql::Id::Object(object) => object.values().all(is_literal),
+            sql::Id::Generate(_) => false,
+        },
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::*;
 
+    fn hex(cursor: &str) -> String {
+        cursor.bytes().map(|b| format!("{b:02x}")).collect()
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let values = vec![
+            sql::Value::from(5),
+            sql::Value::from("Laser"),
+            sql::Value::from(sql::Thing::from(("weapon", "one"))),
+        ];
+        let cursor = encode_cursor(values.clone());
+
+        assert_eq!(decode_cursor(&cursor), Some(values));
+    }
+
+    #[test]
+    fn test_cursor_with_subquery_is_rejected() {
+        assert_eq!(decode_cursor(&hex("[(DELETE user), weapon:one]")), None);
+        assert_eq!(decode_cursor(&hex("[5, (DELETE user)]")), None);
+        assert_eq!(
+            decode_cursor(&hex("[{ a: (DELETE user) }, weapon:one]")),
+            None
+        );
+        assert_eq!(decode_cursor(&hex("[5, weapon:[(DELETE user)]]")), None);
+        assert_eq!(decode_cursor(&hex("[$auth, weapon:one]")), None);
+        assert_eq!(decode_cursor(&hex("[5, 1]")), None);
+    }
+
     #[test]
     fn test_statement_with_alias() {
         let canadian_cities = AliasName::new("legal_age");
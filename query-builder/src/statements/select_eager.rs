@@ -0,0 +1,397 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use surrealdb::sql;
+
+use crate::{
+    BindingsList, Buildable, Conditional, Erroneous, ErrorList, Filter, NumberLike, Parametric,
+    SurrealOrmError, SurrealOrmResult,
+};
+
+use super::{Order, Orderables};
+
+/// Prefix of the projections holding the ids of the records a loaded link references
+/// but which do not exist.
+pub(crate) const DANGLING_LINKS_PREFIX: &str = "__dangling_";
+
+/// A link path to eagerly load along with the filtering, ordering and limit
+/// of the records loaded at the end of the path.
+///
+/// The path is built from the link methods of the schema e.g
+/// `Alien::schema().ally().spaceShips(E)`. A clause in the path filters the
+/// records loaded at that level of the path.
+///
+/// # Example
+/// ```rust, ignore
+/// let alien::Schema { name, .. } = Alien::schema();
+/// let space_ship::Schema { name: ship_name, .. } = SpaceShip::schema();
+///
+/// let aliens = select(All)
+///     .from(Alien::table())
+///     .with(Alien::schema().weapon())
+///     .with(
+///         eager(Alien::schema().ally().spaceShips(E))
+///             .where_(ship_name.like("Millennium"))
+///             .order_by(ship_name.asc())
+///             .limit(5),
+///     )
+///     .return_many::<Alien>(db.clone())
+///     .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct EagerLoad {
+    path: String,
+    where_: Option<String>,
+    order_by: Vec<Order>,
+    limit: Option<String>,
+    bindings: BindingsList,
+    errors: ErrorList,
+}
+
+/// Creates an eager load of the link path. See [`EagerLoad`].
+pub fn eager(link_path: impl Into<EagerLoad>) -> EagerLoad {
+    link_path.into()
+}
+
+impl<T: Buildable + Parametric + Erroneous> From<T> for EagerLoad {
+    fn from(link_path: T) -> Self {
+        Self {
+            path: link_path.build(),
+            where_: None,
+            order_by: vec![],
+            limit: None,
+            bindings: link_path.get_bindings(),
+            errors: link_path.get_errors(),
+        }
+    }
+}
+
+impl EagerLoad {
+    /// Filters the records loaded at the end of the path
+    pub fn where_(mut self, condition: impl Conditional + Clone) -> Self {
+        self.bindings.extend(condition.get_bindings());
+        self.errors.extend(condition.get_errors());
+        self.where_ = Some(Filter::new(condition).build());
+        self
+    }
+
+    /// Orders the records loaded at the end of the path when the link is a list of links
+    pub fn order_by(mut self, orderables: impl Into<Orderables>) -> Self {
+        let orderables: Orderables = orderables.into();
+        self.bindings.extend(orderables.get_bindings());
+        self.errors.extend(orderables.get_errors());
+
+        let orders: Vec<Order> = orderables.into();
+        self.order_by.extend(orders);
+        self
+    }
+
+    /// Limits the records loaded at the end of the path when the link is a list of links
+    pub fn limit(mut self, limit: impl Into<NumberLike>) -> Self {
+        let limit: NumberLike = limit.into();
+        self.bindings.extend(limit.get_bindings());
+        self.limit = Some(limit.build());
+        self
+    }
+}
+
+impl Parametric for EagerLoad {
+    fn get_bindings(&self) -> BindingsList {
+        self.bindings.to_vec()
+    }
+}
+
+impl Erroneous for EagerLoad {
+    fn get_errors(&self) -> ErrorList {
+        self.errors.to_vec()
+    }
+}
+
+/// The link paths eagerly loaded by a select statement, merged into a tree
+/// so that paths sharing a prefix load the links of the prefix once.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EagerLoads(Vec<EagerLoadNode>);
+
+#[derive(Debug, Clone)]
+struct EagerLoadNode {
+    field: String,
+    where_: Option<String>,
+    order_by: Vec<Order>,
+    limit: Option<String>,
+    children: EagerLoads,
+}
+
+impl EagerLoadNode {
+    fn new(field: String) -> Self {
+        Self {
+            field,
+            where_: None,
+            order_by: vec![],
+            limit: None,
+            children: EagerLoads::default(),
+        }
+    }
+}
+
+impl EagerLoads {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Merges the link path into the tree.
+    pub(crate) fn add(&mut self, load: EagerLoad) -> Result<(), String> {
+        let idiom = sql::idiom(&load.path)
+            .map_err(|e| format!("Invalid link path `{}` to eagerly load. {e}", load.path))?;
+
+        let mut segments: Vec<(String, Option<String>)> = vec![];
+        for part in idiom.0 {
+            match (part, segments.last_mut()) {
+                (sql::Part::Field(ident), _) => segments.push((ident.0, None)),
+                (sql::Part::Where(condition), Some((_, where_))) => {
+                    *where_ = Some(condition.to_string());
+                }
+                (sql::Part::All, Some(_)) => {}
+                (part, _) => {
+                    return Err(format!(
+                        "Invalid link path `{}` to eagerly load. Only fields and filters are supported but found `{part}`",
+                        load.path
+                    ))
+                }
+            }
+        }
+
+        let last = segments.len().saturating_sub(1);
+        let mut nodes = self;
+        for (index, (field, where_)) in segments.into_iter().enumerate() {
+            let position = match nodes.0.iter().position(|node| node.field == field) {
+                Some(position) => position,
+                None => {
+                    nodes.0.push(EagerLoadNode::new(field));
+                    nodes.0.len() - 1
+                }
+            };
+            let node = &mut nodes.0[position];
+
+            let where_ = if index == last {
+                match (where_, load.where_.clone()) {
+                    (Some(path_where), Some(load_where)) => {
+                        Some(format!("({path_where}) AND ({load_where})"))
+                    }
+                    (path_where, load_where) => path_where.or(load_where),
+                }
+            } else {
+                where_
+            };
+            if where_.is_some() {
+                node.where_ = where_;
+            }
+
+            if index == last {
+                node.order_by.extend(load.order_by.clone());
+                if load.limit.is_some() {
+                    node.limit = load.limit.clone();
+                }
+            }
+            nodes = &mut node.children;
+        }
+
+        Ok(())
+    }
+}
+
+impl Buildable for EagerLoads {
+    fn build(&self) -> String {
+        self.0
+            .iter()
+            .map(|node| {
+                let field = sql::Ident::from(node.field.as_str()).to_string();
+                let dangling_alias =
+                    sql::Ident::from(format!("{DANGLING_LINKS_PREFIX}{}", node.field)).to_string();
+
+                let projections = if node.children.is_empty() {
+                    "*".to_string()
+                } else {
+                    format!("*, {}", node.children.build())
+                };
+
+                let where_ = node
+                    .where_
+                    .as_ref()
+                    .map(|w| format!(" WHERE {w}"))
+                    .unwrap_or_default();
+
+                let order_by = if node.order_by.is_empty() {
+                    "".to_string()
+                } else {
+                    format!(
+                        " ORDER BY {}",
+                        node.order_by
+                            .iter()
+                            .map(|o| o.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                };
+
+                let limit = node
+                    .limit
+                    .as_ref()
+                    .map(|l| format!(" LIMIT {l}"))
+                    .unwrap_or_default();
+
+                // A list of links loads many records while a single link loads one record
+                // or NONE if the link is empty or filtered out.
+                format!(
+                    "(IF type::is::array({field}) \
+                    THEN (SELECT {projections} FROM $parent.{field}{where_}{order_by}{limit}) \
+                    ELSE (SELECT {projections} FROM ONLY $parent.{field}{where_}) END) AS {field}, \
+                    array::complement(array::flatten([{field}]), (SELECT VALUE id FROM $parent.{field})) \
+                    AS {dangling_alias}"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// Returns an error if a link loaded within the value references a record that
+/// does not exist and removes the projections used to find such links.
+pub(crate) fn check_dangling_links(value: &mut sql::Value) -> SurrealOrmResult<()> {
+    check_dangling_links_at(value, &[])
+}
+
+fn check_dangling_links_at(value: &mut sql::Value, path: &[&str]) -> SurrealOrmResult<()> {
+    match value {
+        sql::Value::Array(array) => {
+            for item in array.0.iter_mut() {
+                check_dangling_links_at(item, path)?;
+            }
+        }
+        sql::Value::Object(object) => {
+            let dangling_keys = object
+                .0
+                .keys()
+                .filter(|key| key.starts_with(DANGLING_LINKS_PREFIX))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for key in dangling_keys {
+                let ids = match object.0.remove(&key) {
+                    Some(sql::Value::Array(ids)) => ids
+                        .0
+                        .into_iter()
+                        .filter_map(|id| match id {
+                            sql::Value::Thing(id) => Some(id),
+                            _ => None,
+                        })
+                        .collect::<Vec<_>>(),
+                    _ => vec![],
+                };
+
+                if !ids.is_empty() {
+                    let field = key.trim_start_matches(DANGLING_LINKS_PREFIX);
+                    let field = path.iter().copied().chain([field]).collect::<Vec<_>>();
+                    return Err(SurrealOrmError::DanglingLinks {
+                        field: field.join("."),
+                        ids,
+                    });
+                }
+            }
+
+            for (key, item) in object.0.iter_mut() {
+                let path = path
+                    .iter()
+                    .copied()
+                    .chain([key.as_str()])
+                    .collect::<Vec<_>>();
+                check_dangling_links_at(item, &path)?;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{cond, statements::select, All, CanOrder, Field, Operatable, Table, ToRaw};
+
+    #[test]
+    fn test_eager_loads_merge_paths_sharing_a_prefix() {
+        let name = Field::new("name");
+        let statement = select(All)
+            .from(Table::new("alien"))
+            .with(Field::new("ally.weapon"))
+            .with(
+                eager(Field::new("ally.spaceShips"))
+                    .where_(cond(name.like("Millennium")))
+                    .order_by(name.desc())
+                    .limit(5),
+            );
+
+        insta::assert_snapshot!(statement.fine_tune_params());
+        insta::assert_snapshot!(statement.to_raw().build());
+    }
+
+    #[test]
+    fn test_eager_load_path_filters() {
+        let statement = select(All).from(Table::new("alien")).with(
+            eager(Field::new("spaceShips[WHERE name = 'Falcon']"))
+                .where_(Field::new("crew").greater_than(2)),
+        );
+
+        insta::assert_snapshot!(statement.fine_tune_params());
+        insta::assert_snapshot!(statement.to_raw().build());
+    }
+
+    #[test]
+    fn test_eager_load_invalid_path() {
+        let mut loads = EagerLoads::default();
+        assert!(loads.add(eager(Field::new("ally->likes->planet"))).is_err());
+    }
+
+    #[test]
+    fn test_select_with_eager_loads() {
+        let statement = select(All)
+            .from(Table::new("alien"))
+            .with(Field::new("weapon"));
+
+        assert_eq!(
+            statement.to_raw().build(),
+            "SELECT *, (IF type::is::array(weapon) THEN (SELECT * FROM $parent.weapon) \
+            ELSE (SELECT * FROM ONLY $parent.weapon) END) AS weapon, \
+            array::complement(array::flatten([weapon]), (SELECT VALUE id FROM $parent.weapon)) \
+            AS __dangling_weapon FROM alien;"
+        );
+    }
+
+    #[test]
+    fn test_check_dangling_links() {
+        let mut value = sql::value(
+            "[{ id: alien:1, ally: { id: alien:2, spaceShips: [], __dangling_spaceShips: [space_ship:1] }, __dangling_ally: [] }]",
+        )
+        .unwrap();
+
+        match check_dangling_links(&mut value) {
+            Err(SurrealOrmError::DanglingLinks { field, ids }) => {
+                assert_eq!(field, "ally.spaceShips");
+                assert_eq!(ids.len(), 1);
+            }
+            _ => panic!("Expected a dangling link"),
+        }
+
+        let mut value =
+            sql::value("[{ id: alien:1, weapon: { id: weapon:1 }, __dangling_weapon: [] }]")
+                .unwrap();
+        check_dangling_links(&mut value).unwrap();
+        assert_eq!(
+            value.to_string(),
+            "[{ id: alien:1, weapon: { id: weapon:1 } }]"
+        );
+    }
+}
@@ -6,9 +6,15 @@
 
 use std::fmt;
 
+use serde::{de::DeserializeOwned, Deserialize};
+use surrealdb::{sql::Thing, Connection, Surreal};
+
 use crate::{
-    traits::{BindingsList, Buildable, Erroneous, Parametric, Queryable},
-    DatetimeLike, NumberLike, TableLike,
+    traits::{
+        instrumentation::{execute, TracedResponse},
+        BindingsList, Buildable, Erroneous, Parametric, Queryable,
+    },
+    DatetimeLike, Model, NumberLike, SurrealOrmError, SurrealOrmResult, TableLike, ToRaw,
 };
 
 /// Creates a SHOW CHANGES statement builder.
@@ -29,10 +35,15 @@ pub fn show_changes_for_table(table: impl Into<TableLike>) -> ShowChangesStateme
     }
 }
 
+enum ShowSince {
+    Timestamp(DatetimeLike),
+    Versionstamp(u64),
+}
+
 /// Represents the initial state for building a SHOW CHANGES statement.
 pub struct ShowChangesStatement {
     table: TableLike,
-    since: Option<DatetimeLike>,
+    since: Option<ShowSince>,
     limit: Option<NumberLike>,
 }
 
@@ -40,7 +51,28 @@ impl ShowChangesStatement {
     /// Sets the SINCE clause for the SHOW CHANGES statement.
     pub fn since(mut self, timestamp: impl Into<DatetimeLike>) -> ShowChangesStatement {
         let timestamp: DatetimeLike = timestamp.into();
-        self.since = Some(timestamp);
+        self.since = Some(ShowSince::Timestamp(timestamp));
+        self
+    }
+
+    /// Sets the SINCE clause for the SHOW CHANGES statement to a versionstamp as
+    /// reported by [`ChangeSet::versionstamp`]. The versionstamp is inclusive, so to
+    /// resume after a stored change set, use [`ChangeSet::next_versionstamp`].
+    /// When neither this nor `since` is set, changes are read from the start.
+    ///
+    /// Examples
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// use surreal_orm::{*, statements::show_changes_for_table};
+    ///
+    /// let statement = show_changes_for_table("reading").since_versionstamp(65536).limit(10);
+    /// assert_eq!(
+    ///     statement.to_raw().build(),
+    ///     "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10;"
+    /// );
+    /// ```
+    pub fn since_versionstamp(mut self, versionstamp: u64) -> ShowChangesStatement {
+        self.since = Some(ShowSince::Versionstamp(versionstamp));
         self
     }
 
@@ -50,6 +82,117 @@ impl ShowChangesStatement {
         self.limit = Some(limit);
         self
     }
+
+    /// Runs the SHOW CHANGES statement and decodes the change sets of the table
+    /// into the model `T`.
+    ///
+    /// SurrealDB does not accept parameters within SHOW CHANGES, so the statement is
+    /// run with its values inlined.
+    ///
+    /// Examples
+    /// ```rust, ignore
+    /// use surreal_orm::{*, statements::show_changes_for_table};
+    ///
+    /// let change_sets = show_changes_for_table(Weapon::table())
+    ///     .since_versionstamp(last_change_set.next_versionstamp())
+    ///     .get_changes::<Weapon>(db.clone())
+    ///     .await?;
+    ///
+    /// for change_set in change_sets {
+    ///     for change in change_set.changes {
+    ///         match change {
+    ///             TableChange::Update(weapon) => println!("{:?}", weapon),
+    ///             TableChange::Delete(id) => println!("deleted {id}"),
+    ///             _ => {}
+    ///         }
+    ///     }
+    ///     store_versionstamp(change_set.next_versionstamp());
+    /// }
+    /// ```
+    pub async fn get_changes<T>(
+        &self,
+        db: Surreal<impl Connection>,
+    ) -> SurrealOrmResult<Vec<ChangeSet<T>>>
+    where
+        T: Model + DeserializeOwned,
+    {
+        let query_builder_error = self.get_errors();
+
+        if !query_builder_error.is_empty() {
+            return Err(SurrealOrmError::QueryBuilder(
+                query_builder_error.join(". \n"),
+            ));
+        }
+
+        // SINCE and LIMIT only accept literals, so the bindings are inlined.
+        let TracedResponse {
+            mut response, span, ..
+        } = execute(&self.to_raw(), db).await?;
+        let changes = response
+            .take::<Vec<ChangeSet<T>>>(0)
+            .map_err(SurrealOrmError::Deserialization);
+
+        span.finish_with_rows(changes, Vec::len)
+    }
+}
+
+// SHOW CHANGES reports the full 10-byte versionstamp, whose last 2 bytes are
+// a suffix not accepted by its SINCE clause.
+const VERSIONSTAMP_SUFFIX_BITS: u64 = 16;
+
+/// A set of changes made to a table at a specific versionstamp.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(bound = "T: DeserializeOwned")]
+pub struct ChangeSet<T> {
+    /// The versionstamp of the change set. Store this to resume reading changes later.
+    pub versionstamp: u64,
+    /// The changes made to the table at this versionstamp.
+    pub changes: Vec<TableChange<T>>,
+}
+
+impl<T> ChangeSet<T> {
+    /// The versionstamp to resume reading changes from after this change set.
+    pub fn next_versionstamp(&self) -> u64 {
+        ((self.versionstamp >> VERSIONSTAMP_SUFFIX_BITS) + 1) << VERSIONSTAMP_SUFFIX_BITS
+    }
+}
+
+/// A single change recorded in a table's change feed.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case", bound = "T: DeserializeOwned")]
+pub enum TableChange<T> {
+    /// A record was created. Only reported when the original value is stored.
+    Create(T),
+    /// A record was created or updated.
+    Update(T),
+    /// A record was deleted.
+    #[serde(deserialize_with = "deserialize_deleted_id")]
+    Delete(Thing),
+    /// The table was defined.
+    #[serde(deserialize_with = "deserialize_defined_table")]
+    DefineTable(String),
+}
+
+fn deserialize_deleted_id<'de, D>(deserializer: D) -> Result<Thing, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Deleted {
+        id: Thing,
+    }
+    Deleted::deserialize(deserializer).map(|deleted| deleted.id)
+}
+
+fn deserialize_defined_table<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Defined {
+        name: String,
+    }
+    Defined::deserialize(deserializer).map(|defined| defined.name)
 }
 
 impl Queryable for ShowChangesStatement {}
@@ -59,7 +202,7 @@ impl Erroneous for ShowChangesStatement {
         let mut errors = vec![];
         errors.extend(self.table.get_errors());
 
-        if let Some(timestamp) = &self.since {
+        if let Some(ShowSince::Timestamp(timestamp)) = &self.since {
             errors.extend(timestamp.get_errors());
         }
 
@@ -75,7 +218,7 @@ impl Parametric for ShowChangesStatement {
         let mut bindings = vec![];
         bindings.extend(self.table.get_bindings());
 
-        if let Some(timestamp) = &self.since {
+        if let Some(ShowSince::Timestamp(timestamp)) = &self.since {
             bindings.extend(timestamp.get_bindings());
         }
         if let Some(limit) = &self.limit {
@@ -88,8 +231,16 @@ impl Parametric for ShowChangesStatement {
 impl Buildable for ShowChangesStatement {
     fn build(&self) -> String {
         let mut query = format!("SHOW CHANGES FOR TABLE {}", self.table.build());
-        if let Some(timestamp) = &self.since {
-            query += &format!(" SINCE \"{}\"", timestamp.build());
+        match &self.since {
+            Some(ShowSince::Timestamp(timestamp)) => {
+                query += &format!(" SINCE {}", timestamp.build());
+            }
+            Some(ShowSince::Versionstamp(versionstamp)) => {
+                query += &format!(" SINCE {}", versionstamp >> VERSIONSTAMP_SUFFIX_BITS);
+            }
+            None => {
+                query += " SINCE 0";
+            }
         }
         if let Some(limit) = &self.limit {
             query += &format!(" LIMIT {}", limit.build());
@@ -113,10 +264,37 @@ mod tests {
     fn test_show_changes_for_table_build() {
         let timestamp = chrono::DateTime::from_timestamp(4343434434, 232).unwrap();
         let statement = show_changes_for_table("reading").since(timestamp).limit(10);
-        assert_eq!(statement.fine_tune_params(), "SHOW CHANGES FOR TABLE $_param_00000001 SINCE \"$_param_00000002\" LIMIT $_param_00000003;");
+        assert_eq!(
+            statement.fine_tune_params(),
+            "SHOW CHANGES FOR TABLE $_param_00000001 SINCE $_param_00000002 LIMIT $_param_00000003;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "SHOW CHANGES FOR TABLE reading SINCE '2107-08-22T05:33:54.000000232Z' LIMIT 10;"
+        );
+    }
+
+    #[test]
+    fn test_show_changes_for_table_since_versionstamp_build() {
+        let statement = show_changes_for_table("reading")
+            .since_versionstamp(65536)
+            .limit(10);
+        assert_eq!(
+            statement.fine_tune_params(),
+            "SHOW CHANGES FOR TABLE $_param_00000001 SINCE 1 LIMIT $_param_00000002;"
+        );
+        assert_eq!(
+            statement.to_raw().build(),
+            "SHOW CHANGES FOR TABLE reading SINCE 1 LIMIT 10;"
+        );
+    }
+
+    #[test]
+    fn test_show_changes_for_table_defaults_to_versionstamp_zero() {
+        let statement = show_changes_for_table("reading");
         assert_eq!(
             statement.to_raw().build(),
-            "SHOW CHANGES FOR TABLE reading SINCE \"'2107-08-22T05:33:54.000000232Z'\" LIMIT 10;"
+            "SHOW CHANGES FOR TABLE reading SINCE 0;"
         );
     }
 }
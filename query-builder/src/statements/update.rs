@@ -23,9 +23,9 @@ use surrealdb::sql;
 
 use crate::{
     derive_binding_and_errors_from_value, Binding, BindingsList, Buildable, Conditional,
-    DurationLike, Erroneous, ErrorList, Filter, Model, Parametric, PatchOp, Queryable, ReturnType,
-    ReturnableDefault, ReturnableStandard, Setter, SurrealId, SurrealSimpleId, SurrealUlid,
-    SurrealUuid, ToRaw,
+    DurationLike, Erroneous, ErrorList, Field, Filter, Model, Parametric, PatchOp, Queryable,
+    ReturnType, ReturnableDefault, ReturnableStandard, Setter, SurrealId, SurrealSimpleId,
+    SurrealUlid, SurrealUuid, ToRaw, STALE_WRITE,
 };
 
 /// Creates a new UPDATE statement.
@@ -126,6 +126,7 @@ where
         patch_ops: vec![],
         set: vec![],
         where_: None,
+        version_guard: None,
         return_type: None,
         timeout: None,
         parallel: false,
@@ -216,6 +217,7 @@ where
     set: Vec<String>,
     patch_ops: Vec<String>,
     where_: Option<String>,
+    version_guard: Option<String>,
     return_type: Option<ReturnType>,
     timeout: Option<String>,
     bindings: BindingsList,
@@ -365,14 +367,41 @@ where
     /// Caution! Overrides all data even with default. Use with care. You may prefer `merge` with Updater instead e.g `UserUpdater`.
     /// Specify the full record data using the CONTENT keyword. The content must be serializable
     /// and implement Model trait.
+    /// For a model with a `version` field, the update only applies if the record is still
+    /// at the version of the content, and fails with a stale write otherwise.
     pub fn content(mut self, content: T) -> UpdateStatement<T> {
         let (binding, errors) = derive_binding_and_errors_from_value(&content);
         self.content = Some(binding.get_param_dollarised());
+        if let Some(version) = T::version_field() {
+            self.version_guard = Some(self.version_guard(&version, binding.get_value()));
+        }
         self.bindings.push(binding);
         self.errors.extend(errors);
         self.into()
     }
 
+    /// Condition which lets the update through only if the record does not exist yet or is
+    /// still at the version of the content, and throws a stale write error otherwise.
+    fn version_guard(&mut self, version: &Field, content: &sql::Value) -> String {
+        let field = |name: &str| match content {
+            sql::Value::Object(object) => object.get(name).cloned().unwrap_or_default(),
+            _ => sql::Value::None,
+        };
+        let expected = field(&version.build());
+        let stale_write = Binding::new(format!(
+            "{STALE_WRITE}: {} was modified since version {expected}",
+            field("id")
+        ));
+        let expected = Binding::new(expected);
+        let guard = format!(
+            "IF {version} IS NONE OR {version} = {} THEN true ELSE {{ THROW {} }} END",
+            expected.get_param_dollarised(),
+            stale_write.get_param_dollarised()
+        );
+        self.bindings.extend([expected, stale_write]);
+        guard
+    }
+
     /// merge-update only specific fields by using the MERGE keyword and specifying only the fields which are to be updated.
     pub fn merge(mut self, merge: impl Serialize) -> UpdateStatement<T> {
         let (binding, errors) = derive_binding_and_errors_from_value(&merge);
@@ -472,6 +501,7 @@ where
 }
 
 /// A builder for update statements.
+#[derive(Debug, Clone)]
 pub struct UpdateStatement<T>(UpdateStatementInit<T>)
 where
     T: Serialize + DeserializeOwned + Model;
@@ -589,6 +619,11 @@ where
         self.0.parallel = true;
         self
     }
+
+    pub(crate) fn with_error(mut self, error: String) -> Self {
+        self.0.errors.push(error);
+        self
+    }
 }
 
 impl<T> Buildable for UpdateStatement<T>
@@ -617,8 +652,15 @@ where
             query = format!("{query} PATCH [{patch_vec}]");
         }
 
-        if let Some(condition) = &statement.where_ {
-            query = format!("{query} WHERE {condition}");
+        // The version guard comes last so that records filtered out do not throw
+        match (&statement.where_, &statement.version_guard) {
+            (Some(condition), Some(guard)) => {
+                query = format!("{query} WHERE {condition} AND ({guard})");
+            }
+            (Some(condition), None) | (None, Some(condition)) => {
+                query = format!("{query} WHERE {condition}");
+            }
+            (None, None) => {}
         }
 
         if let Some(return_type) = &statement.return_type {
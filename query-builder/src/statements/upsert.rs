@@ -0,0 +1,447 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2023 Oyelowo Oyedayo
+ */
+
+// Statement syntax
+// UPSERT @targets
+// 	[ CONTENT @value
+// 	  | MERGE @value
+// 	  | REPLACE @value
+// 	  | PATCH @value
+// 	  | SET @field = @value ...
+// 	]
+// 	[ WHERE @condition ]
+// 	[ RETURN [ NONE | BEFORE | AFTER | DIFF | @projections ... ]
+// 	[ TIMEOUT @duration ]
+// 	[ PARALLEL ]
+// ;
+//
+// SurrealDB 1.x has no UPSERT keyword, so the statement is built from the statements
+// with the same behaviour. UPDATE on a record id creates the record if it does not exist,
+// and for a table, UPDATE runs if any record matches, CREATE otherwise.
+use std::marker::PhantomData;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    derive_binding_and_errors_from_value, Binding, BindingsList, Buildable, Conditional,
+    DurationLike, Erroneous, ErrorList, Filter, Model, Parametric, PatchOp, Queryable, ReturnType,
+    ReturnableDefault, ReturnableStandard, Setter, ToRaw,
+};
+
+use super::update::TargettablesForUpdate;
+
+/// Creates a new UPSERT statement.
+/// The UPSERT statement updates the record if it exists and creates it otherwise,
+/// which makes writes idempotent. For a record id, the record with that id is created.
+/// For a table, all the records matching the condition are updated,
+/// or a new record is created if none matches.
+///
+/// Upserting a table checks for a match and writes in the same statement, but two
+/// concurrent upserts can both find no match and both create a record. Define a unique
+/// index on the fields the condition matches so that the second one fails instead.
+///
+/// # Arguments
+///
+/// * `targettables` - The record id or table to upsert.
+///
+/// # Examples
+///
+/// ```rust, ignore
+/// # use surreal_query_builder as surreal_orm;
+/// use surreal_orm::{*, statements::upsert};
+///
+/// // Create or fully replace a specific record using content method
+/// upsert::<User>(user1)
+///     .content(
+///          User {
+///             name: "Oyelowo".into(),
+///             age: 198,
+///             ...
+///          }
+///     );
+///
+/// // Create or update specific fields of a record using merge method
+/// upsert::<User>(user2)
+///     .merge(
+///          UserDocument {
+///             hobbies: vec!["music production", "problem solving", "rust"],
+///             ...
+///          }
+///     );
+///
+/// // Update the records that match the filter or create one if none does
+/// upsert::<User>(User::table())
+///     .set([name.equal_to("Oyelowo"), score.increment_by(5)])
+///     .where_(name.equal("Oyelowo"));
+/// ```
+pub fn upsert<T>(targettables: impl Into<TargettablesForUpdate>) -> UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    let table_name = T::table();
+    let targettables: TargettablesForUpdate = targettables.into();
+    let mut bindings = vec![];
+    let mut errors = vec![];
+    let target = match targettables {
+        TargettablesForUpdate::Table(table) => {
+            let table = table.to_string();
+            if table != table_name.to_string() {
+                errors.push(format!(
+                    "table name -{table} does not match the surreal model struct type which belongs to {table_name} table"
+                ));
+            }
+            UpsertTarget::Table(table)
+        }
+        TargettablesForUpdate::SurrealId(id) => {
+            if !id
+                .to_string()
+                .starts_with(format!("{table_name}:").as_str())
+            {
+                errors.push(format!(
+                    "id - {id} does not belong to {table_name} table from the surreal model struct provided"
+                ));
+            }
+            let binding = Binding::new(id);
+            let param = binding.get_param_dollarised();
+            bindings.push(binding);
+            UpsertTarget::SurrealId(param)
+        }
+    };
+
+    UpsertStatementInit {
+        target,
+        content: None,
+        merge: None,
+        replace: None,
+        patch_ops: vec![],
+        set: vec![],
+        where_: None,
+        return_type: None,
+        timeout: None,
+        parallel: false,
+        bindings,
+        errors,
+        __model_return_type: PhantomData,
+    }
+}
+
+#[derive(Debug, Clone)]
+enum UpsertTarget {
+    Table(String),
+    SurrealId(String),
+}
+
+/// Upsert statement initializer
+#[derive(Debug, Clone)]
+pub struct UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    target: UpsertTarget,
+    content: Option<String>,
+    merge: Option<String>,
+    replace: Option<String>,
+    set: Vec<String>,
+    patch_ops: Vec<String>,
+    where_: Option<String>,
+    return_type: Option<ReturnType>,
+    timeout: Option<String>,
+    bindings: BindingsList,
+    errors: ErrorList,
+    parallel: bool,
+    __model_return_type: PhantomData<T>,
+}
+
+impl<T> Queryable for UpsertStatement<T> where T: Serialize + DeserializeOwned + Model {}
+impl<T> Erroneous for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn get_errors(&self) -> ErrorList {
+        self.0.errors.to_vec()
+    }
+}
+
+impl<T> UpsertStatementInit<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    /// Caution! Overrides all data even with default. Use with care.
+    /// Specify the full record data using the CONTENT keyword. The content must be serializable
+    /// and implement Model trait.
+    pub fn content(mut self, content: T) -> UpsertStatement<T> {
+        let (binding, errors) = derive_binding_and_errors_from_value(&content);
+        self.content = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self.errors.extend(errors);
+        self.into()
+    }
+
+    /// merge-update only specific fields by using the MERGE keyword and specifying only the fields which are to be updated.
+    /// A created record only has the merged fields.
+    pub fn merge(mut self, merge: impl Serialize) -> UpsertStatement<T> {
+        let (binding, errors) = derive_binding_and_errors_from_value(&merge);
+        self.merge = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self.errors.extend(errors);
+        self.into()
+    }
+
+    /// Caution!
+    /// Fully replaces the record with completely new object and data. This will remove all fields
+    /// that are not present in the new object. This is a destructive operation.
+    pub fn replace(mut self, replacement: impl Serialize) -> UpsertStatement<T> {
+        let (binding, errors) = derive_binding_and_errors_from_value(&replacement);
+        self.replace = Some(binding.get_param_dollarised());
+        self.bindings.push(binding);
+        self.errors.extend(errors);
+        self.into()
+    }
+
+    /// Specify fields to set using the SET clause. Incrementing a missing numeric field
+    /// or appending to a missing array starts from an empty value when the record is created.
+    ///
+    /// # Examples
+    ///
+    /// ```rust, ignore
+    /// // Set multiple fields as an array or vector:
+    /// .set([name.equal_to("Laser"), damage.increment_by(100)]);
+    ///
+    /// // set a single field number. Generates  =
+    /// .set(score.equal_to(5))
+    /// ```
+    pub fn set(mut self, settables: impl Into<Vec<Setter>>) -> UpsertStatement<T> {
+        let settable: Vec<Setter> = settables.into();
+
+        let (settable, bindings) = settable.into_iter().fold(
+            (Vec::new(), Vec::new()),
+            |(mut settable, mut bindings), s| {
+                settable.push(s.build());
+                bindings.extend(s.get_bindings());
+                (settable, bindings)
+            },
+        );
+
+        self.bindings.extend(bindings);
+        self.set.extend(settable);
+        self.into()
+    }
+
+    /// Specify the patch operations to be applied to the record using the PATCH keyword.
+    /// Only supported for record ids as a created record has nothing to patch.
+    ///
+    /// # Arguments
+    /// * `patch_op` - A patch operation to be applied to the record. Use the `patch`
+    ///   helper function.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// name.patch_replace("Oyelowo");
+    /// name.patch_add("Oyelowo");
+    /// name.patch_remove();
+    /// ```
+    pub fn patch(mut self, patch_op: impl Into<Vec<PatchOp>>) -> UpsertStatement<T> {
+        if let UpsertTarget::Table(table) = &self.target {
+            self.errors.push(format!(
+                "Patching is only supported when upserting a record id, not the table - {table}"
+            ));
+        }
+
+        let patch_op: Vec<PatchOp> = patch_op.into();
+        for patch_op in patch_op {
+            self.bindings.extend(patch_op.get_bindings());
+            self.errors.extend(patch_op.get_errors());
+            self.patch_ops.push(patch_op.build());
+        }
+
+        self.into()
+    }
+}
+
+/// A builder for upsert statements.
+pub struct UpsertStatement<T>(UpsertStatementInit<T>)
+where
+    T: Serialize + DeserializeOwned + Model;
+
+impl<T> From<UpsertStatementInit<T>> for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn from(value: UpsertStatementInit<T>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    /// Adds a condition to the `` clause of the query.
+    /// When upserting a table, a record is created if none matches the condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - Filter for the query.
+    ///
+    /// # Example
+    ///
+    /// ```rust, ignore
+    /// .where_(age.greater_than_or_equal(18)
+    /// ```
+    pub fn where_(mut self, condition: impl Conditional) -> Self {
+        self.0.bindings.extend(condition.get_bindings());
+        let condition = Filter::new(condition);
+        self.0.where_ = Some(condition.build());
+        self
+    }
+
+    /// Sets the return type for the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `return_type` - The type of return to set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// statement.return_type(ReturnType::After);
+    /// ```
+    pub fn return_type(mut self, return_type: impl Into<ReturnType>) -> Self {
+        let return_type = return_type.into();
+        self.0.return_type = Some(return_type);
+        self
+    }
+
+    /// Sets the timeout duration for the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - a value that can represent a duration for the timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// let query = query.timeout(Duration::from_secs(30));
+    /// ```
+    pub fn timeout(mut self, duration: impl Into<DurationLike>) -> Self {
+        let duration: DurationLike = duration.into();
+        self.0.timeout = Some(duration.to_raw().build());
+        self
+    }
+
+    /// Indicates that the query should be executed in parallel.
+    pub fn parallel(mut self) -> Self {
+        self.0.parallel = true;
+        self
+    }
+
+    fn build_options(&self) -> String {
+        let statement = &self.0;
+        let mut options = String::new();
+
+        if let Some(return_type) = &statement.return_type {
+            options = format!("{options} {}", return_type.to_string().trim_end());
+        }
+
+        if let Some(timeout) = &statement.timeout {
+            options = format!("{options} TIMEOUT {timeout}");
+        }
+
+        if statement.parallel {
+            options.push_str(" PARALLEL");
+        }
+        options
+    }
+}
+
+impl<T> Buildable for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn build(&self) -> String {
+        let statement = &self.0;
+
+        let data = if let Some(content) = &statement.content {
+            format!(" CONTENT {content}")
+        } else if let Some(merge) = &statement.merge {
+            format!(" MERGE {merge}")
+        } else if let Some(replace) = &statement.replace {
+            format!(" REPLACE {replace}")
+        } else if !statement.set.is_empty() {
+            format!(" SET {}", statement.set.join(", "))
+        } else if !statement.patch_ops.is_empty() {
+            format!(" PATCH [{}]", statement.patch_ops.join(", "))
+        } else {
+            "".to_string()
+        };
+
+        let condition = statement
+            .where_
+            .as_ref()
+            .map(|condition| format!(" WHERE {condition}"))
+            .unwrap_or_default();
+        let options = self.build_options();
+
+        match &statement.target {
+            UpsertTarget::SurrealId(id) => format!("UPDATE {id}{data}{condition}{options};"),
+            UpsertTarget::Table(table) => {
+                // CREATE has no MERGE or REPLACE which are the same as CONTENT for a new record
+                let create_data = if let Some(content) = statement
+                    .content
+                    .as_ref()
+                    .or(statement.merge.as_ref())
+                    .or(statement.replace.as_ref())
+                {
+                    format!(" CONTENT {content}")
+                } else if !statement.set.is_empty() {
+                    format!(" SET {}", statement.set.join(", "))
+                } else {
+                    "".to_string()
+                };
+
+                format!(
+                    "IF (SELECT VALUE id FROM {table}{condition} LIMIT 1) \
+                    THEN (UPDATE {table}{data}{condition}{options}) \
+                    ELSE (CREATE {table}{create_data}{options}) END;"
+                )
+            }
+        }
+    }
+}
+
+impl<T> std::fmt::Display for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.build())
+    }
+}
+
+impl<T> Parametric for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model,
+{
+    fn get_bindings(&self) -> BindingsList {
+        self.0.bindings.to_vec()
+    }
+}
+
+impl<T> ReturnableDefault<T> for UpsertStatement<T> where T: Serialize + DeserializeOwned + Model {}
+
+impl<T> ReturnableStandard<T> for UpsertStatement<T>
+where
+    T: Serialize + DeserializeOwned + Model + Send + Sync,
+{
+    fn set_return_type(mut self, return_type: ReturnType) -> Self {
+        self.0.return_type = Some(return_type);
+        self
+    }
+
+    fn get_return_type(&self) -> ReturnType {
+        self.0.return_type.clone().unwrap_or(ReturnType::None)
+    }
+}
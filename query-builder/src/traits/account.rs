@@ -0,0 +1,118 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use serde::{de::DeserializeOwned, Serialize};
+use surrealdb::{
+    opt::auth::{self, Jwt},
+    Connection, Surreal,
+};
+
+use crate::{
+    statements::select, All, Buildable, Field, Node, Operatable, ReturnableSelect, Scope,
+    SurrealOrmError, SurrealOrmResult, ToRaw,
+};
+
+/// The outcome of a successful signup or signin.
+#[derive(Debug)]
+pub struct Authenticated<T> {
+    /// The token issued for the session. Can be used with `Surreal::authenticate`.
+    pub token: Jwt,
+    /// The record the credentials belong to.
+    pub account: T,
+}
+
+/// A node whose records sign up and sign in through a scope.
+/// Derived for nodes with the `account` struct attribute e.g
+/// `#[orm(table = user, account = "account")]`.
+///
+/// The scope is expected to be defined on the database e.g with `define_scope`.
+/// SurrealDB 2.0 replaces scopes with record access methods, defined with `define_access`.
+/// The table must grant the signed in record select permission on itself
+/// e.g `FOR select WHERE id = $auth.id` so that the record can be returned.
+///
+/// Examples:
+///
+/// ```rust, ignore
+/// #[derive(Node, Serialize, Deserialize, Debug, Clone)]
+/// #[serde(rename_all = "camelCase")]
+/// #[orm(table = user, account = "account")]
+/// pub struct User {
+///     id: SurrealSimpleId<Self>,
+///     email: String,
+///     pass: String,
+/// }
+///
+/// let Authenticated { token, account } = User::signup(
+///     db.clone(),
+///     "test",
+///     "test",
+///     serde_json::json!({ "email": "oyelowo@codebreather.com", "pass": "1234" }),
+/// )
+/// .await?;
+/// ```
+#[async_trait::async_trait]
+pub trait Account: Node + DeserializeOwned + Send {
+    /// The name of the scope the node authenticates with
+    fn scope() -> Scope;
+
+    /// Signs up a new record with the signup expression of the scope.
+    /// The connection is authenticated as the new record afterwards.
+    async fn signup(
+        db: Surreal<impl Connection>,
+        namespace: &str,
+        database: &str,
+        credentials: impl Serialize + Send + Sync + 'async_trait,
+    ) -> SurrealOrmResult<Authenticated<Self>> {
+        let scope = Self::scope().to_string();
+        let token = db
+            .signup(auth::Scope {
+                namespace,
+                database,
+                scope: &scope,
+                params: credentials,
+            })
+            .await
+            .map_err(SurrealOrmError::Authentication)?;
+
+        let account = Self::authenticated_account(db).await?;
+        Ok(Authenticated { token, account })
+    }
+
+    /// Signs in an existing record with the signin expression of the scope.
+    /// The connection is authenticated as the record afterwards.
+    async fn signin(
+        db: Surreal<impl Connection>,
+        namespace: &str,
+        database: &str,
+        credentials: impl Serialize + Send + Sync + 'async_trait,
+    ) -> SurrealOrmResult<Authenticated<Self>> {
+        let scope = Self::scope().to_string();
+        let token = db
+            .signin(auth::Scope {
+                namespace,
+                database,
+                scope: &scope,
+                params: credentials,
+            })
+            .await
+            .map_err(SurrealOrmError::Authentication)?;
+
+        let account = Self::authenticated_account(db).await?;
+        Ok(Authenticated { token, account })
+    }
+
+    /// Returns the record the connection is currently authenticated as.
+    async fn authenticated_account(db: Surreal<impl Connection>) -> SurrealOrmResult<Self> {
+        let statement = select(All)
+            .from(Self::table())
+            .where_(Field::new("id").equal((*crate::auth()).clone()));
+
+        statement
+            .return_one::<Self>(db)
+            .await?
+            .ok_or_else(|| SurrealOrmError::RecordNotFound(statement.to_raw().build()))
+    }
+}
@@ -0,0 +1,55 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use crate::{
+    statements::{select, select::TargettablesForSelect, SelectStatement},
+    Field, ValueLike,
+};
+
+/// A struct of values aggregated from the records of a table, grouped by its group keys.
+/// Derived with `#[derive(Aggregate)]` so that the projection of the query and the fields
+/// of the struct cannot drift apart. Each field is aliased as its serialized name.
+///
+/// # Example
+/// ```rust, ignore
+/// #[derive(Aggregate, Serialize, Deserialize, Debug)]
+/// #[serde(rename_all = "camelCase")]
+/// struct SalesPerCountry {
+///     #[agg(group_key = Order::schema().country)]
+///     country: String,
+///     #[agg(sum = Order::schema().amount)]
+///     total_amount: f64,
+///     #[agg(count)]
+///     orders: u64,
+/// }
+///
+/// // SELECT country AS country, math::sum(amount) AS totalAmount, count() AS orders
+/// // FROM order WHERE paid = true GROUP BY country;
+/// let sales = SalesPerCountry::aggregate(Order::table())
+///     .where_(paid.eq(true))
+///     .return_many::<SalesPerCountry>(db)
+///     .await?;
+/// ```
+pub trait Aggregate {
+    /// The aggregated values and the group keys, aliased as the fields of the struct.
+    fn projections() -> Vec<ValueLike>;
+
+    /// The aliases of the group keys.
+    fn group_keys() -> Vec<Field>;
+
+    /// Selects the aggregated values from the targets, grouped by the group keys, or all
+    /// together when there are none.
+    fn aggregate(targets: impl Into<TargettablesForSelect>) -> SelectStatement {
+        let statement = select(Self::projections()).from(targets);
+        let group_keys = Self::group_keys();
+
+        if group_keys.is_empty() {
+            statement.group_all()
+        } else {
+            statement.group_by(group_keys)
+        }
+    }
+}
@@ -0,0 +1,230 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use crate::{
+    statements::select_eager::DANGLING_LINKS_PREFIX, InterceptedQuery, SurrealOrmError,
+    SurrealOrmResult,
+};
+
+#[cfg(feature = "tracing")]
+pub use traced::*;
+
+#[cfg(feature = "tracing")]
+mod traced {
+    use std::{
+        future::Future,
+        sync::{PoisonError, RwLock},
+        time::Instant,
+    };
+
+    use tracing::{field::Empty, Instrument};
+
+    use crate::{Binding, BindingsList, InterceptedQuery, SurrealOrmResult};
+
+    /// How the values bound to the parameters of a query are recorded on its span.
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+    #[derive(Debug, Clone, Copy, Default)]
+    pub enum BindingRedaction {
+        /// Only the number of bindings is recorded.
+        #[default]
+        All,
+        /// The bindings are recorded with their values.
+        None,
+        /// The bindings are recorded with the values returned by the function
+        /// e.g to mask the strings but keep the numbers.
+        Custom(fn(&Binding) -> String),
+    }
+
+    static BINDING_REDACTION: RwLock<BindingRedaction> = RwLock::new(BindingRedaction::All);
+
+    /// Sets how the values bound to the parameters of the queries are recorded on their
+    /// spans. The values are not recorded by default.
+    #[cfg_attr(docsrs, doc(cfg(feature = "tracing")))]
+    pub fn set_binding_redaction(redaction: BindingRedaction) {
+        *BINDING_REDACTION
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = redaction;
+    }
+
+    fn binding_values(bindings: &BindingsList) -> Option<String> {
+        let redaction = *BINDING_REDACTION
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        let render: fn(&Binding) -> String = match redaction {
+            BindingRedaction::All => return None,
+            BindingRedaction::None => |binding| binding.get_value().to_string(),
+            BindingRedaction::Custom(render) => render,
+        };
+
+        Some(
+            bindings
+                .iter()
+                .map(|binding| format!("{} = {}", binding.get_param_dollarised(), render(binding)))
+                .collect::<Vec<_>>()
+                .join(", "),
+        )
+    }
+
+    /// The `query` span of a statement, from when it is sent until its result is taken.
+    pub(crate) struct QuerySpan {
+        span: tracing::Span,
+    }
+
+    impl QuerySpan {
+        pub(crate) fn new(query: &InterceptedQuery) -> Self {
+            let span = tracing::info_span!(
+                target: "surreal_orm",
+                "query",
+                kind = %query.kind(),
+                table = Empty,
+                query = %query.query,
+                bindings = query.bindings.len() as u64,
+                binding_values = Empty,
+                duration_ms = Empty,
+                rows = Empty,
+                error = Empty,
+            );
+
+            // Parsing the query for its table is only worth it when the span is recorded
+            if !span.is_disabled() {
+                if let Some(table) = query.table() {
+                    span.record("table", table.as_str());
+                }
+                if let Some(values) = binding_values(&query.bindings) {
+                    span.record("binding_values", values.as_str());
+                }
+            }
+
+            Self { span }
+        }
+
+        pub(crate) async fn send(
+            &self,
+            request: impl Future<Output = SurrealOrmResult<surrealdb::Response>>,
+        ) -> SurrealOrmResult<surrealdb::Response> {
+            let started = Instant::now();
+            let response = request.instrument(self.span.clone()).await;
+
+            self.span
+                .record("duration_ms", started.elapsed().as_secs_f64() * 1000.0);
+            if let Err(error) = &response {
+                self.span.record("error", error.class());
+            }
+            response
+        }
+
+        pub(crate) fn finish<R>(self, result: SurrealOrmResult<R>) -> SurrealOrmResult<R> {
+            if let Err(error) = &result {
+                self.span.record("error", error.class());
+            }
+            result
+        }
+
+        pub(crate) fn finish_with_rows<R>(
+            self,
+            result: SurrealOrmResult<R>,
+            rows: impl FnOnce(&R) -> usize,
+        ) -> SurrealOrmResult<R> {
+            if let Ok(records) = &result {
+                self.span.record("rows", rows(records) as u64);
+            }
+            self.finish(result)
+        }
+    }
+}
+
+/// The `query` span of a statement. Nothing is recorded without the `tracing` feature.
+#[cfg(not(feature = "tracing"))]
+pub(crate) struct QuerySpan;
+
+#[cfg(not(feature = "tracing"))]
+impl QuerySpan {
+    pub(crate) fn new(_query: &InterceptedQuery) -> Self {
+        Self
+    }
+
+    pub(crate) async fn send(
+        &self,
+        request: impl std::future::Future<Output = SurrealOrmResult<surrealdb::Response>>,
+    ) -> SurrealOrmResult<surrealdb::Response> {
+        request.await
+    }
+
+    pub(crate) fn finish<R>(self, result: SurrealOrmResult<R>) -> SurrealOrmResult<R> {
+        result
+    }
+
+    pub(crate) fn finish_with_rows<R>(
+        self,
+        result: SurrealOrmResult<R>,
+        _rows: impl FnOnce(&R) -> usize,
+    ) -> SurrealOrmResult<R> {
+        result
+    }
+}
+
+/// The response of a statement along with its span, so the rows and errors of its
+/// results can be recorded once taken.
+pub(crate) struct TracedResponse {
+    pub(crate) response: surrealdb::Response,
+    pub(crate) span: QuerySpan,
+    /// Whether the query loads links eagerly, whose results then have to be checked
+    /// for dangling links.
+    pub(crate) has_eager_loads: bool,
+}
+
+/// Sends the statement to the database, or with the `mock` feature to the mock database
+/// in scope, after applying the registered query interceptors.
+pub(crate) async fn execute<Q>(
+    statement: &Q,
+    db: surrealdb::Surreal<impl surrealdb::Connection>,
+) -> SurrealOrmResult<TracedResponse>
+where
+    Q: crate::Queryable + Sync + ?Sized,
+{
+    let query_builder_error = statement.get_errors();
+
+    if !query_builder_error.is_empty() {
+        return Err(SurrealOrmError::QueryBuilder(
+            query_builder_error.join(". \n"),
+        ));
+    }
+
+    let mut query = InterceptedQuery::new(statement.build(), statement.get_bindings());
+    crate::traits::interceptor::intercept(&mut query)?;
+
+    let span = QuerySpan::new(&query);
+    let has_eager_loads = query.query.contains(DANGLING_LINKS_PREFIX);
+    #[cfg(feature = "mock")]
+    if let Some(mock) = crate::MockDatabase::current() {
+        let response = span.send(mock.send(&query)).await?;
+        return Ok(TracedResponse {
+            response,
+            span,
+            has_eager_loads,
+        });
+    }
+
+    let request = query
+        .bindings
+        .iter()
+        .fold(db.query(query.query.as_str()), |acc, val| {
+            acc.bind((val.get_param(), val.get_value()))
+        });
+    let response = span
+        .send(async {
+            request
+                .await
+                .map_err(|e| SurrealOrmError::from_statement_error(e, SurrealOrmError::QueryRun))
+        })
+        .await?;
+
+    Ok(TracedResponse {
+        response,
+        span,
+        has_eager_loads,
+    })
+}
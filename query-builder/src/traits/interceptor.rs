@@ -0,0 +1,159 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::sync::{Arc, PoisonError, RwLock};
+
+use surrealdb::sql;
+
+use crate::{BindingsList, Buildable, Parametric, SurrealOrmError, SurrealOrmResult};
+
+/// A query about to be sent to the database by `Runnable::run`.
+#[derive(Debug, Clone)]
+pub struct InterceptedQuery {
+    /// The query string. Values are bound as parameters e.g `$_param_00000001`.
+    pub query: String,
+    /// The values bound to the parameters of the query.
+    pub bindings: BindingsList,
+}
+
+impl InterceptedQuery {
+    pub(crate) fn new(query: String, bindings: BindingsList) -> Self {
+        Self { query, bindings }
+    }
+
+    /// The keyword of the first statement of the query e.g `SELECT`.
+    pub fn kind(&self) -> String {
+        self.query
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .trim_start_matches('(')
+            .trim_end_matches(';')
+            .to_uppercase()
+    }
+
+    /// The table read or written by the first statement of the query, if any.
+    pub fn table(&self) -> Option<String> {
+        let target = match self.statements().ok()?.into_iter().next()? {
+            sql::Statement::Select(statement) => statement.what.0.into_iter().next(),
+            sql::Statement::Create(statement) => statement.what.0.into_iter().next(),
+            sql::Statement::Update(statement) => statement.what.0.into_iter().next(),
+            sql::Statement::Delete(statement) => statement.what.0.into_iter().next(),
+            sql::Statement::Insert(statement) => Some(statement.into),
+            sql::Statement::Relate(statement) => Some(statement.kind),
+            _ => None,
+        }?;
+
+        self.table_of(target)
+    }
+
+    /// Parses the statements of the query e.g to check that a `DELETE` statement
+    /// has a `WHERE` clause.
+    pub fn statements(&self) -> SurrealOrmResult<Vec<sql::Statement>> {
+        sql::parse(&self.query)
+            .map(|query| query.0 .0)
+            .map_err(|e| SurrealOrmError::QueryBuilder(format!("Unable to parse the query. {e}")))
+    }
+
+    fn table_of(&self, target: sql::Value) -> Option<String> {
+        match target {
+            sql::Value::Table(table) => Some(table.0),
+            sql::Value::Thing(thing) => Some(thing.tb),
+            sql::Value::Param(param) => self
+                .bindings
+                .iter()
+                .find(|binding| binding.get_param() == &param.0 .0)
+                .and_then(|binding| self.table_of(binding.get_value().clone())),
+            sql::Value::Array(targets) => targets
+                .0
+                .into_iter()
+                .next()
+                .and_then(|target| self.table_of(target)),
+            _ => None,
+        }
+    }
+}
+
+impl Buildable for InterceptedQuery {
+    fn build(&self) -> String {
+        self.query.clone()
+    }
+}
+
+impl Parametric for InterceptedQuery {
+    fn get_bindings(&self) -> BindingsList {
+        self.bindings.clone()
+    }
+}
+
+/// Inspects, rewrites or rejects the queries run by `Runnable::run`, and the statement
+/// methods built on it e.g `return_many`, before they are sent to the database.
+/// Registered with `register_query_interceptor`.
+///
+/// The registered interceptors are global to the process: they apply to the queries of
+/// every `Surreal` client and task, including tests running in parallel.
+///
+/// # Example
+/// ```rust, ignore
+/// // Blocks the deletes of whole tables
+/// register_query_interceptor(|query: &mut InterceptedQuery| {
+///     let unbounded_delete = query.statements()?.iter().any(|statement| {
+///         matches!(statement, sql::Statement::Delete(delete) if delete.cond.is_none())
+///     });
+///
+///     if unbounded_delete {
+///         return Err(SurrealOrmError::QueryRejected(
+///             "DELETE statements must have a WHERE clause".to_string(),
+///         ));
+///     }
+///     Ok(())
+/// });
+/// ```
+pub trait QueryInterceptor: Send + Sync {
+    /// Called with each query before it is sent. The query or its bindings can be modified
+    /// and returning an error rejects the query.
+    fn intercept(&self, query: &mut InterceptedQuery) -> SurrealOrmResult<()>;
+}
+
+impl<F> QueryInterceptor for F
+where
+    F: Fn(&mut InterceptedQuery) -> SurrealOrmResult<()> + Send + Sync,
+{
+    fn intercept(&self, query: &mut InterceptedQuery) -> SurrealOrmResult<()> {
+        self(query)
+    }
+}
+
+static QUERY_INTERCEPTORS: RwLock<Vec<Arc<dyn QueryInterceptor>>> = RwLock::new(Vec::new());
+
+/// Registers an interceptor for all the queries run from then on, by any client or task
+/// of the process. Interceptors are applied in the order they are registered.
+pub fn register_query_interceptor(interceptor: impl QueryInterceptor + 'static) {
+    QUERY_INTERCEPTORS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(Arc::new(interceptor));
+}
+
+/// Removes all the registered query interceptors, including those registered by other
+/// tasks of the process.
+pub fn clear_query_interceptors() {
+    QUERY_INTERCEPTORS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+pub(crate) fn intercept(query: &mut InterceptedQuery) -> SurrealOrmResult<()> {
+    let interceptors = QUERY_INTERCEPTORS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+
+    interceptors
+        .iter()
+        .try_for_each(|interceptor| interceptor.intercept(query))
+}
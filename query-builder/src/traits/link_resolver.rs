@@ -0,0 +1,258 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+// `sql::Thing` is flagged as having interior mutability through the values an id can hold,
+// but the ids used as keys here are never mutated.
+#![allow(clippy::mutable_key_type)]
+
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap},
+    ops::DerefMut,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+use surrealdb::{sql, Connection, Surreal};
+
+use crate::{
+    statements::select, All, LinkMany, LinkOne, LinkSelf, Node, Reference, ReturnableSelect,
+    SurrealOrmResult,
+};
+
+/// A link field holding references to foreign nodes which can be resolved
+/// in place by the [`LinkResolver`].
+pub trait LinkReferences<V: Node> {
+    /// The references of the link
+    fn references_mut(&mut self) -> Vec<&mut Reference<V>>;
+}
+
+impl<V: Node> LinkReferences<V> for Reference<V> {
+    fn references_mut(&mut self) -> Vec<&mut Reference<V>> {
+        vec![self]
+    }
+}
+
+impl<V: Node> LinkReferences<V> for LinkOne<V> {
+    fn references_mut(&mut self) -> Vec<&mut Reference<V>> {
+        vec![self.deref_mut()]
+    }
+}
+
+impl<V: Node> LinkReferences<V> for LinkSelf<V> {
+    fn references_mut(&mut self) -> Vec<&mut Reference<V>> {
+        vec![self.deref_mut().as_mut()]
+    }
+}
+
+impl<V: Node> LinkReferences<V> for LinkMany<V> {
+    fn references_mut(&mut self) -> Vec<&mut Reference<V>> {
+        self.iter_mut().collect()
+    }
+}
+
+type CachedRecords = HashMap<sql::Thing, Option<Arc<dyn Any + Send + Sync>>>;
+
+/// Resolves the links of many records at once, avoiding a query per record.
+///
+/// The ids referenced by the records are gathered and loaded with a single
+/// `SELECT * FROM [ids]` per foreign table. Loaded records, and ids found not to exist,
+/// are cached for the lifetime of the resolver, so a resolver is meant to be created per request.
+/// Clones of the resolver share the cache.
+///
+/// # Example
+/// ```rust, ignore
+/// let mut aliens = select(All).from(Alien::table()).return_many::<Alien>(db.clone()).await?;
+///
+/// let resolver = LinkResolver::new(db.clone());
+/// resolver.resolve(&mut aliens, |alien| &mut alien.weapon).await?;
+/// resolver.resolve(&mut aliens, |alien| &mut alien.space_ships).await?;
+///
+/// assert!(aliens[0].weapon.value().is_some());
+/// ```
+pub struct LinkResolver<C: Connection> {
+    db: Surreal<C>,
+    cache: Arc<Mutex<CachedRecords>>,
+}
+
+impl<C: Connection> Clone for LinkResolver<C> {
+    fn clone(&self) -> Self {
+        Self {
+            db: self.db.clone(),
+            cache: self.cache.clone(),
+        }
+    }
+}
+
+impl<C: Connection> std::fmt::Debug for LinkResolver<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinkResolver")
+            .field("cached", &self.cached_records().len())
+            .finish()
+    }
+}
+
+impl<C: Connection> LinkResolver<C> {
+    /// Creates a resolver with an empty cache
+    pub fn new(db: Surreal<C>) -> Self {
+        Self {
+            db,
+            cache: Default::default(),
+        }
+    }
+
+    fn cached_records(&self) -> std::sync::MutexGuard<'_, CachedRecords> {
+        self.cache.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Loads the records of the ids. Records which do not exist are left out of the result.
+    pub async fn load<V>(
+        &self,
+        ids: impl IntoIterator<Item = sql::Thing>,
+    ) -> SurrealOrmResult<HashMap<sql::Thing, V>>
+    where
+        V: Node + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        let mut records = HashMap::new();
+        let mut missing_by_table = BTreeMap::<String, Vec<sql::Thing>>::new();
+        {
+            let cache = self.cached_records();
+            for id in ids {
+                if records.contains_key(&id) {
+                    continue;
+                }
+                match cache.get(&id) {
+                    Some(Some(record)) => {
+                        if let Some(record) = record.downcast_ref::<V>() {
+                            records.insert(id, record.clone());
+                        }
+                    }
+                    Some(None) => {}
+                    None => {
+                        let ids = missing_by_table.entry(id.tb.clone()).or_default();
+                        if !ids.contains(&id) {
+                            ids.push(id);
+                        }
+                    }
+                }
+            }
+        }
+
+        for (_, ids) in missing_by_table {
+            let loaded = select(All)
+                .from(ids.clone())
+                .return_many::<V>(self.db.clone())
+                .await?;
+            let loaded = loaded
+                .into_iter()
+                .map(|record| (record.get_id_as_thing(), record))
+                .collect::<HashMap<_, _>>();
+
+            let mut cache = self.cached_records();
+            for id in ids {
+                let record = loaded.get(&id).cloned();
+                cache.insert(
+                    id.clone(),
+                    record
+                        .clone()
+                        .map(|record| Arc::new(record) as Arc<dyn Any + Send + Sync>),
+                );
+                if let Some(record) = record {
+                    records.insert(id, record);
+                }
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Resolves the link of each of the records in place. References to
+    /// records which do not exist are left as ids.
+    pub async fn resolve<M, L, V>(
+        &self,
+        records: &mut [M],
+        link: impl Fn(&mut M) -> &mut L,
+    ) -> SurrealOrmResult<()>
+    where
+        L: LinkReferences<V>,
+        V: Node + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        let ids = records
+            .iter_mut()
+            .flat_map(|record| {
+                link(record)
+                    .references_mut()
+                    .into_iter()
+                    .filter_map(|reference| reference.get_id().cloned())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let loaded = self.load::<V>(ids).await?;
+
+        for record in records.iter_mut() {
+            for reference in link(record).references_mut() {
+                let value = reference.get_id().and_then(|id| loaded.get(id)).cloned();
+                if let Some(value) = value {
+                    *reference = Reference::FetchedValue(value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns a loader of the records of a node for async-graphql's `DataLoader`,
+    /// sharing the cache of the resolver.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let loader = DataLoader::new(LinkResolver::new(db).loader::<Weapon>(), tokio::spawn);
+    /// let weapon = loader.load_one(alien.weapon.get_id().unwrap().clone()).await?;
+    /// ```
+    #[cfg(feature = "async-graphql")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "async-graphql")))]
+    pub fn loader<V>(&self) -> LinkLoader<V, C>
+    where
+        V: Node + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    {
+        LinkLoader {
+            resolver: self.clone(),
+            _node: std::marker::PhantomData,
+        }
+    }
+}
+
+/// Loads the records of a node by their ids for async-graphql's `DataLoader`.
+/// Created with [`LinkResolver::loader`].
+#[cfg(feature = "async-graphql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async-graphql")))]
+#[derive(Debug)]
+pub struct LinkLoader<V, C: Connection> {
+    resolver: LinkResolver<C>,
+    _node: std::marker::PhantomData<fn() -> V>,
+}
+
+#[cfg(feature = "async-graphql")]
+impl<V, C> async_graphql::dataloader::Loader<sql::Thing> for LinkLoader<V, C>
+where
+    V: Node + Serialize + DeserializeOwned + Clone + Send + Sync + 'static,
+    C: Connection,
+{
+    type Value = V;
+    type Error = Arc<crate::SurrealOrmError>;
+
+    async fn load(&self, keys: &[sql::Thing]) -> Result<HashMap<sql::Thing, V>, Self::Error> {
+        self.resolver
+            .load(keys.iter().cloned())
+            .await
+            .map_err(Arc::new)
+    }
+}
@@ -0,0 +1,396 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use std::{
+    cell::RefCell,
+    fs,
+    future::Future,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use surrealdb::{
+    engine::local::{Db, Mem},
+    sql, Surreal,
+};
+
+use crate::{
+    Binding, BindingsList, Buildable, InterceptedQuery, SurrealOrmError, SurrealOrmResult, ToRaw,
+};
+
+thread_local! {
+    static CURRENT_MOCK: RefCell<Option<MockDatabase>> = const { RefCell::new(None) };
+}
+
+/// A query run within the scope of a `MockDatabase`.
+#[derive(Debug, Clone)]
+pub struct RecordedQuery {
+    /// The query string. Values are bound as parameters e.g `$_param_00000001`.
+    pub query: String,
+    /// The query string with the values of its bindings inlined. The query patterns
+    /// of the mock database are matched against it.
+    pub raw: String,
+    /// The values bound to the parameters of the query.
+    pub bindings: BindingsList,
+}
+
+impl RecordedQuery {
+    /// Whether the value is bound to one of the parameters of the query.
+    pub fn has_binding(&self, value: impl Into<sql::Value>) -> bool {
+        let value = value.into();
+        self.bindings
+            .iter()
+            .any(|binding| binding.get_value() == &value)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum MockResult {
+    Ok(sql::Value),
+    Err(String),
+}
+
+/// The results of a query recorded in a snapshot file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SnapshotEntry {
+    query: String,
+    results: Vec<MockResult>,
+}
+
+#[derive(Debug)]
+enum Mode {
+    Mock,
+    Record {
+        path: PathBuf,
+        entries: Mutex<Vec<SnapshotEntry>>,
+    },
+    Replay {
+        entries: Mutex<Vec<(SnapshotEntry, bool)>>,
+    },
+}
+
+#[derive(Debug)]
+struct MockState {
+    db: Surreal<Db>,
+    mode: Mode,
+    responses: Mutex<Vec<(Regex, MockResult)>>,
+    queries: Mutex<Vec<RecordedQuery>>,
+}
+
+/// Stands in for the database of the statements run within its `scope`, for testing code
+/// built on `Runnable` e.g `return_one`, `return_many` or `get_data` without a database.
+///
+/// Every query is recorded. Queries matching a canned response get it back, and the others
+/// are run against an in-memory database, or replayed from a snapshot file.
+/// A `Surreal` client cannot tell which mock it belongs to, hence the statements
+/// only use the mock within `MockDatabase::scope`, whatever client they are given.
+///
+/// The mock is set for the task polling the scoped future only. Statements run in tasks
+/// spawned from it, e.g with `tokio::spawn`, go to the client they are given; wrap the
+/// spawned future in its own `scope` for them to use the mock too.
+///
+/// Requires the `mock` feature.
+///
+/// # Example
+/// ```rust, ignore
+/// let mock = MockDatabase::new().await?;
+/// mock.respond(r"^SELECT \* FROM invoice", vec![invoice])?;
+///
+/// let invoices = mock.scope(list_invoices(mock.db())).await?;
+///
+/// let query = mock.assert_ran_once(r"^SELECT \* FROM invoice");
+/// assert!(query.has_binding("acme"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockDatabase {
+    state: Arc<MockState>,
+}
+
+impl MockDatabase {
+    async fn with_mode(mode: Mode) -> SurrealOrmResult<Self> {
+        let db = Surreal::new::<Mem>(())
+            .await
+            .map_err(SurrealOrmError::QueryRun)?;
+        db.use_ns("test")
+            .use_db("test")
+            .await
+            .map_err(SurrealOrmError::QueryRun)?;
+
+        Ok(Self {
+            state: Arc::new(MockState {
+                db,
+                mode,
+                responses: Mutex::new(Vec::new()),
+                queries: Mutex::new(Vec::new()),
+            }),
+        })
+    }
+
+    /// A mock database running the queries without a canned response against an
+    /// in-memory database.
+    pub async fn new() -> SurrealOrmResult<Self> {
+        Self::with_mode(Mode::Mock).await
+    }
+
+    /// A mock database running the queries without a canned response against an
+    /// in-memory database, and writing their results to the snapshot file for `replay`.
+    pub async fn record(path: impl AsRef<Path>) -> SurrealOrmResult<Self> {
+        Self::with_mode(Mode::Record {
+            path: path.as_ref().to_path_buf(),
+            entries: Mutex::new(Vec::new()),
+        })
+        .await
+    }
+
+    /// A mock database answering the queries without a canned response with the results
+    /// recorded in the snapshot file, in the order they were recorded. The queries must be
+    /// the same as when recorded, including the values of their bindings e.g the ids.
+    /// Errors are replayed as thrown errors with the recorded message.
+    pub async fn replay(path: impl AsRef<Path>) -> SurrealOrmResult<Self> {
+        let path = path.as_ref();
+        let entries = fs::read_to_string(path)
+            .map_err(|e| snapshot_error(path, e))
+            .and_then(|snapshot| {
+                serde_json::from_str::<Vec<SnapshotEntry>>(&snapshot)
+                    .map_err(|e| snapshot_error(path, e))
+            })?;
+
+        Self::with_mode(Mode::Replay {
+            entries: Mutex::new(entries.into_iter().map(|entry| (entry, false)).collect()),
+        })
+        .await
+    }
+
+    /// The in-memory database of the mock, e.g to seed data or to pass to the code under test.
+    pub fn db(&self) -> Surreal<Db> {
+        self.state.db.clone()
+    }
+
+    /// Runs the future with the mock standing in for the database of its statements.
+    pub fn scope<F: Future>(&self, future: F) -> MockScope<F> {
+        MockScope {
+            mock: self.clone(),
+            future: Box::pin(future),
+        }
+    }
+
+    /// Answers the queries matching the pattern with the response as the result of
+    /// their only statement. The latest matching response is used.
+    ///
+    /// # Panics
+    /// If the pattern is not a valid regular expression.
+    pub fn respond(&self, pattern: &str, response: impl Serialize) -> SurrealOrmResult<()> {
+        let response = sql::to_value(response).map_err(|e| {
+            SurrealOrmError::QueryBuilder(format!("Unable to serialize the mock response. {e}"))
+        })?;
+        self.add_response(pattern, MockResult::Ok(response));
+        Ok(())
+    }
+
+    /// Fails the queries matching the pattern with the error message.
+    ///
+    /// # Panics
+    /// If the pattern is not a valid regular expression.
+    pub fn fail(&self, pattern: &str, message: impl Into<String>) {
+        self.add_response(pattern, MockResult::Err(message.into()));
+    }
+
+    /// The queries run so far, in order.
+    pub fn queries(&self) -> Vec<RecordedQuery> {
+        lock(&self.state.queries).clone()
+    }
+
+    /// The queries run so far whose raw string matches the pattern.
+    ///
+    /// # Panics
+    /// If the pattern is not a valid regular expression.
+    pub fn queries_matching(&self, pattern: &str) -> Vec<RecordedQuery> {
+        let pattern = query_pattern(pattern);
+        lock(&self.state.queries)
+            .iter()
+            .filter(|query| pattern.is_match(&query.raw))
+            .cloned()
+            .collect()
+    }
+
+    /// Asserts that exactly one of the queries run so far matches the pattern, and returns it.
+    ///
+    /// # Panics
+    /// If no query or more than one query matches the pattern.
+    #[track_caller]
+    pub fn assert_ran_once(&self, pattern: &str) -> RecordedQuery {
+        let mut matching = self.queries_matching(pattern);
+        if matching.len() != 1 {
+            let queries = self
+                .queries()
+                .into_iter()
+                .map(|query| query.raw)
+                .collect::<Vec<_>>();
+            panic!(
+                "Expected one query matching `{pattern}`, but found {}. Queries run:\n{}",
+                matching.len(),
+                queries.join("\n")
+            );
+        }
+        matching.remove(0)
+    }
+
+    pub(crate) fn current() -> Option<Self> {
+        CURRENT_MOCK.with(|current| current.borrow().clone())
+    }
+
+    pub(crate) async fn send(
+        &self,
+        query: &InterceptedQuery,
+    ) -> SurrealOrmResult<surrealdb::Response> {
+        let raw = query.to_raw().build();
+        lock(&self.state.queries).push(RecordedQuery {
+            query: query.query.clone(),
+            raw: raw.clone(),
+            bindings: query.bindings.clone(),
+        });
+
+        let response = lock(&self.state.responses)
+            .iter()
+            .rev()
+            .find(|(pattern, _)| pattern.is_match(&raw))
+            .map(|(_, response)| response.clone());
+        if let Some(response) = response {
+            return self.reply(vec![response]).await;
+        }
+
+        match &self.state.mode {
+            Mode::Mock => self.run(query).await,
+            Mode::Record { path, entries } => {
+                let mut response = self.run(query).await?;
+                let results = (0..response.num_statements())
+                    .map(|index| match response.take::<sql::Value>(index) {
+                        Ok(value) => MockResult::Ok(value),
+                        Err(error) => MockResult::Err(error.to_string()),
+                    })
+                    .collect::<Vec<_>>();
+
+                let snapshot = {
+                    let mut entries = lock(entries);
+                    entries.push(SnapshotEntry {
+                        query: raw,
+                        results: results.clone(),
+                    });
+                    serde_json::to_string_pretty(&*entries).map_err(|e| snapshot_error(path, e))?
+                };
+                if let Some(directory) = path.parent() {
+                    fs::create_dir_all(directory).map_err(|e| snapshot_error(path, e))?;
+                }
+                fs::write(path, snapshot).map_err(|e| snapshot_error(path, e))?;
+
+                self.reply(results).await
+            }
+            Mode::Replay { entries } => {
+                let results = lock(entries)
+                    .iter_mut()
+                    .find(|(entry, replayed)| !replayed && entry.query == raw)
+                    .map(|(entry, replayed)| {
+                        *replayed = true;
+                        entry.results.clone()
+                    })
+                    .ok_or_else(|| {
+                        SurrealOrmError::MockSnapshot(format!(
+                            "No recorded results for the query `{raw}`"
+                        ))
+                    })?;
+
+                self.reply(results).await
+            }
+        }
+    }
+
+    fn add_response(&self, pattern: &str, response: MockResult) {
+        lock(&self.state.responses).push((query_pattern(pattern), response));
+    }
+
+    async fn run(&self, query: &InterceptedQuery) -> SurrealOrmResult<surrealdb::Response> {
+        query
+            .bindings
+            .iter()
+            .fold(self.state.db.query(query.query.as_str()), |acc, val| {
+                acc.bind((val.get_param(), val.get_value()))
+            })
+            .await
+            .map_err(|e| SurrealOrmError::from_statement_error(e, SurrealOrmError::QueryRun))
+    }
+
+    // Returns the results through the in-memory database, as a `Response` can only
+    // be created by running a query.
+    async fn reply(&self, results: Vec<MockResult>) -> SurrealOrmResult<surrealdb::Response> {
+        let (statements, bindings): (Vec<_>, BindingsList) = results
+            .into_iter()
+            .map(|result| {
+                let (keyword, value) = match result {
+                    MockResult::Ok(value) => ("RETURN", value),
+                    MockResult::Err(message) => ("THROW", message.into()),
+                };
+                let binding = Binding::new(value);
+                (
+                    format!("{keyword} {};", binding.get_param_dollarised()),
+                    binding,
+                )
+            })
+            .unzip();
+
+        self.run(&InterceptedQuery::new(statements.join("\n"), bindings))
+            .await
+    }
+}
+
+/// A future run with a `MockDatabase` standing in for the database of its statements.
+/// Created with `MockDatabase::scope`.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct MockScope<F> {
+    mock: MockDatabase,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for MockScope<F> {
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let _current = CurrentMock::enter(this.mock.clone());
+        this.future.as_mut().poll(cx)
+    }
+}
+
+/// Sets the current mock of the thread while a scoped future is polled.
+struct CurrentMock(Option<MockDatabase>);
+
+impl CurrentMock {
+    fn enter(mock: MockDatabase) -> Self {
+        Self(CURRENT_MOCK.with(|current| current.replace(Some(mock))))
+    }
+}
+
+impl Drop for CurrentMock {
+    fn drop(&mut self) {
+        CURRENT_MOCK.with(|current| current.replace(self.0.take()));
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn query_pattern(pattern: &str) -> Regex {
+    Regex::new(pattern).unwrap_or_else(|e| panic!("Invalid query pattern `{pattern}`. {e}"))
+}
+
+fn snapshot_error(path: &Path, error: impl std::fmt::Display) -> SurrealOrmError {
+    SurrealOrmError::MockSnapshot(format!("{}: {error}", path.display()))
+}
@@ -4,9 +4,16 @@
  * Copyright (c) 2023 Oyelowo Oyedayo
  */
 
+pub(crate) mod account;
+pub(crate) mod aggregate;
 pub(crate) mod binding;
 pub(crate) mod db_resources;
 pub(crate) mod general;
+pub(crate) mod instrumentation;
+pub(crate) mod interceptor;
+pub(crate) mod link_resolver;
+#[cfg(feature = "mock")]
+pub(crate) mod mock_database;
 pub(crate) mod model;
 pub(crate) mod operation;
 pub(crate) mod patch_op;
@@ -15,10 +22,21 @@ pub(crate) mod raw;
 pub(crate) mod setter;
 pub(crate) mod statements;
 pub(crate) mod table_resources;
+pub(crate) mod typed_results;
 
+pub use account::*;
+pub use aggregate::*;
 pub use binding::*;
 pub use db_resources::*;
 pub use general::*;
+#[cfg(feature = "tracing")]
+pub use instrumentation::{set_binding_redaction, BindingRedaction};
+pub use interceptor::{
+    clear_query_interceptors, register_query_interceptor, InterceptedQuery, QueryInterceptor,
+};
+pub use link_resolver::*;
+#[cfg(feature = "mock")]
+pub use mock_database::{MockDatabase, MockScope, RecordedQuery};
 pub use model::*;
 pub use operation::*;
 pub use patch_op::*;
@@ -27,3 +45,4 @@ pub use raw::*;
 pub use setter::*;
 pub use statements::*;
 pub use table_resources::*;
+pub use typed_results::*;
@@ -5,17 +5,19 @@
  */
 
 use crate::{
-    count,
+    cond, count,
     statements::{
         create::{create, CreateStatement},
         delete::{delete, DeleteStatementMini},
-        select::{select, SelectStatementCount},
+        select::{select, SelectStatementCount, SelectStatementNearest},
         select_value,
         update::{update, UpdateStatement},
+        upsert::{upsert, UpsertStatement},
         SelectStatementMini,
     },
-    Alias, All, Conditional, Field, Filter, NodeClause, Raw, SurrealId, SurrealOrmResult,
-    SurrealSimpleId, SurrealUlid, SurrealUuid, Table, ValueLike,
+    Alias, All, ArrayLike, Conditional, Field, Filter, Function, NodeClause, Operatable, Raw,
+    ReturnType, Setter, SurrealId, SurrealOrmResult, SurrealSimpleId, SurrealUlid, SurrealUuid,
+    Table, ValueLike, NONE,
 };
 use serde::{de::DeserializeOwned, Serialize};
 use surrealdb::sql::{self, Thing};
@@ -36,6 +38,13 @@ pub trait Model: Sized {
     type Id;
     /// For checking renamed struct field names
     type StructRenamedCreator;
+    /// The name of the model/table as a constant. Used for compile time checks e.g in `query!`.
+    /// Set by the derive macros. Empty by default for manual implementations, whose tables
+    /// are then unknown to `query!`.
+    const TABLE_NAME: &'static str = "";
+    /// The database names of the serializable fields as a constant.
+    /// Used for compile time checks e.g in `query!`. Set by the derive macros.
+    const SERIALIZABLE_FIELD_NAMES: &'static [&'static str] = &[];
     /// The name of the model/table
     fn table() -> Table;
 
@@ -69,6 +78,29 @@ pub trait Model: Sized {
     /// Get model's fields definitions statements as a list
     fn define_fields() -> Vec<Raw>;
 
+    /// Get model's indexes definitions statements as a list
+    fn define_indexes() -> Vec<Raw> {
+        vec![]
+    }
+
+    /// Get model's events definitions statements as a list
+    fn define_events() -> Vec<Raw> {
+        vec![]
+    }
+
+    /// The field set to the time a record is deleted when the model is soft deleted
+    /// i.e `#[orm(soft_delete = "deleted_at")]`. Soft deleted records are kept in the
+    /// database so that they can be restored.
+    fn soft_delete_field() -> Option<Field> {
+        None
+    }
+
+    /// The field incremented on every write of a record i.e `#[orm(version)]`.
+    /// Saving a record read at an older version fails with a stale write.
+    fn version_field() -> Option<Field> {
+        None
+    }
+
     /// Get old name of field
     fn get_field_meta() -> Vec<FieldMetadata>;
 
@@ -116,46 +148,126 @@ pub trait SurrealCrud: Sized + Serialize + DeserializeOwned + Model {
         update::<Self>(self.get_id_as_thing()).content(self)
     }
 
-    /// Finds a record by id.
+    /// Creates the record or replaces its content if it exists, by id.
+    /// Running it again with the same record leaves the database unchanged,
+    /// so it can be used for idempotent writes.
+    fn save_or_create(self) -> UpsertStatement<Self> {
+        upsert::<Self>(self.get_id_as_thing()).content(self)
+    }
+
+    /// Finds a record by id. A soft deleted record is not found unless
+    /// `with_deleted` or `only_deleted` is used.
     fn find_by_id(id: impl Into<Thing>) -> SelectStatementMini<Self> {
         select(All).from(id.into()).into()
     }
 
-    /// Finds records by filtering.
+    /// Finds records by filtering. Soft deleted records are excluded unless
+    /// `with_deleted` or `only_deleted` is used.
     fn find_where(filter: impl Conditional + Clone) -> SelectStatementMini<Self> {
         select(All).from(Self::table()).where_(filter).into()
     }
 
-    /// Count filtered records.
+    /// Count filtered records. Soft deleted records are not counted.
     fn count_where(filter: impl Conditional + Clone) -> SelectStatementCount {
-        let selection = select_value(Field::new("count")).from(
-            select(count!(Filter::new(filter)))
-                .from(Self::table())
-                .group_all(),
-        );
+        let count = select(count!(Filter::new(filter))).from(Self::table());
+        let count = match Self::soft_delete_field() {
+            Some(deleted_at) => count.where_(deleted_at.is(NONE)),
+            None => count,
+        };
+        let selection = select_value(Field::new("count")).from(count.group_all());
         selection.into()
     }
 
-    /// Count all records.
+    /// Count all records. Soft deleted records are not counted.
     fn count_all() -> SelectStatementCount {
-        let selection = select_value(Field::new("count"))
-            .from(select(count!()).from(Self::table()).group_all());
+        let count = select(count!()).from(Self::table());
+        let count = match Self::soft_delete_field() {
+            Some(deleted_at) => count.where_(deleted_at.is(NONE)),
+            None => count,
+        };
+        let selection = select_value(Field::new("count")).from(count.group_all());
         selection.into()
     }
 
-    /// Delete the current record by instance.
+    /// Finds the `k` records whose vector field is nearest to the vector, along with
+    /// their distance to it, nearest first. Uses the M-Tree vector index of the field when defined.
+    /// The distance is recomputed with the `vector::distance::*` function of the chosen
+    /// distance, as `vector::distance::knn()`, which returns the distance computed by the
+    /// search, is not available in SurrealDB 1.5.6.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let documents = Document::nearest(embedding, query_embedding, 10)
+    ///     .distance(VectorDistance::Cosine)
+    ///     .get(db)
+    ///     .await?;
+    /// ```
+    fn nearest(
+        field: impl Into<Field>,
+        vector: impl Into<ArrayLike>,
+        k: u32,
+    ) -> SelectStatementNearest<Self> {
+        SelectStatementNearest::new(field.into(), vector.into(), k)
+    }
+
+    /// Delete the current record by instance. A soft deleted model has its
+    /// soft delete field set to the current time instead.
     fn delete(&self) -> DeleteStatementMini<Self> {
-        delete::<Self>(self.get_id_as_thing()).into()
+        Self::delete_by_id(self.get_id_as_thing())
     }
 
-    /// Deletes a record by id.
+    /// Deletes a record by id. A soft deleted model has its
+    /// soft delete field set to the current time instead.
     fn delete_by_id(id: impl Into<Thing>) -> DeleteStatementMini<Self> {
-        delete::<Self>(id.into()).into()
+        let id = id.into();
+        match Self::soft_delete_field() {
+            Some(deleted_at) => update::<Self>(id)
+                .set(Setter::assign(&deleted_at, time_now()))
+                .where_(deleted_at.is(NONE))
+                .return_type(ReturnType::None)
+                .into(),
+            None => delete::<Self>(id).into(),
+        }
     }
 
-    /// Deletes records by filtering.
+    /// Deletes records by filtering. A soft deleted model has its
+    /// soft delete field set to the current time instead.
     fn delete_where(filter: impl Conditional + Clone) -> DeleteStatementMini<Self> {
-        delete::<Self>(Self::table()).where_(filter).into()
+        match Self::soft_delete_field() {
+            Some(deleted_at) => update::<Self>(Self::table())
+                .set(Setter::assign(&deleted_at, time_now()))
+                .where_(cond(filter).and(deleted_at.is(NONE)))
+                .return_type(ReturnType::None)
+                .into(),
+            None => delete::<Self>(Self::table()).where_(filter).into(),
+        }
+    }
+
+    /// Restores the current record if it is soft deleted by unsetting its soft delete field.
+    fn restore(&self) -> UpdateStatement<Self> {
+        Self::restore_by_id(self.get_id_as_thing())
+    }
+
+    /// Restores a soft deleted record by id by unsetting its soft delete field.
+    fn restore_by_id(id: impl Into<Thing>) -> UpdateStatement<Self> {
+        let statement = update::<Self>(id.into());
+        match Self::soft_delete_field() {
+            Some(deleted_at) => statement.set(Setter::assign(&deleted_at, NONE)),
+            None => statement.set(vec![]).with_error(format!(
+                "Unable to restore a record of `{}` which is not soft deleted. \
+                Use the `soft_delete` attribute on the model.",
+                Self::table()
+            )),
+        }
+    }
+}
+
+/// The current time on the database, set as the time a record is soft deleted.
+fn time_now() -> Function {
+    Function {
+        query_string: "time::now()".to_string(),
+        bindings: vec![],
+        errors: vec![],
     }
 }
 
@@ -5,8 +5,9 @@
  */
 
 use crate::{
-    Aliasable, ArrayLike, Binding, BindingsList, Buildable, Conditional, Erroneous, ErrorList,
-    GeometryLike, NumberLike, Ordinal, Parametric, Setter, StrandLike, ValueLike,
+    statements::VectorDistance, Aliasable, ArrayLike, Binding, BindingsList, Buildable,
+    Conditional, Erroneous, ErrorList, GeometryLike, NumberLike, Ordinal, Parametric, Setter,
+    StrandLike, ValueLike,
 };
 use std::fmt::Display;
 use surrealdb::sql;
@@ -1283,6 +1284,83 @@ pub trait Operatable: Sized + Parametric + Buildable + Erroneous {
         operation
     }
 
+    /// `<|k|>` Finds the `k` records whose vector field is nearest to the given vector,
+    /// using the M-Tree vector index of the field when defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of nearest records to find.
+    /// * `vector` - The vector to search with. Could also be a `Field` or `Param`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::*;
+    ///
+    /// # let embedding = Field::new("embedding");
+    /// let query = embedding.knn(3, vec![1, 2, 3]);
+    /// assert_eq!(query.to_raw().build(), "embedding <|3|> [1, 2, 3]");
+    /// ```
+    fn knn(&self, k: u32, vector: impl Into<ArrayLike>) -> Operation {
+        let vector: ArrayLike = vector.into();
+        self.generate_query(format!("<|{k}|>"), vector)
+    }
+
+    /// `<|k,DIST|>` Same as `knn` but compares the vector with every record
+    /// using the given distance, without a vector index.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of nearest records to find.
+    /// * `distance` - The distance used to compare the vectors.
+    /// * `vector` - The vector to search with. Could also be a `Field` or `Param`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::{*, statements::VectorDistance};
+    ///
+    /// # let embedding = Field::new("embedding");
+    /// let query = embedding.knn_with_distance(3, VectorDistance::Manhattan, vec![1, 2, 3]);
+    /// assert_eq!(query.to_raw().build(), "embedding <|3,MANHATTAN|> [1, 2, 3]");
+    /// ```
+    fn knn_with_distance(
+        &self,
+        k: u32,
+        distance: VectorDistance,
+        vector: impl Into<ArrayLike>,
+    ) -> Operation {
+        let vector: ArrayLike = vector.into();
+        self.generate_query(format!("<|{k},{distance}|>"), vector)
+    }
+
+    /// `<|k,ef|>` Same as `knn` but searches the HNSW vector index of the field,
+    /// exploring `ef` candidates. A larger `ef` is more accurate but slower.
+    /// Requires a SurrealDB server version that supports HNSW indexes.
+    ///
+    /// # Arguments
+    ///
+    /// * `k` - The number of nearest records to find.
+    /// * `ef` - The size of the dynamic candidate list of the search.
+    /// * `vector` - The vector to search with. Could also be a `Field` or `Param`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use surreal_query_builder as surreal_orm;
+    /// # use surreal_orm::*;
+    ///
+    /// # let embedding = Field::new("embedding");
+    /// let query = embedding.knn_ef(3, 40, vec![1, 2, 3]);
+    /// assert_eq!(query.to_raw().build(), "embedding <|3,40|> [1, 2, 3]");
+    /// ```
+    fn knn_ef(&self, k: u32, ef: u32, vector: impl Into<ArrayLike>) -> Operation {
+        let vector: ArrayLike = vector.into();
+        self.generate_query(format!("<|{k},{ef}|>"), vector)
+    }
+
     /// Check whether the value of the field is between the given lower and upper bounds.
     ///
     /// # Arguments
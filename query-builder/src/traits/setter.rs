@@ -7,7 +7,7 @@ use crate::{
         SelectStatement, Subquery, UpdateStatement,
     },
     Binding, BindingsList, Block, Buildable, Conditional, Edge, Erroneous, ErrorList, Field, Node,
-    Param, Parametric,
+    Param, Parametric, ValueLike,
 };
 
 /// A helper struct for generating SQL update statements.
@@ -18,6 +18,18 @@ pub struct Setter {
     errors: ErrorList,
 }
 
+impl Setter {
+    /// Assigns the value to a field which is not typed e.g a field named in a model attribute.
+    pub(crate) fn assign(field: &Field, value: impl Into<ValueLike>) -> Self {
+        let value: ValueLike = value.into();
+        Self {
+            query_string: format!("{field} = {}", value.build()),
+            bindings: value.get_bindings(),
+            errors: value.get_errors(),
+        }
+    }
+}
+
 impl std::fmt::Display for Setter {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.build())
@@ -1,10 +1,14 @@
-use super::{Buildable, Parametric};
+use super::{
+    instrumentation::{execute, TracedResponse},
+    Buildable, Parametric,
+};
 use crate::{
-    AllGetter, Field, Model, Projections, Queryable, ReturnType, SurrealOrmError, SurrealOrmResult,
+    statements::select_eager::check_dangling_links, AllGetter, Field, Model, Projections,
+    Queryable, ReturnType, StatementSlot, SurrealOrmError, SurrealOrmResult, TypedResults,
     ValueLike,
 };
 use serde::{de::DeserializeOwned, Serialize};
-use surrealdb::{Connection, Surreal};
+use surrealdb::{sql, Connection, Surreal};
 
 // Create, Update, Relate, Delete
 // [ RETURN [ NONE | BEFORE | AFTER | DIFF | @projections ... ]
@@ -20,21 +24,7 @@ where
         &self,
         db: Surreal<impl surrealdb::Connection>,
     ) -> SurrealOrmResult<surrealdb::Response> {
-        let query_builder_error = self.get_errors();
-
-        if !query_builder_error.is_empty() {
-            return Err(SurrealOrmError::QueryBuilder(
-                query_builder_error.join(". \n"),
-            ));
-        }
-
-        let query = self.build();
-        let query = db.query(query);
-        let query = self.get_bindings().iter().fold(query, |acc, val| {
-            acc.bind((val.get_param(), val.get_value()))
-        });
-
-        Ok(query.await.map_err(SurrealOrmError::QueryRun)?)
+        execute(self, db).await.map(|traced| traced.response)
     }
 
     /// Runs the statement against the database and returns the deserialized result.
@@ -45,11 +35,40 @@ where
     where
         T: Sized + Serialize + DeserializeOwned,
     {
-        let mut response = self.run(db).await?;
+        let TracedResponse {
+            mut response, span, ..
+        } = execute(self, db).await?;
+        let record = response.take::<Option<T>>(0).map_err(|e| {
+            SurrealOrmError::from_statement_error(e, SurrealOrmError::Deserialization)
+        });
+
+        span.finish_with_rows(record, |record| usize::from(record.is_some()))
+    }
+
+    /// Runs the statements against the database and deserializes the result of each
+    /// statement into the element of the tuple at the same position.
+    /// LET, BEGIN, COMMIT and CANCEL statements are skipped and `()` ignores the
+    /// result of a statement.
+    ///
+    /// # Example
+    /// ```rust, ignore
+    /// let (users, weapon, ()) = chain(select(All).from(User::table()))
+    ///     .chain(let_("strength").equal_to(5))
+    ///     .chain(select(All).from_only(weapon_id))
+    ///     .chain(delete::<Planet>(planet_id))
+    ///     .run_typed::<(Vec<User>, Option<Weapon>, ())>(db)
+    ///     .await?;
+    /// ```
+    async fn run_typed<T>(&self, db: Surreal<impl surrealdb::Connection>) -> SurrealOrmResult<T>
+    where
+        T: TypedResults + Send,
+    {
+        let slots = StatementSlot::from_query(&self.build())?;
+        let TracedResponse {
+            mut response, span, ..
+        } = execute(self, db).await?;
 
-        Ok(response
-            .take::<Option<T>>(0)
-            .map_err(SurrealOrmError::Deserialization)?)
+        span.finish(T::from_response(&mut response, &slots))
     }
 }
 
@@ -94,7 +113,7 @@ where
         let projections: Projections = projections.into();
         query = query.set_return_type(ReturnType::Projections(projections));
 
-        let response = query.run(db).await?;
+        let response = execute(&query, db).await?;
         get_first::<P>(response)
     }
 
@@ -112,7 +131,7 @@ where
         let projections: Projections = projections.into();
         query = query.set_return_type(ReturnType::Projections(projections));
 
-        let response = query.run(db).await?;
+        let response = execute(&query, db).await?;
         get_one::<P>(response)
     }
 
@@ -130,7 +149,7 @@ where
         let projections: Projections = projections.into();
         query = query.set_return_type(ReturnType::Projections(projections));
 
-        let response = query.run(db).await?;
+        let response = execute(&query, db).await?;
         get_many::<P>(response)
     }
 
@@ -261,7 +280,7 @@ where
 
     /// Runs the statement against the database and returns the one result.
     async fn return_one(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Option<T>> {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_one::<T>(response)
     }
 
@@ -271,7 +290,7 @@ where
     /// even if you select subset, it fills up the rest to make
     /// sure you get the full record and can be properly deserialized.
     async fn get_one(self, db: Surreal<impl Connection>) -> SurrealOrmResult<T> {
-        let response = self.run(db).await?;
+        let response = execute(&self, db).await?;
         let returned_type = self.get_return_type();
         let all = vec![ValueLike::from(Field::new("*"))];
         let selected_fields = match returned_type {
@@ -296,19 +315,27 @@ where
 
     /// Runs the statement against the database and returns the many results.
     async fn return_many(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Vec<T>> {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_many::<T>(response)
     }
 
     /// Runs the statement against the database and returns no result.
+    /// Errors of the statement, e.g a stale write, are still returned.
     async fn return_none(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<()> {
-        self.run(db).await?;
-        Ok(())
+        let TracedResponse {
+            mut response, span, ..
+        } = execute(self, db).await?;
+        let result = response
+            .take::<sql::Value>(0)
+            .map(|_| ())
+            .map_err(|e| SurrealOrmError::from_statement_error(e, SurrealOrmError::QueryRun));
+
+        span.finish(result)
     }
 
     /// Runs the statement against the database and returns the first result.
     async fn return_first(&self, db: Surreal<impl Connection>) -> SurrealOrmResult<Option<T>> {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_first::<T>(response)
     }
 
@@ -341,7 +368,7 @@ where
     where
         V: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_one::<V>(response)
     }
 
@@ -354,7 +381,7 @@ where
     where
         V: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_many::<V>(response)
     }
 }
@@ -377,7 +404,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_first::<T>(response)
     }
 
@@ -389,7 +416,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_one::<T>(response)
     }
 
@@ -398,7 +425,7 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await.unwrap();
+        let response = execute(self, db).await.unwrap();
         get_last::<T>(response).unwrap().unwrap()
     }
 
@@ -407,42 +434,73 @@ where
     where
         T: Serialize + DeserializeOwned,
     {
-        let response = self.run(db).await?;
+        let response = execute(self, db).await?;
         get_many::<T>(response)
     }
 }
 
-fn get_one<T>(mut response: surrealdb::Response) -> SurrealOrmResult<Option<T>>
+/// Takes the records of the first statement of the response after checking that
+/// the links eagerly loaded with `SelectStatement::with` are not dangling.
+fn take_many<T>(response: TracedResponse) -> SurrealOrmResult<Vec<T>>
 where
-    T: Serialize + DeserializeOwned,
+    T: DeserializeOwned,
+{
+    let TracedResponse {
+        mut response,
+        span,
+        has_eager_loads,
+    } = response;
+
+    span.finish_with_rows(take_records(&mut response, has_eager_loads), Vec::len)
+}
+
+fn take_records<T>(
+    response: &mut surrealdb::Response,
+    has_eager_loads: bool,
+) -> SurrealOrmResult<Vec<T>>
+where
+    T: DeserializeOwned,
 {
     let mut value = response
-        .take::<Vec<T>>(0)
-        .map_err(SurrealOrmError::Deserialization)?;
+        .take::<sql::Value>(0)
+        .map_err(|e| SurrealOrmError::from_statement_error(e, SurrealOrmError::Deserialization))?;
+    if has_eager_loads {
+        check_dangling_links(&mut value)?;
+    }
+
+    let values = match value {
+        sql::Value::Array(values) => values.0,
+        value => vec![value],
+    };
+
+    sql::from_value(values.into()).map_err(|e| SurrealOrmError::Deserialization(e.into()))
+}
+
+fn get_one<T>(response: TracedResponse) -> SurrealOrmResult<Option<T>>
+where
+    T: Serialize + DeserializeOwned,
+{
+    let mut value = take_many::<T>(response)?;
     if value.len() > 1 {
         return Err(SurrealOrmError::TooManyItemsReturned(1.into()));
     }
     Ok(value.pop())
 }
 
-fn get_many<T>(mut response: surrealdb::Response) -> SurrealOrmResult<Vec<T>>
+fn get_many<T>(response: TracedResponse) -> SurrealOrmResult<Vec<T>>
 where
     T: Serialize + DeserializeOwned,
 {
-    let value = response
-        .take::<Vec<T>>(0)
-        .map_err(SurrealOrmError::Deserialization)?;
+    let value = take_many::<T>(response)?;
 
     Ok(value)
 }
 
-fn get_first<T>(mut response: surrealdb::Response) -> SurrealOrmResult<Option<T>>
+fn get_first<T>(response: TracedResponse) -> SurrealOrmResult<Option<T>>
 where
     T: Serialize + DeserializeOwned,
 {
-    let mut value = response
-        .take::<Vec<T>>(0)
-        .map_err(SurrealOrmError::Deserialization)?;
+    let mut value = take_many::<T>(response)?;
 
     let value = if !value.is_empty() {
         Some(value.swap_remove(0))
@@ -453,13 +511,11 @@ where
     Ok(value)
 }
 
-fn get_last<T>(mut response: surrealdb::Response) -> SurrealOrmResult<Option<T>>
+fn get_last<T>(response: TracedResponse) -> SurrealOrmResult<Option<T>>
 where
     T: Serialize + DeserializeOwned,
 {
-    let mut value = response
-        .take::<Vec<T>>(0)
-        .map_err(SurrealOrmError::Deserialization)?;
+    let mut value = take_many::<T>(response)?;
 
     let value = if !value.is_empty() { value.pop() } else { None };
 
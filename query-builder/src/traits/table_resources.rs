@@ -1,24 +1,24 @@
 use crate::{Field, Model, Raw, Table};
 
 /// Provides the ability to gather all the resources
-/// of a table in a single place. Fields definitions,
-/// and table definitions are using automatically
+/// of a table in a single place. Table, fields, indexes
+/// and events definitions are using automatically
 /// generated methods from the `Model` trait.
-/// Events and indexes definitions are manually
-/// implemented using the statements or query macro
-/// provided by the crate.
+/// Indexes and events come from the `index` and `event`
+/// attributes and can also be manually implemented
+/// using the statements or query macro provided by the crate.
 pub trait TableResources
 where
     Self: Model,
 {
-    /// Returns a list of fields definitions.
+    /// Returns a list of events definitions.
     fn events_definitions() -> Vec<Raw> {
-        vec![]
+        Self::define_events()
     }
 
     /// Returns a list of fields' indexes definitions.
     fn indexes_definitions() -> Vec<Raw> {
-        vec![]
+        Self::define_indexes()
     }
 
     /// Returns a list of fields definitions.
@@ -0,0 +1,221 @@
+/*
+ * Author: Oyelowo Oyedayo
+ * Email: oyelowo.oss@gmail.com
+ * Copyright (c) 2024 Oyelowo Oyedayo
+ */
+
+use serde::de::DeserializeOwned;
+use surrealdb::{
+    sql::{self, Statement},
+    Response,
+};
+
+use crate::{SurrealOrmError, SurrealOrmResult};
+
+/// A statement of a query whose result is returned in the response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementSlot {
+    /// The position of the result of the statement in the response
+    pub index: usize,
+    /// The statement as sent to the database
+    pub statement: String,
+}
+
+impl StatementSlot {
+    /// Gets the statements of a query which results are decoded into typed results.
+    ///
+    /// BEGIN, COMMIT, CANCEL and OPTION statements have no result in the response.
+    /// LET statements have an empty result which is skipped.
+    /// Within a transaction, a RETURN statement replaces the results of the statements before it.
+    pub fn from_query(query: &str) -> SurrealOrmResult<Vec<Self>> {
+        let query = sql::parse(query).map_err(|e| {
+            SurrealOrmError::QueryBuilder(format!(
+                "Unable to parse the statements of the query. {e}"
+            ))
+        })?;
+
+        let mut results = vec![];
+        let mut transaction_results = vec![];
+        let mut in_transaction = false;
+
+        for statement in query.0 .0 {
+            match statement {
+                Statement::Begin(_) => {
+                    in_transaction = true;
+                    continue;
+                }
+                Statement::Commit(_) | Statement::Cancel(_) => {
+                    results.append(&mut transaction_results);
+                    in_transaction = false;
+                    continue;
+                }
+                Statement::Option(_) => continue,
+                _ => {}
+            }
+
+            let is_let = matches!(statement, Statement::Set(_));
+            let result = (is_let, statement.to_string());
+            if in_transaction {
+                if matches!(statement, Statement::Output(_)) {
+                    transaction_results.clear();
+                }
+                transaction_results.push(result);
+            } else {
+                results.push(result);
+            }
+        }
+        results.append(&mut transaction_results);
+
+        Ok(results
+            .into_iter()
+            .enumerate()
+            .filter(|(_, (is_let, _))| !is_let)
+            .map(|(index, (_, statement))| Self { index, statement })
+            .collect())
+    }
+}
+
+/// The result of a single statement in a response.
+pub trait StatementResult: Sized {
+    /// Takes the result of the statement at the index from the response.
+    fn take_from(response: &mut Response, index: usize) -> surrealdb::Result<Self>;
+}
+
+impl<T: DeserializeOwned> StatementResult for Vec<T> {
+    fn take_from(response: &mut Response, index: usize) -> surrealdb::Result<Self> {
+        response.take(index)
+    }
+}
+
+impl<T: DeserializeOwned> StatementResult for Option<T> {
+    fn take_from(response: &mut Response, index: usize) -> surrealdb::Result<Self> {
+        response.take(index)
+    }
+}
+
+impl StatementResult for sql::Value {
+    fn take_from(response: &mut Response, index: usize) -> surrealdb::Result<Self> {
+        response.take(index)
+    }
+}
+
+/// Ignores the result of the statement. An error returned by the statement is still surfaced.
+impl StatementResult for () {
+    fn take_from(response: &mut Response, index: usize) -> surrealdb::Result<Self> {
+        response.take::<sql::Value>(index).map(|_| ())
+    }
+}
+
+/// A tuple of the results of the statements of a query, in the order of the statements.
+/// Implemented for tuples of up to 12 `StatementResult`s
+/// e.g `(Vec<User>, Option<Weapon>, ())`.
+pub trait TypedResults: Sized {
+    /// The number of statement results in the tuple
+    const LEN: usize;
+
+    /// Decodes the results of the statements from the response.
+    fn from_response(response: &mut Response, slots: &[StatementSlot]) -> SurrealOrmResult<Self>;
+}
+
+fn take_statement_result<T: StatementResult>(
+    response: &mut Response,
+    slot: &StatementSlot,
+) -> SurrealOrmResult<T> {
+    T::take_from(response, slot.index).map_err(|source| {
+        SurrealOrmError::from_statement_error(source, |source| SurrealOrmError::StatementResult {
+            index: slot.index,
+            statement: slot.statement.clone(),
+            source,
+        })
+    })
+}
+
+macro_rules! impl_typed_results {
+    ($len:expr; $($result:ident: $position:tt),+) => {
+        impl<$($result: StatementResult),+> TypedResults for ($($result,)+) {
+            const LEN: usize = $len;
+
+            fn from_response(
+                response: &mut Response,
+                slots: &[StatementSlot],
+            ) -> SurrealOrmResult<Self> {
+                if slots.len() != Self::LEN {
+                    return Err(SurrealOrmError::StatementResultsCount {
+                        expected: Self::LEN,
+                        found: slots.len(),
+                    });
+                }
+
+                Ok(($(take_statement_result::<$result>(response, &slots[$position])?,)+))
+            }
+        }
+    };
+}
+
+impl_typed_results!(1; A: 0);
+impl_typed_results!(2; A: 0, B: 1);
+impl_typed_results!(3; A: 0, B: 1, C: 2);
+impl_typed_results!(4; A: 0, B: 1, C: 2, D: 3);
+impl_typed_results!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_typed_results!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_typed_results!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_typed_results!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+impl_typed_results!(9; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8);
+impl_typed_results!(10; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9);
+impl_typed_results!(11; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10);
+impl_typed_results!(12; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7, I: 8, J: 9, K: 10, L: 11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn indexes(query: &str) -> Vec<usize> {
+        StatementSlot::from_query(query)
+            .unwrap()
+            .into_iter()
+            .map(|slot| slot.index)
+            .collect()
+    }
+
+    #[test]
+    fn test_let_statements_are_skipped() {
+        assert_eq!(
+            indexes("LET $min = 5; SELECT * FROM weapon WHERE strength > $min; LET $max = 10; SELECT * FROM user;"),
+            vec![1, 3]
+        );
+    }
+
+    #[test]
+    fn test_transaction_statements_have_no_result() {
+        assert_eq!(
+            indexes(
+                "BEGIN TRANSACTION; LET $name = 'Oyelowo'; CREATE user SET name = $name; \
+                SELECT * FROM user; COMMIT TRANSACTION; SELECT * FROM weapon;"
+            ),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_return_in_transaction_replaces_previous_results() {
+        assert_eq!(
+            indexes(
+                "BEGIN TRANSACTION; CREATE user SET name = 'Oyelowo'; RETURN 5; \
+                CREATE weapon SET strength = 5; COMMIT TRANSACTION;"
+            ),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn test_slot_keeps_statement() {
+        let slots = StatementSlot::from_query("LET $min = 5; SELECT * FROM weapon;").unwrap();
+        assert_eq!(
+            slots,
+            vec![StatementSlot {
+                index: 1,
+                statement: "SELECT * FROM weapon".to_string(),
+            }]
+        );
+    }
+}
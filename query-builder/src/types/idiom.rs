@@ -35,6 +35,10 @@ pub struct User(sql::Idiom);
 #[derive(Debug, Clone)]
 pub struct Scope(sql::Idiom);
 
+/// Surreal access method
+#[derive(Debug, Clone)]
+pub struct Access(sql::Idiom);
+
 /// Surreal table
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct Table(sql::Table);
@@ -149,7 +153,7 @@ macro_rules! impl_new_for_all {
     };
 }
 
-impl_new_for_all!(Namespace, Database, Login, Token, User, Scope, Event, TableIndex);
+impl_new_for_all!(Namespace, Database, Login, Token, User, Scope, Access, Event, TableIndex);
 
 macro_rules! impl_display_for_all {
     ($($types_:ty),*) => {
@@ -186,7 +190,9 @@ macro_rules! impl_display_for_all {
     )*
     };
 }
-impl_display_for_all!(Namespace, Database, Login, Token, User, Scope, Table, Event, TableIndex);
+impl_display_for_all!(
+    Namespace, Database, Login, Token, User, Scope, Access, Table, Event, TableIndex
+);
 
 /// Wrapper around Surreal idiom. X suffix stands for extra.
 pub struct Idiomx(sql::Idiom);
@@ -337,6 +337,7 @@ pub fn assert_value_is_uuid<T: IsUuid>(_value: T) {}
 pub trait IsDatetime {}
 
 impl IsDatetime for chrono::DateTime<chrono::Utc> {}
+impl IsDatetime for crate::sql::Datetime {}
 
 /// Validate that type is a Datetime at compile time
 pub fn assert_type_is_datetime<T: IsDatetime>() {
@@ -561,3 +562,71 @@ macro_rules! assert_not {
     };
 }
 pub use assert_not;
+
+/// Compares two strings at compile time
+pub const fn const_str_eq(left: &str, right: &str) -> bool {
+    let left = left.as_bytes();
+    let right = right.as_bytes();
+
+    if left.len() != right.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Checks at compile time that a table belongs to one of the models.
+/// Each model is given as its table name and the names of its database fields.
+///
+/// # Example
+/// ```
+/// # use surreal_query_builder::validators::models_have_table;
+/// const MODELS: &[(&str, &[&str])] = &[("user", &["id", "name"]), ("weapon", &["id", "strength"])];
+/// const _: () = assert!(models_have_table(MODELS, "weapon"));
+/// const _: () = assert!(!models_have_table(MODELS, "planet"));
+/// ```
+pub const fn models_have_table(models: &[(&str, &[&str])], table: &str) -> bool {
+    let mut i = 0;
+    while i < models.len() {
+        if const_str_eq(models[i].0, table) {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Checks at compile time that a field belongs to the model of the table.
+/// Each model is given as its table name and the names of its database fields.
+///
+/// # Example
+/// ```
+/// # use surreal_query_builder::validators::models_have_field;
+/// const MODELS: &[(&str, &[&str])] = &[("user", &["id", "name"]), ("weapon", &["id", "strength"])];
+/// const _: () = assert!(models_have_field(MODELS, "user", "name"));
+/// const _: () = assert!(!models_have_field(MODELS, "user", "strength"));
+/// ```
+pub const fn models_have_field(models: &[(&str, &[&str])], table: &str, field: &str) -> bool {
+    let mut i = 0;
+    while i < models.len() {
+        let (model_table, fields) = models[i];
+        if const_str_eq(model_table, table) {
+            let mut j = 0;
+            while j < fields.len() {
+                if const_str_eq(fields[j], field) {
+                    return true;
+                }
+                j += 1;
+            }
+        }
+        i += 1;
+    }
+    false
+}